@@ -1,11 +1,20 @@
 use std::mem::size_of;
 use std::ops::{Deref, DerefMut};
 
+use serde::Serialize;
+use thiserror::Error;
+
 use solana_sdk::account::{Account, AccountSharedData, ReadableAccount};
 use solana_sdk::clock::Epoch;
 use solana_sdk::pubkey::Pubkey;
 
-use anchor_lang::{AccountDeserialize, AccountSerialize, Key, ZeroCopy};
+use anchor_lang::{AccountDeserialize, AccountSerialize, Discriminator, Key, Owner, ZeroCopy};
+
+use dexter_client_api::base::getter::GetAccount;
+use dexter_client_api::errors::{ClientError, ClientResult};
+
+use crate::diff::{diff_fields, FieldChange};
+use crate::getter::AnchorGetter;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AnchorAccount<T> {
@@ -23,6 +32,47 @@ impl<T> AnchorAccount<T> {
         Ok(Self { key, account, data })
     }
 
+    pub fn try_from_account_unchecked(key: Pubkey, account: Account) -> anchor_lang::Result<Self>
+    where
+        T: AccountDeserialize,
+    {
+        let data = T::try_deserialize_unchecked(&mut account.data())?;
+        Ok(Self { key, account, data })
+    }
+
+    pub fn try_from_account_checked(
+        key: Pubkey,
+        account: Account,
+    ) -> Result<Self, AccountCheckError>
+    where
+        T: AccountDeserialize + Discriminator + Owner,
+    {
+        let expected_owner = T::owner();
+        if account.owner != expected_owner {
+            return Err(AccountCheckError::OwnerMismatch {
+                key,
+                expected: expected_owner,
+                actual: account.owner,
+            });
+        }
+
+        let Some(discriminator) = account.data.get(..8) else {
+            return Err(AccountCheckError::DiscriminatorNotFound(key));
+        };
+        if discriminator != T::DISCRIMINATOR {
+            return Err(AccountCheckError::DiscriminatorMismatch {
+                key,
+                expected: T::DISCRIMINATOR,
+                actual: discriminator.try_into().unwrap(),
+            });
+        }
+
+        let data = T::try_deserialize_unchecked(&mut account.data())
+            .map_err(|err| AccountCheckError::DataDidNotDeserialize(key, err.to_string()))?;
+
+        Ok(Self { key, account, data })
+    }
+
     pub fn as_parts(this: &Self) -> (&Pubkey, &Account, &T) {
         (&this.key, &this.account, &this.data)
     }
@@ -39,6 +89,41 @@ impl<T> AnchorAccount<T> {
         (this.key, this.account, this.data)
     }
 
+    pub fn reload(this: &Self, client: &impl GetAccount) -> ClientResult<Self>
+    where
+        T: AccountDeserialize,
+    {
+        client.try_get_anchor_account(&this.key)
+    }
+
+    pub fn reload_in_place(this: &mut Self, client: &impl GetAccount) -> ClientResult<()>
+    where
+        T: AccountDeserialize,
+    {
+        *this = Self::reload(this, client)?;
+        Ok(())
+    }
+
+    pub fn diff(this: &Self, other: &Self) -> serde_json::Result<Vec<FieldChange>>
+    where
+        T: Serialize,
+    {
+        diff_fields(&this.data, &other.data)
+    }
+
+    pub fn set_lamports(this: &mut Self, lamports: u64) {
+        this.account.lamports = lamports;
+    }
+
+    pub fn set_owner(this: &mut Self, owner: Pubkey) {
+        this.account.owner = owner;
+    }
+
+    /// Keeps the decoded view, which a later mutation writes over the resized data.
+    pub fn resize_data(this: &mut Self, new_len: usize) {
+        this.account.data.resize(new_len, 0);
+    }
+
     pub fn serializable_mut(&mut self) -> SerializableMut<T>
     where
         T: AccountSerialize,
@@ -142,6 +227,32 @@ impl<T> ReadableAccount for AnchorAccount<T> {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum AccountCheckError {
+    #[error("The account {key} is owned by {actual} instead of {expected}")]
+    OwnerMismatch {
+        key: Pubkey,
+        expected: Pubkey,
+        actual: Pubkey,
+    },
+    #[error("The account {0} is too small to hold a discriminator")]
+    DiscriminatorNotFound(Pubkey),
+    #[error("The account {key} has the discriminator {actual:?} instead of {expected:?}")]
+    DiscriminatorMismatch {
+        key: Pubkey,
+        expected: [u8; 8],
+        actual: [u8; 8],
+    },
+    #[error("Failed to deserialize the data of the account {0}: {1}")]
+    DataDidNotDeserialize(Pubkey, String),
+}
+
+impl From<AccountCheckError> for ClientError {
+    fn from(error: AccountCheckError) -> Self {
+        ClientError::DomainSpecific(Box::new(error))
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct SerializableMut<'a, T: AccountSerialize> {
     key: &'a Pubkey,
@@ -171,8 +282,13 @@ impl<'a, T: AccountSerialize> Key for SerializableMut<'a, T> {
 
 impl<'a, T: AccountSerialize> Drop for SerializableMut<'a, T> {
     fn drop(&mut self) {
-        self.account.data.clear();
-        self.data.try_serialize(&mut self.account.data).unwrap();
+        let mut data = Vec::with_capacity(self.account.data.len());
+        self.data.try_serialize(&mut data).unwrap();
+
+        // the space allocated beyond the serialized data is kept, zeroed
+        let len = data.len().max(self.account.data.len());
+        data.resize(len, 0);
+        self.account.data = data;
     }
 }
 
@@ -205,7 +321,53 @@ impl<'a, T: ZeroCopy> Key for LoadableMut<'a, T> {
 
 impl<'a, T: ZeroCopy> Drop for LoadableMut<'a, T> {
     fn drop(&mut self) {
-        let written = bytemuck::from_bytes_mut::<T>(&mut self.account.data[8..size_of::<T>() + 8]);
-        *written = *self.data;
+        let len = self.account.data.len().max(size_of::<T>() + 8);
+        self.account.data.resize(len, 0);
+
+        self.account.data[8..size_of::<T>() + 8].copy_from_slice(bytemuck::bytes_of(self.data));
+    }
+}
+
+// the data is copied out, as fetched buffers carry no alignment guarantee
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZeroCopyAccounts<T: ZeroCopy> {
+    accounts: Vec<(Pubkey, Account)>,
+    data: Vec<T>,
+}
+
+impl<T: ZeroCopy> ZeroCopyAccounts<T> {
+    pub fn try_from_accounts(accounts: Vec<(Pubkey, Account)>) -> Result<Self, Pubkey> {
+        let mut data = Vec::with_capacity(accounts.len());
+        for (key, account) in &accounts {
+            let bytes = account
+                .data
+                .strip_prefix(&T::DISCRIMINATOR[..])
+                .and_then(|bytes| bytes.get(..size_of::<T>()))
+                .ok_or(*key)?;
+            data.push(bytemuck::pod_read_unaligned(bytes));
+        }
+
+        Ok(Self { accounts, data })
+    }
+
+    pub fn len(&self) -> usize {
+        self.accounts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<(&Pubkey, &T)> {
+        let (key, _) = self.accounts.get(index)?;
+        Some((key, &self.data[index]))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Pubkey, &T)> {
+        self.accounts.iter().map(|(key, _)| key).zip(&self.data)
+    }
+
+    pub fn into_accounts(self) -> Vec<(Pubkey, Account)> {
+        self.accounts
     }
 }
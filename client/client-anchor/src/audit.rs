@@ -0,0 +1,82 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use solana_sdk::account::Account;
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProgramAccountsAudit {
+    pub program_id: Pubkey,
+    // known types in the given order first, then unknown ones with the most accounts
+    pub groups: Vec<DiscriminatorGroup>,
+    pub total_lamports: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiscriminatorGroup {
+    pub discriminator: Option<[u8; 8]>,
+    // `None` for unknown discriminators
+    pub name: Option<String>,
+    pub accounts: Vec<Pubkey>,
+    pub lamports: u64,
+}
+
+impl ProgramAccountsAudit {
+    pub fn new(
+        program_id: Pubkey,
+        known: &[(&str, [u8; 8])],
+        accounts: Vec<(Pubkey, Account)>,
+    ) -> Self {
+        let mut by_discriminator: BTreeMap<Option<[u8; 8]>, (Vec<Pubkey>, u64)> = BTreeMap::new();
+        let mut total_lamports = 0;
+
+        for (key, account) in accounts {
+            let discriminator = account.data.get(..8).map(|bytes| bytes.try_into().unwrap());
+
+            let (keys, lamports) = by_discriminator.entry(discriminator).or_default();
+            keys.push(key);
+            *lamports += account.lamports;
+            total_lamports += account.lamports;
+        }
+
+        let mut groups = Vec::with_capacity(by_discriminator.len());
+        for (name, discriminator) in known {
+            let (accounts, lamports) = by_discriminator
+                .remove(&Some(*discriminator))
+                .unwrap_or_default();
+            groups.push(DiscriminatorGroup {
+                discriminator: Some(*discriminator),
+                name: Some(name.to_string()),
+                accounts,
+                lamports,
+            });
+        }
+
+        let mut unknown_groups: Vec<_> = by_discriminator
+            .into_iter()
+            .map(|(discriminator, (accounts, lamports))| DiscriminatorGroup {
+                discriminator,
+                name: None,
+                accounts,
+                lamports,
+            })
+            .collect();
+        unknown_groups.sort_by(|a, b| b.accounts.len().cmp(&a.accounts.len()));
+        groups.extend(unknown_groups);
+
+        Self {
+            program_id,
+            groups,
+            total_lamports,
+        }
+    }
+
+    pub fn unknown_groups(&self) -> impl Iterator<Item = &DiscriminatorGroup> {
+        self.groups.iter().filter(|group| group.name.is_none())
+    }
+
+    pub fn account_count(&self) -> usize {
+        self.groups.iter().map(|group| group.accounts.len()).sum()
+    }
+}
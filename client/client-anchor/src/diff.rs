@@ -0,0 +1,75 @@
+use std::fmt;
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// `path` is dot-separated with indices for elements, e.g. `positions.2.amount`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    pub path: String,
+    pub before: Value,
+    pub after: Value,
+}
+
+impl fmt::Display for FieldChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} -> {}", self.path, self.before, self.after)
+    }
+}
+
+pub fn diff_fields<T: Serialize>(before: &T, after: &T) -> serde_json::Result<Vec<FieldChange>> {
+    let before = serde_json::to_value(before)?;
+    let after = serde_json::to_value(after)?;
+
+    let mut changes = Vec::new();
+    diff_values(String::new(), before, after, &mut changes);
+    Ok(changes)
+}
+
+fn diff_values(path: String, before: Value, after: Value, changes: &mut Vec<FieldChange>) {
+    match (before, after) {
+        (Value::Object(mut before), Value::Object(mut after)) => {
+            let mut keys: Vec<_> = before.keys().cloned().collect();
+            keys.extend(
+                after
+                    .keys()
+                    .filter(|key| !before.contains_key(*key))
+                    .cloned(),
+            );
+
+            for key in keys {
+                diff_values(
+                    join(&path, &key),
+                    before.remove(&key).unwrap_or(Value::Null),
+                    after.remove(&key).unwrap_or(Value::Null),
+                    changes,
+                );
+            }
+        }
+        // numeric arrays such as pubkeys serialized as bytes are reported as a whole
+        (Value::Array(before), Value::Array(after))
+            if before.len() == after.len() && !before.iter().all(Value::is_number) =>
+        {
+            for (i, (before, after)) in before.into_iter().zip(after).enumerate() {
+                diff_values(join(&path, &i.to_string()), before, after, changes);
+            }
+        }
+        (before, after) => {
+            if before != after {
+                changes.push(FieldChange {
+                    path,
+                    before,
+                    after,
+                });
+            }
+        }
+    }
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_owned()
+    } else {
+        format!("{path}.{key}")
+    }
+}
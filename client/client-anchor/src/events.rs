@@ -0,0 +1,295 @@
+use std::marker::PhantomData;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+use solana_sdk::clock::Slot;
+use solana_sdk::inner_instruction::InnerInstructionsList;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+
+use anchor_lang::event::EVENT_IX_TAG_LE;
+use anchor_lang::Event;
+
+use dexter_client_api::base::getter::{GetSignaturesForAddress, GetTransaction};
+use dexter_client_api::errors::{ClientError, ClientResult};
+use dexter_client_api::execution::{ExecutionEffect, ExecutionOutput};
+use dexter_client_api::exts::getter::GetTransactionExt;
+use dexter_client_api::Client;
+
+const PROGRAM_DATA_LOG_PREFIX: &str = "Program data: ";
+
+// the seed of the pda that `emit_cpi!` signs its self-invocation with
+const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
+
+pub fn parse_events<E: Event>(logs: &[String]) -> Vec<E> {
+    logs.iter()
+        .filter_map(|log| log.strip_prefix(PROGRAM_DATA_LOG_PREFIX))
+        .filter_map(|data| STANDARD.decode(data).ok())
+        .filter_map(|data| decode_event(&data))
+        .collect()
+}
+
+/// Instructions referring to keys beyond `account_keys`, i.e. through lookup tables, are skipped.
+pub fn parse_cpi_events<E: Event>(
+    program_id: &Pubkey,
+    account_keys: &[Pubkey],
+    inner_instructions: &InnerInstructionsList,
+) -> Vec<E> {
+    let (event_authority, _) = Pubkey::find_program_address(&[EVENT_AUTHORITY_SEED], program_id);
+
+    inner_instructions
+        .iter()
+        .flatten()
+        .map(|inner| &inner.instruction)
+        .filter(|instruction| {
+            let key = |index: u8| account_keys.get(usize::from(index));
+            key(instruction.program_id_index) == Some(program_id)
+                && instruction.accounts.first().copied().and_then(key) == Some(&event_authority)
+        })
+        .filter_map(|instruction| instruction.data.strip_prefix(&EVENT_IX_TAG_LE[..]))
+        .filter_map(decode_event)
+        .collect()
+}
+
+pub(crate) fn decode_event<E: Event>(data: &[u8]) -> Option<E> {
+    let mut payload = data.strip_prefix(&E::DISCRIMINATOR[..])?;
+    E::deserialize(&mut payload).ok()
+}
+
+pub trait ExecutionEvents {
+    /// `emit!` events first, then `emit_cpi!` ones if the inner instructions were recorded.
+    fn events<E: Event>(&self, program_id: &Pubkey) -> Vec<E>;
+}
+
+impl ExecutionEvents for ExecutionOutput {
+    fn events<E: Event>(&self, program_id: &Pubkey) -> Vec<E> {
+        collect_events(
+            program_id,
+            self.transaction.message.static_account_keys(),
+            &self.logs,
+            self.inner_instructions.as_ref(),
+        )
+    }
+}
+
+impl ExecutionEvents for ExecutionEffect {
+    fn events<E: Event>(&self, program_id: &Pubkey) -> Vec<E> {
+        collect_events(
+            program_id,
+            self.transaction.message.static_account_keys(),
+            &self.logs,
+            self.inner_instructions.as_ref(),
+        )
+    }
+}
+
+fn collect_events<E: Event>(
+    program_id: &Pubkey,
+    account_keys: &[Pubkey],
+    logs: &[String],
+    inner_instructions: Option<&InnerInstructionsList>,
+) -> Vec<E> {
+    let mut events = parse_events(logs);
+    if let Some(inner_instructions) = inner_instructions {
+        events.extend(parse_cpi_events(
+            program_id,
+            account_keys,
+            inner_instructions,
+        ));
+    }
+    events
+}
+
+/// Both bounds inclusive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayRange {
+    Slots(RangeInclusive<Slot>),
+    /// `None` leaves the range open.
+    Signatures {
+        first: Option<Signature>,
+        last: Option<Signature>,
+    },
+}
+
+pub trait EventReplay: Client {
+    /// Oldest first, skipping failed transactions.
+    fn replay_events<E: Event>(
+        &self,
+        program_id: &Pubkey,
+        range: &ReplayRange,
+    ) -> ClientResult<Vec<(Signature, E)>>
+    where
+        Self: GetSignaturesForAddress + GetTransaction,
+    {
+        const PAGE_LIMIT: usize = 1000;
+
+        let (mut before, until) = match range {
+            ReplayRange::Slots(_) => (None, None),
+            ReplayRange::Signatures { first, last } => (*last, *first),
+        };
+
+        // the bounds of a signature range are exclusive in the rpc
+        let mut signatures = Vec::new();
+        if let ReplayRange::Signatures {
+            last: Some(last), ..
+        } = range
+        {
+            signatures.push((*last, true));
+        }
+
+        'pages: loop {
+            let statuses =
+                self.get_signatures_for_address(program_id, before, until, Some(PAGE_LIMIT))?;
+            let is_last_page = statuses.len() < PAGE_LIMIT;
+
+            for status in statuses {
+                let signature = Signature::from_str(&status.signature)
+                    .map_err(|err| ClientError::DomainSpecific(err.into()))?;
+                before = Some(signature);
+
+                if let ReplayRange::Slots(slots) = range {
+                    if status.slot > *slots.end() {
+                        continue;
+                    }
+                    if status.slot < *slots.start() {
+                        break 'pages;
+                    }
+                }
+
+                signatures.push((signature, status.err.is_none()));
+            }
+
+            if is_last_page {
+                break;
+            }
+        }
+
+        if let ReplayRange::Signatures {
+            first: Some(first), ..
+        } = range
+        {
+            signatures.push((*first, true));
+        }
+        signatures.dedup_by_key(|(signature, _)| *signature);
+
+        let mut events = Vec::new();
+        for (signature, succeeded) in signatures.into_iter().rev() {
+            if !succeeded {
+                continue;
+            }
+
+            let output = self.try_fetch_execution_output(&signature)?;
+            if output.result.is_err() {
+                continue;
+            }
+
+            events.extend(
+                output
+                    .events::<E>(program_id)
+                    .into_iter()
+                    .map(|event| (signature, event)),
+            );
+        }
+
+        Ok(events)
+    }
+}
+
+impl<C: ?Sized + Client> EventReplay for C {}
+
+pub struct EventSubscriber<'a, C: ?Sized, E> {
+    client: &'a C,
+    program_id: Pubkey,
+    last_signature: Option<Signature>,
+    _event: PhantomData<E>,
+}
+
+impl<'a, C, E> EventSubscriber<'a, C, E>
+where
+    C: ?Sized + GetSignaturesForAddress + GetTransaction,
+    E: Event,
+{
+    /// Observes only transactions after the subscription.
+    pub fn new(client: &'a C, program_id: Pubkey) -> ClientResult<Self> {
+        let mut subscriber = Self {
+            client,
+            program_id,
+            last_signature: None,
+            _event: PhantomData,
+        };
+        subscriber.last_signature = subscriber
+            .get_signatures(None, 1)?
+            .first()
+            .map(|(signature, _)| *signature);
+
+        Ok(subscriber)
+    }
+
+    /// Oldest first.
+    pub fn poll(&mut self) -> ClientResult<Vec<(Signature, E)>> {
+        const PAGE_LIMIT: usize = 1000;
+
+        // pages go back from the newest signature until the last one is reached
+        let mut signatures = Vec::new();
+        let mut before = None;
+        loop {
+            let page = self.get_signatures(before, PAGE_LIMIT)?;
+            let is_last_page = page.len() < PAGE_LIMIT;
+            before = page.last().map(|(signature, _)| *signature);
+            signatures.extend(page);
+
+            if is_last_page {
+                break;
+            }
+        }
+
+        let mut events = Vec::new();
+        for (signature, succeeded) in signatures.iter().rev() {
+            // events of failed transactions are logged but never took effect
+            if !succeeded {
+                continue;
+            }
+
+            let output = self.client.try_fetch_execution_output(signature)?;
+            events.extend(
+                output
+                    .events::<E>(&self.program_id)
+                    .into_iter()
+                    .map(|event| (*signature, event)),
+            );
+        }
+
+        // advanced only once every transaction is fetched so that a failed poll can be retried
+        if let Some((signature, _)) = signatures.first() {
+            self.last_signature = Some(*signature);
+        }
+
+        Ok(events)
+    }
+
+    // newest first, along with whether they succeeded
+    fn get_signatures(
+        &self,
+        before: Option<Signature>,
+        limit: usize,
+    ) -> ClientResult<Vec<(Signature, bool)>> {
+        let statuses = self.client.get_signatures_for_address(
+            &self.program_id,
+            before,
+            self.last_signature,
+            Some(limit),
+        )?;
+
+        statuses
+            .into_iter()
+            .map(|status| {
+                Signature::from_str(&status.signature)
+                    .map(|signature| (signature, status.err.is_none()))
+                    .map_err(|err| ClientError::DomainSpecific(err.into()))
+            })
+            .collect()
+    }
+}
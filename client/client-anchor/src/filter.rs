@@ -0,0 +1,29 @@
+use anchor_lang::AnchorSerialize;
+
+use dexter_client_api::base::getter::{Memcmp, ProgramAccountsFilter};
+
+pub fn memcmp_filter(offset: usize, value: &impl AnchorSerialize) -> ProgramAccountsFilter {
+    ProgramAccountsFilter::Memcmp(Memcmp::new_raw_bytes(offset, value.try_to_vec().unwrap()))
+}
+
+/// ```ignore
+/// let filters = vec![filter_on!(Vault::owner == owner), filter_on!(state::Vault::id == 7u64)];
+/// ```
+#[macro_export]
+macro_rules! filter_on {
+    ($first:ident $(:: $rest:ident)+ == $value:expr) => {
+        $crate::filter_on!(@split [$first] $($rest)+ ; $value)
+    };
+    // the last segment is the field, the preceding ones the path of the type
+    (@split [$($ty:tt)+] $field:ident ; $value:expr) => {{
+        let value = $value;
+        // never called, just ensures the value is of the field type
+        let _ = |data: &$($ty)+| {
+            let _ = [&data.$field, &value];
+        };
+        $crate::memcmp_filter(<$($ty)+>::FIELD_OFFSETS.$field, &value)
+    }};
+    (@split [$($ty:tt)+] $next:ident $($rest:ident)+ ; $value:expr) => {
+        $crate::filter_on!(@split [$($ty)+ :: $next] $($rest)+ ; $value)
+    };
+}
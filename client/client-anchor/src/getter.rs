@@ -1,14 +1,22 @@
+use std::collections::HashMap;
+use std::mem::size_of;
+
 use solana_sdk::pubkey::Pubkey;
 
-use anchor_lang::AccountDeserialize;
+use anchor_lang::{AccountDeserialize, Discriminator, Owner, ZeroCopy};
+
+use bytemuck::Pod;
 
 use dexter_client_api::base::getter::{
-    GetAccount, GetMultipleAccounts, GetProgramAccounts, ProgramAccountsFilter,
+    DataSlice, GetAccount, GetMultipleAccounts, GetProgramAccounts, Memcmp, ProgramAccountsFilter,
 };
 use dexter_client_api::errors::{ClientError, ClientResult};
 use dexter_client_api::Client;
 
-use crate::account::AnchorAccount;
+use crate::account::{AnchorAccount, ZeroCopyAccounts};
+use crate::audit::ProgramAccountsAudit;
+use crate::gpa::GpaBuilder;
+use crate::pda::Pda;
 
 pub trait AnchorGetter: Client {
     fn get_anchor_account<T>(&self, pubkey: &Pubkey) -> ClientResult<Option<AnchorAccount<T>>>
@@ -37,6 +45,65 @@ pub trait AnchorGetter: Client {
         }
     }
 
+    /// Skips the discriminator check, e.g. for data written before a migration.
+    fn get_anchor_account_unchecked<T>(
+        &self,
+        pubkey: &Pubkey,
+    ) -> ClientResult<Option<AnchorAccount<T>>>
+    where
+        Self: GetAccount,
+        T: AccountDeserialize,
+    {
+        let Some(account) = self.get_account(pubkey)? else {
+            return Ok(None);
+        };
+
+        let account = AnchorAccount::try_from_account_unchecked(*pubkey, account)
+            .map_err(|_| ClientError::AccountDidNotDeserialize(*pubkey))?;
+
+        Ok(Some(account))
+    }
+
+    fn try_get_anchor_account_unchecked<T>(&self, pubkey: &Pubkey) -> ClientResult<AnchorAccount<T>>
+    where
+        Self: GetAccount,
+        T: AccountDeserialize,
+    {
+        match self.get_anchor_account_unchecked(pubkey)? {
+            Some(account) => Ok(account),
+            None => Err(ClientError::AccountNotFound(*pubkey)),
+        }
+    }
+
+    /// Fails with an [`AccountCheckError`](crate::AccountCheckError) telling which check failed.
+    fn try_get_anchor_account_checked<T>(&self, pubkey: &Pubkey) -> ClientResult<AnchorAccount<T>>
+    where
+        Self: GetAccount,
+        T: AccountDeserialize + Discriminator + Owner,
+    {
+        let Some(account) = self.get_account(pubkey)? else {
+            return Err(ClientError::AccountNotFound(*pubkey));
+        };
+
+        Ok(AnchorAccount::try_from_account_checked(*pubkey, account)?)
+    }
+
+    fn get_pda_account<T>(&self, seeds: &T::Seeds) -> ClientResult<Option<AnchorAccount<T>>>
+    where
+        Self: GetAccount,
+        T: AccountDeserialize + Pda,
+    {
+        self.get_anchor_account(&T::find_pda_address(seeds))
+    }
+
+    fn try_get_pda_account<T>(&self, seeds: &T::Seeds) -> ClientResult<AnchorAccount<T>>
+    where
+        Self: GetAccount,
+        T: AccountDeserialize + Pda,
+    {
+        self.try_get_anchor_account(&T::find_pda_address(seeds))
+    }
+
     fn get_anchor_program_accounts<T>(
         &self,
         program_id: &Pubkey,
@@ -55,6 +122,160 @@ pub trait AnchorGetter: Client {
             .collect()
     }
 
+    /// Skips the other account types of the program.
+    fn get_anchor_program_accounts_filtered<T>(
+        &self,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+    ) -> ClientResult<Vec<AnchorAccount<T>>>
+    where
+        Self: GetProgramAccounts,
+        T: AccountDeserialize + Discriminator + Owner,
+    {
+        let program_id = T::owner();
+
+        let mut all_filters = vec![ProgramAccountsFilter::Memcmp(Memcmp::new_raw_bytes(
+            0,
+            T::DISCRIMINATOR.to_vec(),
+        ))];
+        all_filters.extend(filters.into_iter().flatten());
+
+        self.get_program_accounts(&program_id, Some(all_filters))?
+            .into_iter()
+            .filter(|(_, account)| account.owner == program_id)
+            .map(|(key, account)| {
+                AnchorAccount::try_from_account(key, account)
+                    .map_err(|_| ClientError::AccountDidNotDeserialize(key))
+            })
+            .collect()
+    }
+
+    fn get_anchor_zero_copy_program_accounts<T>(
+        &self,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+    ) -> ClientResult<ZeroCopyAccounts<T>>
+    where
+        Self: GetProgramAccounts,
+        T: ZeroCopy + Owner,
+    {
+        let program_id = T::owner();
+
+        let mut all_filters = vec![ProgramAccountsFilter::Memcmp(Memcmp::new_raw_bytes(
+            0,
+            T::DISCRIMINATOR.to_vec(),
+        ))];
+        all_filters.extend(filters.into_iter().flatten());
+
+        let accounts = self
+            .get_program_accounts(&program_id, Some(all_filters))?
+            .into_iter()
+            .filter(|(_, account)| account.owner == program_id)
+            .collect();
+
+        ZeroCopyAccounts::try_from_accounts(accounts).map_err(ClientError::AccountDidNotDeserialize)
+    }
+
+    /// Fetches only `P` at `offset` of `T`, e.g. the header of large orderbook accounts.
+    fn get_anchor_zero_copy_program_account_slices<T, P>(
+        &self,
+        offset: usize,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+    ) -> ClientResult<Vec<(Pubkey, P)>>
+    where
+        Self: GetProgramAccounts,
+        T: ZeroCopy + Owner,
+        P: Pod,
+    {
+        check_slice_bounds::<T, P>(offset)?;
+
+        let program_id = T::owner();
+
+        let mut all_filters = vec![ProgramAccountsFilter::Memcmp(Memcmp::new_raw_bytes(
+            0,
+            T::DISCRIMINATOR.to_vec(),
+        ))];
+        all_filters.extend(filters.into_iter().flatten());
+
+        let data_slice = DataSlice {
+            offset: 8 + offset,
+            length: size_of::<P>(),
+        };
+
+        self.get_program_accounts_sliced(&program_id, Some(all_filters), data_slice)?
+            .into_iter()
+            .filter(|(_, account)| account.owner == program_id)
+            .map(|(key, account)| {
+                bytemuck::try_pod_read_unaligned(&account.data)
+                    .map(|slice| (key, slice))
+                    .map_err(|_| ClientError::AccountDidNotDeserialize(key))
+            })
+            .collect()
+    }
+
+    /// The discriminator is not fetched and thus not checked.
+    fn get_anchor_zero_copy_multiple_account_slices<T, P>(
+        &self,
+        pubkeys: &[Pubkey],
+        offset: usize,
+    ) -> ClientResult<Vec<Option<P>>>
+    where
+        Self: GetMultipleAccounts,
+        T: ZeroCopy,
+        P: Pod,
+    {
+        check_slice_bounds::<T, P>(offset)?;
+
+        let data_slice = DataSlice {
+            offset: 8 + offset,
+            length: size_of::<P>(),
+        };
+
+        let accounts = self.get_multiple_accounts_sliced(pubkeys, data_slice)?;
+        pubkeys
+            .iter()
+            .copied()
+            .zip(accounts)
+            .map(|(key, account_opt)| {
+                let Some(account) = account_opt else {
+                    return Ok(None);
+                };
+
+                bytemuck::try_pod_read_unaligned(&account.data)
+                    .map(Some)
+                    .map_err(|_| ClientError::AccountDidNotDeserialize(key))
+            })
+            .collect()
+    }
+
+    fn audit_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        known: &[(&str, [u8; 8])],
+    ) -> ClientResult<ProgramAccountsAudit>
+    where
+        Self: GetProgramAccounts,
+    {
+        let data_slice = DataSlice {
+            offset: 0,
+            length: 8,
+        };
+
+        let accounts = self
+            .get_program_accounts_sliced(program_id, None, data_slice)?
+            .into_iter()
+            .filter(|(_, account)| account.owner == *program_id)
+            .collect();
+
+        Ok(ProgramAccountsAudit::new(*program_id, known, accounts))
+    }
+
+    fn gpa_builder<T>(&self) -> GpaBuilder<'_, Self, T>
+    where
+        Self: GetProgramAccounts + GetMultipleAccounts,
+        T: AccountDeserialize + Discriminator + Owner,
+    {
+        GpaBuilder::new(self)
+    }
+
     fn get_anchor_multiple_accounts<T>(
         &self,
         pubkeys: &[Pubkey],
@@ -81,6 +302,39 @@ pub trait AnchorGetter: Client {
             .collect()
     }
 
+    /// Fetches the `C` each `P` refers to by `foreign_key`, e.g. markets along with their vaults.
+    fn join_anchor_program_accounts<P, C, F>(
+        &self,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+        foreign_key: F,
+    ) -> ClientResult<Vec<(AnchorAccount<P>, Option<AnchorAccount<C>>)>>
+    where
+        Self: GetProgramAccounts + GetMultipleAccounts,
+        P: AccountDeserialize + Discriminator + Owner,
+        C: AccountDeserialize + Clone,
+        F: Fn(&P) -> Pubkey,
+    {
+        let parents = self.get_anchor_program_accounts_filtered::<P>(filters)?;
+
+        let mut keys: Vec<_> = parents.iter().map(|parent| foreign_key(parent)).collect();
+        keys.sort();
+        keys.dedup();
+
+        let children: HashMap<_, _> = keys
+            .iter()
+            .copied()
+            .zip(self.get_anchor_multiple_accounts::<C>(&keys)?)
+            .collect();
+
+        Ok(parents
+            .into_iter()
+            .map(|parent| {
+                let child = children[&foreign_key(&parent)].clone();
+                (parent, child)
+            })
+            .collect())
+    }
+
     fn try_get_anchor_multiple_accounts<T>(
         &self,
         pubkeys: &[Pubkey],
@@ -103,3 +357,17 @@ pub trait AnchorGetter: Client {
 }
 
 impl<C: ?Sized + Client> AnchorGetter for C {}
+
+fn check_slice_bounds<T, P>(offset: usize) -> ClientResult<()> {
+    match offset.checked_add(size_of::<P>()) {
+        Some(end) if end <= size_of::<T>() => Ok(()),
+        _ => Err(ClientError::DomainSpecific(
+            format!(
+                "slice of {} bytes at offset {offset} is out of bounds of {} bytes",
+                size_of::<P>(),
+                size_of::<T>(),
+            )
+            .into(),
+        )),
+    }
+}
@@ -0,0 +1,159 @@
+use std::marker::PhantomData;
+use std::vec::IntoIter;
+
+use solana_sdk::pubkey::Pubkey;
+
+use anchor_lang::{AccountDeserialize, Discriminator, Owner};
+
+use dexter_client_api::base::getter::{
+    GetMultipleAccounts, GetProgramAccounts, Memcmp, ProgramAccountsFilter,
+};
+use dexter_client_api::errors::ClientResult;
+
+use crate::account::AnchorAccount;
+use crate::getter::AnchorGetter;
+
+const DEFAULT_PAGE_SIZE: usize = 100;
+
+// fetches the matched keys first and their data page by page
+pub struct GpaBuilder<'a, C: ?Sized, T> {
+    client: &'a C,
+    program_id: Pubkey,
+    filters: Vec<ProgramAccountsFilter>,
+    limit: Option<usize>,
+    page_size: usize,
+    after: Option<Pubkey>,
+    _data: PhantomData<T>,
+}
+
+impl<'a, C, T> GpaBuilder<'a, C, T>
+where
+    C: ?Sized + GetProgramAccounts + GetMultipleAccounts,
+    T: AccountDeserialize + Discriminator + Owner,
+{
+    pub fn new(client: &'a C) -> Self {
+        Self {
+            client,
+            program_id: T::owner(),
+            filters: vec![ProgramAccountsFilter::Memcmp(Memcmp::new_raw_bytes(
+                0,
+                T::DISCRIMINATOR.to_vec(),
+            ))],
+            limit: None,
+            page_size: DEFAULT_PAGE_SIZE,
+            after: None,
+            _data: PhantomData,
+        }
+    }
+
+    pub fn program_id(mut self, program_id: Pubkey) -> Self {
+        self.program_id = program_id;
+        self
+    }
+
+    /// `offset` is past the discriminator.
+    pub fn memcmp(mut self, offset: usize, bytes: impl Into<Vec<u8>>) -> Self {
+        self.filters
+            .push(ProgramAccountsFilter::Memcmp(Memcmp::new_raw_bytes(
+                offset + 8,
+                bytes.into(),
+            )));
+        self
+    }
+
+    pub fn memcmp_pubkey(self, offset: usize, pubkey: &Pubkey) -> Self {
+        self.memcmp(offset, pubkey.to_bytes())
+    }
+
+    /// Includes the discriminator.
+    pub fn data_size(mut self, data_size: u64) -> Self {
+        self.filters
+            .push(ProgramAccountsFilter::DataSize(data_size));
+        self
+    }
+
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        assert!(page_size > 0, "page size must be positive");
+        self.page_size = page_size;
+        self
+    }
+
+    pub fn after(mut self, cursor: Pubkey) -> Self {
+        self.after = Some(cursor);
+        self
+    }
+
+    pub fn iter(self) -> ClientResult<GpaIter<'a, C, T>> {
+        let keys = self
+            .client
+            .get_program_account_keys(&self.program_id, Some(self.filters))?;
+
+        let skip = match self.after {
+            Some(cursor) => keys.partition_point(|key| *key <= cursor),
+            None => 0,
+        };
+        let take = self.limit.unwrap_or(usize::MAX);
+        let keys: Vec<_> = keys.into_iter().skip(skip).take(take).collect();
+
+        Ok(GpaIter {
+            client: self.client,
+            pending: keys.into_iter(),
+            page: Vec::new().into_iter(),
+            page_size: self.page_size,
+            cursor: self.after,
+        })
+    }
+}
+
+pub struct GpaIter<'a, C: ?Sized, T> {
+    client: &'a C,
+    pending: IntoIter<Pubkey>,
+    page: IntoIter<AnchorAccount<T>>,
+    page_size: usize,
+    cursor: Option<Pubkey>,
+}
+
+impl<'a, C: ?Sized, T> GpaIter<'a, C, T> {
+    pub fn cursor(&self) -> Option<Pubkey> {
+        self.cursor
+    }
+}
+
+impl<'a, C, T> Iterator for GpaIter<'a, C, T>
+where
+    C: ?Sized + GetMultipleAccounts,
+    T: AccountDeserialize,
+{
+    type Item = ClientResult<AnchorAccount<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(account) = self.page.next() {
+                self.cursor = Some(*AnchorAccount::as_parts(&account).0);
+                return Some(Ok(account));
+            }
+
+            let keys: Vec<_> = self.pending.by_ref().take(self.page_size).collect();
+            if keys.is_empty() {
+                return None;
+            }
+
+            // accounts closed since the key scan are skipped
+            match self.client.get_anchor_multiple_accounts(&keys) {
+                Ok(accounts) => {
+                    self.page = accounts
+                        .into_iter()
+                        .flatten()
+                        .collect::<Vec<_>>()
+                        .into_iter();
+                }
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
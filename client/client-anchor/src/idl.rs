@@ -0,0 +1,841 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer};
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+
+use dexter_client_api::base::getter::GetAccount;
+use dexter_client_api::errors::{ClientError, ClientResult};
+
+// bounds the defined types entered while encoding or decoding, e.g. through an alias of itself
+const MAX_TYPE_DEPTH: usize = 64;
+
+#[derive(Debug, Error)]
+pub enum IdlError {
+    #[error("Invalid idl: {0}")]
+    InvalidIdl(#[from] serde_json::Error),
+    #[error("Invalid address {0:?}")]
+    InvalidAddress(String),
+    #[error("Instruction {0:?} not found")]
+    InstructionNotFound(String),
+    #[error("Account type {0:?} not found")]
+    AccountTypeNotFound(String),
+    #[error("Type {0:?} not found")]
+    TypeNotFound(String),
+    #[error("Missing account {0:?}")]
+    MissingAccount(String),
+    #[error("Missing argument {0:?}")]
+    MissingArgument(String),
+    #[error("Invalid value {1} for {0}")]
+    InvalidValue(String, Value),
+    #[error("Unsupported: {0}")]
+    Unsupported(String),
+    #[error("Type {0:?} nests too deep")]
+    TypeTooDeep(String),
+    #[error("The data does not match any account type")]
+    UnknownDiscriminator,
+    #[error("The data ended unexpectedly")]
+    UnexpectedEnd,
+}
+
+impl From<IdlError> for ClientError {
+    fn from(error: IdlError) -> Self {
+        ClientError::DomainSpecific(Box::new(error))
+    }
+}
+
+/// The format of anchor 0.30 and later.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Idl {
+    pub address: String,
+    #[serde(default)]
+    pub instructions: Vec<IdlInstruction>,
+    #[serde(default)]
+    pub accounts: Vec<IdlAccount>,
+    #[serde(default)]
+    pub types: Vec<IdlTypeDef>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdlInstruction {
+    pub name: String,
+    pub discriminator: Vec<u8>,
+    pub accounts: Vec<IdlInstructionAccountItem>,
+    pub args: Vec<IdlField>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum IdlInstructionAccountItem {
+    Composite(IdlInstructionAccounts),
+    Single(IdlInstructionAccount),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdlInstructionAccounts {
+    pub name: String,
+    pub accounts: Vec<IdlInstructionAccountItem>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdlInstructionAccount {
+    pub name: String,
+    #[serde(default)]
+    pub writable: bool,
+    #[serde(default)]
+    pub signer: bool,
+    #[serde(default)]
+    pub optional: bool,
+    pub address: Option<String>,
+    pub pda: Option<IdlPda>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdlPda {
+    pub seeds: Vec<IdlSeed>,
+    pub program: Option<IdlSeed>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum IdlSeed {
+    Const { value: Vec<u8> },
+    Arg { path: String },
+    Account { path: String },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdlAccount {
+    pub name: String,
+    pub discriminator: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdlField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: IdlType,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdlTypeDef {
+    pub name: String,
+    #[serde(default = "default_serialization")]
+    pub serialization: String,
+    #[serde(rename = "type")]
+    pub ty: IdlTypeDefTy,
+}
+
+fn default_serialization() -> String {
+    "borsh".to_string()
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum IdlTypeDefTy {
+    Struct { fields: Option<IdlDefinedFields> },
+    Enum { variants: Vec<IdlEnumVariant> },
+    Type { alias: IdlType },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdlEnumVariant {
+    pub name: String,
+    pub fields: Option<IdlDefinedFields>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum IdlDefinedFields {
+    Named(Vec<IdlField>),
+    Tuple(Vec<IdlType>),
+}
+
+#[derive(Debug, Clone)]
+pub enum IdlType {
+    Bool,
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    F32,
+    U64,
+    I64,
+    F64,
+    U128,
+    I128,
+    Bytes,
+    String,
+    Pubkey,
+    Option(Box<IdlType>),
+    Vec(Box<IdlType>),
+    Array(Box<IdlType>, usize),
+    Defined { name: String },
+    // e.g. generics, which fail only once encoded or decoded
+    Unsupported(Value),
+}
+
+impl<'de> Deserialize<'de> for IdlType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Value::deserialize(deserializer).map(IdlType::from_value)
+    }
+}
+
+impl IdlType {
+    fn from_value(value: Value) -> Self {
+        let ty = match &value {
+            Value::String(name) => match name.as_str() {
+                "bool" => Some(IdlType::Bool),
+                "u8" => Some(IdlType::U8),
+                "i8" => Some(IdlType::I8),
+                "u16" => Some(IdlType::U16),
+                "i16" => Some(IdlType::I16),
+                "u32" => Some(IdlType::U32),
+                "i32" => Some(IdlType::I32),
+                "f32" => Some(IdlType::F32),
+                "u64" => Some(IdlType::U64),
+                "i64" => Some(IdlType::I64),
+                "f64" => Some(IdlType::F64),
+                "u128" => Some(IdlType::U128),
+                "i128" => Some(IdlType::I128),
+                "bytes" => Some(IdlType::Bytes),
+                "string" => Some(IdlType::String),
+                "pubkey" => Some(IdlType::Pubkey),
+                _ => None,
+            },
+            Value::Object(map) if map.len() == 1 => match map.iter().next().unwrap() {
+                (kind, inner) if kind == "option" => {
+                    Some(IdlType::Option(Box::new(Self::from_value(inner.clone()))))
+                }
+                (kind, inner) if kind == "vec" => {
+                    Some(IdlType::Vec(Box::new(Self::from_value(inner.clone()))))
+                }
+                (kind, Value::Array(items)) if kind == "array" => match items.as_slice() {
+                    [inner, len] => len.as_u64().map(|len| {
+                        IdlType::Array(Box::new(Self::from_value(inner.clone())), len as usize)
+                    }),
+                    _ => None,
+                },
+                (kind, defined) if kind == "defined" => {
+                    let has_generics = defined
+                        .get("generics")
+                        .and_then(Value::as_array)
+                        .is_some_and(|generics| !generics.is_empty());
+                    match defined.get("name").and_then(Value::as_str) {
+                        Some(name) if !has_generics => Some(IdlType::Defined {
+                            name: name.to_string(),
+                        }),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+
+        ty.unwrap_or(IdlType::Unsupported(value))
+    }
+}
+
+/// Integers wider than 64 bits are strings.
+#[derive(Debug, Clone)]
+pub struct IdlClient {
+    idl: Idl,
+    program_id: Pubkey,
+}
+
+impl IdlClient {
+    pub fn new(idl: Idl) -> ClientResult<Self> {
+        let program_id = parse_pubkey(&idl.address)?;
+        Ok(Self { idl, program_id })
+    }
+
+    pub fn from_json(json: &str) -> ClientResult<Self> {
+        let idl = serde_json::from_str(json).map_err(IdlError::from)?;
+        Self::new(idl)
+    }
+
+    pub fn idl(&self) -> &Idl {
+        &self.idl
+    }
+
+    pub fn program_id(&self) -> Pubkey {
+        self.program_id
+    }
+
+    /// Nested accounts are named by their dotted path, e.g. `"vault.authority"`.
+    pub fn build_instruction(
+        &self,
+        name: &str,
+        accounts: &[(&str, Pubkey)],
+        args: &Value,
+    ) -> ClientResult<Instruction> {
+        let instruction = self.instruction(name)?;
+
+        let mut data = instruction.discriminator.clone();
+        for arg in &instruction.args {
+            let value = args
+                .get(&arg.name)
+                .ok_or_else(|| IdlError::MissingArgument(arg.name.clone()))?;
+            self.encode(&arg.ty, value, &mut data, 0)?;
+        }
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts: self.resolve_accounts(name, accounts, args)?,
+            data,
+        })
+    }
+
+    pub fn resolve_accounts(
+        &self,
+        name: &str,
+        accounts: &[(&str, Pubkey)],
+        args: &Value,
+    ) -> ClientResult<Vec<AccountMeta>> {
+        let instruction = self.instruction(name)?;
+
+        let mut flattened = Vec::new();
+        flatten_accounts(&instruction.accounts, "", &mut flattened);
+
+        let mut resolved: HashMap<String, Pubkey> = accounts
+            .iter()
+            .map(|(path, pubkey)| (path.to_string(), *pubkey))
+            .collect();
+
+        // seeds may refer to accounts which are themselves derived
+        loop {
+            let mut progressed = false;
+            for (path, account) in &flattened {
+                if resolved.contains_key(path) {
+                    continue;
+                }
+
+                let address = match (&account.address, &account.pda) {
+                    (Some(address), _) => Some(parse_pubkey(address)?),
+                    (None, Some(pda)) => self.derive_pda(instruction, pda, &resolved, args)?,
+                    (None, None) => None,
+                };
+                if let Some(address) = address {
+                    resolved.insert(path.clone(), address);
+                    progressed = true;
+                }
+            }
+
+            if !progressed {
+                break;
+            }
+        }
+
+        flattened
+            .iter()
+            .map(|(path, account)| match resolved.get(path) {
+                Some(pubkey) => Ok(AccountMeta {
+                    pubkey: *pubkey,
+                    is_signer: account.signer,
+                    is_writable: account.writable,
+                }),
+                // anchor takes the program id in place of a missing optional account
+                None if account.optional => Ok(AccountMeta::new_readonly(self.program_id, false)),
+                None => Err(IdlError::MissingAccount(path.clone()).into()),
+            })
+            .collect()
+    }
+
+    pub fn decode_account(&self, data: &[u8]) -> ClientResult<(String, Value)> {
+        let account = self
+            .idl
+            .accounts
+            .iter()
+            .find(|account| data.starts_with(&account.discriminator))
+            .ok_or(IdlError::UnknownDiscriminator)?;
+
+        let value = self.decode_account_as(&account.name, data)?;
+        Ok((account.name.clone(), value))
+    }
+
+    pub fn decode_account_as(&self, name: &str, data: &[u8]) -> ClientResult<Value> {
+        let account = self
+            .idl
+            .accounts
+            .iter()
+            .find(|account| account.name == name)
+            .ok_or_else(|| IdlError::AccountTypeNotFound(name.to_string()))?;
+
+        let mut payload = data
+            .strip_prefix(account.discriminator.as_slice())
+            .ok_or(IdlError::UnknownDiscriminator)?;
+        let value = self.decode(
+            &IdlType::Defined {
+                name: account.name.clone(),
+            },
+            &mut payload,
+            0,
+        )?;
+
+        Ok(value)
+    }
+
+    pub fn fetch_account(
+        &self,
+        client: &impl GetAccount,
+        pubkey: &Pubkey,
+    ) -> ClientResult<Option<(String, Value)>> {
+        let Some(account) = client.get_account(pubkey)? else {
+            return Ok(None);
+        };
+
+        if account.owner != self.program_id {
+            return Err(ClientError::AccountDidNotDeserialize(*pubkey));
+        }
+
+        self.decode_account(&account.data).map(Some)
+    }
+
+    fn instruction(&self, name: &str) -> Result<&IdlInstruction, IdlError> {
+        self.idl
+            .instructions
+            .iter()
+            .find(|instruction| instruction.name == name)
+            .ok_or_else(|| IdlError::InstructionNotFound(name.to_string()))
+    }
+
+    fn type_def(&self, name: &str, depth: usize) -> Result<&IdlTypeDef, IdlError> {
+        if depth > MAX_TYPE_DEPTH {
+            return Err(IdlError::TypeTooDeep(name.to_string()));
+        }
+
+        let type_def = self
+            .idl
+            .types
+            .iter()
+            .find(|type_def| type_def.name == name)
+            .ok_or_else(|| IdlError::TypeNotFound(name.to_string()))?;
+
+        if type_def.serialization != "borsh" {
+            return Err(IdlError::Unsupported(format!(
+                "{} serialization of {name}",
+                type_def.serialization
+            )));
+        }
+
+        Ok(type_def)
+    }
+
+    // `None` if a seed refers to an account not yet resolved
+    fn derive_pda(
+        &self,
+        instruction: &IdlInstruction,
+        pda: &IdlPda,
+        resolved: &HashMap<String, Pubkey>,
+        args: &Value,
+    ) -> Result<Option<Pubkey>, IdlError> {
+        let mut seeds = Vec::with_capacity(pda.seeds.len());
+        for seed in &pda.seeds {
+            match self.seed_bytes(instruction, seed, resolved, args)? {
+                Some(bytes) => seeds.push(bytes),
+                None => return Ok(None),
+            }
+        }
+
+        let program_id = match &pda.program {
+            Some(seed) => match self.seed_bytes(instruction, seed, resolved, args)? {
+                Some(bytes) => Pubkey::try_from(bytes.as_slice()).map_err(|_| {
+                    IdlError::InvalidValue("program".to_string(), Value::from(bytes))
+                })?,
+                None => return Ok(None),
+            },
+            None => self.program_id,
+        };
+
+        let seeds: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+        Ok(Some(Pubkey::find_program_address(&seeds, &program_id).0))
+    }
+
+    fn seed_bytes(
+        &self,
+        instruction: &IdlInstruction,
+        seed: &IdlSeed,
+        resolved: &HashMap<String, Pubkey>,
+        args: &Value,
+    ) -> Result<Option<Vec<u8>>, IdlError> {
+        match seed {
+            IdlSeed::Const { value } => Ok(Some(value.clone())),
+            IdlSeed::Account { path } => {
+                if path.contains('.') {
+                    return Err(IdlError::Unsupported(format!(
+                        "seed from the account field {path}"
+                    )));
+                }
+                Ok(resolved.get(path).map(|pubkey| pubkey.to_bytes().to_vec()))
+            }
+            IdlSeed::Arg { path } => {
+                let mut segments = path.split('.');
+                let name = segments.next().unwrap_or_default();
+                let arg = instruction
+                    .args
+                    .iter()
+                    .find(|arg| arg.name == name)
+                    .ok_or_else(|| IdlError::MissingArgument(path.clone()))?;
+
+                let mut ty = arg.ty.clone();
+                let mut value = args
+                    .get(name)
+                    .ok_or_else(|| IdlError::MissingArgument(path.clone()))?;
+                for field in segments {
+                    ty = self.field_type(&ty, field, 0)?;
+                    value = value
+                        .get(field)
+                        .ok_or_else(|| IdlError::MissingArgument(path.clone()))?;
+                }
+
+                // strings and bytes are seeded without their length prefix
+                let bytes = match (&ty, value) {
+                    (IdlType::String, Value::String(s)) => s.as_bytes().to_vec(),
+                    (IdlType::Bytes, _) => bytes_from_value(&ty, value)?,
+                    _ => {
+                        let mut bytes = Vec::new();
+                        self.encode(&ty, value, &mut bytes, 0)?;
+                        bytes
+                    }
+                };
+                Ok(Some(bytes))
+            }
+        }
+    }
+
+    fn field_type(&self, ty: &IdlType, field: &str, depth: usize) -> Result<IdlType, IdlError> {
+        let IdlType::Defined { name } = ty else {
+            return Err(IdlError::TypeNotFound(field.to_string()));
+        };
+
+        match &self.type_def(name, depth)?.ty {
+            IdlTypeDefTy::Struct {
+                fields: Some(IdlDefinedFields::Named(fields)),
+            } => fields
+                .iter()
+                .find(|f| f.name == field)
+                .map(|f| f.ty.clone())
+                .ok_or_else(|| IdlError::TypeNotFound(field.to_string())),
+            IdlTypeDefTy::Type { alias } => self.field_type(alias, field, depth + 1),
+            _ => Err(IdlError::TypeNotFound(field.to_string())),
+        }
+    }
+
+    fn encode(
+        &self,
+        ty: &IdlType,
+        value: &Value,
+        out: &mut Vec<u8>,
+        depth: usize,
+    ) -> Result<(), IdlError> {
+        let invalid = || IdlError::InvalidValue(format!("{ty:?}"), value.clone());
+
+        macro_rules! encode_int {
+            ($t:ty, $parse:ident) => {{
+                let n = $parse(value)
+                    .and_then(|n| <$t>::try_from(n).ok())
+                    .ok_or_else(invalid)?;
+                out.extend_from_slice(&n.to_le_bytes());
+            }};
+        }
+
+        match ty {
+            IdlType::Bool => out.push(u8::from(value.as_bool().ok_or_else(invalid)?)),
+            IdlType::U8 => encode_int!(u8, unsigned),
+            IdlType::I8 => encode_int!(i8, signed),
+            IdlType::U16 => encode_int!(u16, unsigned),
+            IdlType::I16 => encode_int!(i16, signed),
+            IdlType::U32 => encode_int!(u32, unsigned),
+            IdlType::I32 => encode_int!(i32, signed),
+            IdlType::U64 => encode_int!(u64, unsigned),
+            IdlType::I64 => encode_int!(i64, signed),
+            IdlType::U128 => encode_int!(u128, unsigned),
+            IdlType::I128 => encode_int!(i128, signed),
+            IdlType::F32 => {
+                let n = value.as_f64().ok_or_else(invalid)? as f32;
+                out.extend_from_slice(&n.to_le_bytes());
+            }
+            IdlType::F64 => {
+                let n = value.as_f64().ok_or_else(invalid)?;
+                out.extend_from_slice(&n.to_le_bytes());
+            }
+            IdlType::String => {
+                let s = value.as_str().ok_or_else(invalid)?;
+                encode_len(s.len(), out);
+                out.extend_from_slice(s.as_bytes());
+            }
+            IdlType::Bytes => {
+                let bytes = bytes_from_value(ty, value)?;
+                encode_len(bytes.len(), out);
+                out.extend_from_slice(&bytes);
+            }
+            IdlType::Pubkey => {
+                let pubkey = value
+                    .as_str()
+                    .and_then(|s| Pubkey::from_str(s).ok())
+                    .ok_or_else(invalid)?;
+                out.extend_from_slice(pubkey.as_ref());
+            }
+            IdlType::Option(inner) => match value {
+                Value::Null => out.push(0),
+                value => {
+                    out.push(1);
+                    self.encode(inner, value, out, depth)?;
+                }
+            },
+            IdlType::Vec(inner) => {
+                let items = value.as_array().ok_or_else(invalid)?;
+                encode_len(items.len(), out);
+                for item in items {
+                    self.encode(inner, item, out, depth)?;
+                }
+            }
+            IdlType::Array(inner, len) => {
+                let items = value
+                    .as_array()
+                    .filter(|items| items.len() == *len)
+                    .ok_or_else(invalid)?;
+                for item in items {
+                    self.encode(inner, item, out, depth)?;
+                }
+            }
+            IdlType::Defined { name } => match &self.type_def(name, depth)?.ty {
+                IdlTypeDefTy::Struct { fields } => {
+                    self.encode_fields(fields, value, out, depth + 1)?
+                }
+                IdlTypeDefTy::Enum { variants } => {
+                    let (variant_name, fields_value) = match value {
+                        Value::String(variant_name) => (variant_name.as_str(), &Value::Null),
+                        Value::Object(map) if map.len() == 1 => {
+                            let (variant_name, fields_value) = map.iter().next().unwrap();
+                            (variant_name.as_str(), fields_value)
+                        }
+                        _ => return Err(invalid()),
+                    };
+                    let (index, variant) = variants
+                        .iter()
+                        .enumerate()
+                        .find(|(_, variant)| variant.name == variant_name)
+                        .ok_or_else(invalid)?;
+
+                    out.push(u8::try_from(index).map_err(|_| invalid())?);
+                    self.encode_fields(&variant.fields, fields_value, out, depth + 1)?;
+                }
+                IdlTypeDefTy::Type { alias } => self.encode(alias, value, out, depth + 1)?,
+            },
+            IdlType::Unsupported(ty) => return Err(IdlError::Unsupported(format!("type {ty}"))),
+        }
+
+        Ok(())
+    }
+
+    fn encode_fields(
+        &self,
+        fields: &Option<IdlDefinedFields>,
+        value: &Value,
+        out: &mut Vec<u8>,
+        depth: usize,
+    ) -> Result<(), IdlError> {
+        match fields {
+            None => {}
+            Some(IdlDefinedFields::Named(fields)) => {
+                for field in fields {
+                    let field_value = value
+                        .get(&field.name)
+                        .ok_or_else(|| IdlError::MissingArgument(field.name.clone()))?;
+                    self.encode(&field.ty, field_value, out, depth)?;
+                }
+            }
+            Some(IdlDefinedFields::Tuple(types)) => {
+                let items = value
+                    .as_array()
+                    .filter(|items| items.len() == types.len())
+                    .ok_or_else(|| IdlError::InvalidValue(format!("{types:?}"), value.clone()))?;
+                for (ty, item) in types.iter().zip(items) {
+                    self.encode(ty, item, out, depth)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn decode(&self, ty: &IdlType, data: &mut &[u8], depth: usize) -> Result<Value, IdlError> {
+        macro_rules! decode_int {
+            ($t:ty) => {{
+                let bytes = take(data, std::mem::size_of::<$t>())?;
+                <$t>::from_le_bytes(bytes.try_into().unwrap())
+            }};
+        }
+
+        let value = match ty {
+            IdlType::Bool => Value::Bool(take(data, 1)?[0] != 0),
+            IdlType::U8 => Value::from(decode_int!(u8)),
+            IdlType::I8 => Value::from(decode_int!(i8)),
+            IdlType::U16 => Value::from(decode_int!(u16)),
+            IdlType::I16 => Value::from(decode_int!(i16)),
+            IdlType::U32 => Value::from(decode_int!(u32)),
+            IdlType::I32 => Value::from(decode_int!(i32)),
+            IdlType::U64 => Value::from(decode_int!(u64)),
+            IdlType::I64 => Value::from(decode_int!(i64)),
+            IdlType::U128 => Value::String(decode_int!(u128).to_string()),
+            IdlType::I128 => Value::String(decode_int!(i128).to_string()),
+            IdlType::F32 => Value::from(decode_int!(f32)),
+            IdlType::F64 => Value::from(decode_int!(f64)),
+            IdlType::String => {
+                let len = decode_int!(u32) as usize;
+                let bytes = take(data, len)?;
+                let s = String::from_utf8(bytes.to_vec())
+                    .map_err(|_| IdlError::InvalidValue(format!("{ty:?}"), Value::from(bytes)))?;
+                Value::String(s)
+            }
+            IdlType::Bytes => {
+                let len = decode_int!(u32) as usize;
+                Value::from(take(data, len)?)
+            }
+            IdlType::Pubkey => {
+                let pubkey = Pubkey::try_from(take(data, 32)?).unwrap();
+                Value::String(pubkey.to_string())
+            }
+            IdlType::Option(inner) => match decode_int!(u8) {
+                0 => Value::Null,
+                _ => self.decode(inner, data, depth)?,
+            },
+            IdlType::Vec(inner) => {
+                let len = decode_int!(u32) as usize;
+                let items = (0..len)
+                    .map(|_| self.decode(inner, data, depth))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Value::Array(items)
+            }
+            IdlType::Array(inner, len) => {
+                let items = (0..*len)
+                    .map(|_| self.decode(inner, data, depth))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Value::Array(items)
+            }
+            IdlType::Defined { name } => match &self.type_def(name, depth)?.ty {
+                IdlTypeDefTy::Struct { fields } => self.decode_fields(fields, data, depth + 1)?,
+                IdlTypeDefTy::Enum { variants } => {
+                    let index = decode_int!(u8) as usize;
+                    let variant = variants
+                        .get(index)
+                        .ok_or_else(|| IdlError::InvalidValue(name.clone(), Value::from(index)))?;
+                    match &variant.fields {
+                        None => Value::String(variant.name.clone()),
+                        fields => {
+                            let mut map = Map::new();
+                            map.insert(
+                                variant.name.clone(),
+                                self.decode_fields(fields, data, depth + 1)?,
+                            );
+                            Value::Object(map)
+                        }
+                    }
+                }
+                IdlTypeDefTy::Type { alias } => self.decode(alias, data, depth + 1)?,
+            },
+            IdlType::Unsupported(ty) => return Err(IdlError::Unsupported(format!("type {ty}"))),
+        };
+
+        Ok(value)
+    }
+
+    fn decode_fields(
+        &self,
+        fields: &Option<IdlDefinedFields>,
+        data: &mut &[u8],
+        depth: usize,
+    ) -> Result<Value, IdlError> {
+        let value = match fields {
+            None => Value::Null,
+            Some(IdlDefinedFields::Named(fields)) => {
+                let mut map = Map::new();
+                for field in fields {
+                    map.insert(field.name.clone(), self.decode(&field.ty, data, depth)?);
+                }
+                Value::Object(map)
+            }
+            Some(IdlDefinedFields::Tuple(types)) => {
+                let items = types
+                    .iter()
+                    .map(|ty| self.decode(ty, data, depth))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Value::Array(items)
+            }
+        };
+
+        Ok(value)
+    }
+}
+
+fn flatten_accounts<'a>(
+    items: &'a [IdlInstructionAccountItem],
+    prefix: &str,
+    flattened: &mut Vec<(String, &'a IdlInstructionAccount)>,
+) {
+    for item in items {
+        match item {
+            IdlInstructionAccountItem::Single(account) => {
+                flattened.push((format!("{prefix}{}", account.name), account));
+            }
+            IdlInstructionAccountItem::Composite(composite) => {
+                let prefix = format!("{prefix}{}.", composite.name);
+                flatten_accounts(&composite.accounts, &prefix, flattened);
+            }
+        }
+    }
+}
+
+fn parse_pubkey(address: &str) -> Result<Pubkey, IdlError> {
+    Pubkey::from_str(address).map_err(|_| IdlError::InvalidAddress(address.to_string()))
+}
+
+fn unsigned(value: &Value) -> Option<u128> {
+    match value {
+        Value::Number(n) => n.as_u64().map(u128::from),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+fn signed(value: &Value) -> Option<i128> {
+    match value {
+        Value::Number(n) => n.as_i64().map(i128::from),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+fn bytes_from_value(ty: &IdlType, value: &Value) -> Result<Vec<u8>, IdlError> {
+    value
+        .as_array()
+        .and_then(|items| {
+            items
+                .iter()
+                .map(|item| item.as_u64().and_then(|n| u8::try_from(n).ok()))
+                .collect::<Option<Vec<_>>>()
+        })
+        .ok_or_else(|| IdlError::InvalidValue(format!("{ty:?}"), value.clone()))
+}
+
+fn encode_len(len: usize, out: &mut Vec<u8>) {
+    out.extend_from_slice(&(len as u32).to_le_bytes());
+}
+
+fn take<'a>(data: &mut &'a [u8], len: usize) -> Result<&'a [u8], IdlError> {
+    if data.len() < len {
+        return Err(IdlError::UnexpectedEnd);
+    }
+
+    let (head, tail) = data.split_at(len);
+    *data = tail;
+    Ok(head)
+}
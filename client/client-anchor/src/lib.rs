@@ -1,7 +1,30 @@
 mod account;
+mod audit;
+mod diff;
+mod filter;
 mod getter;
+mod gpa;
+mod pda;
+mod processor;
 mod setter;
 
+pub mod events;
+pub mod idl;
+
 pub use account::*;
+pub use audit::*;
+pub use diff::*;
+pub use filter::*;
 pub use getter::*;
+pub use gpa::*;
+pub use pda::*;
+pub use processor::*;
 pub use setter::*;
+
+// paths of the code generated by `dexter-client-macros`, which resolve through re-exports too
+#[doc(hidden)]
+pub mod __private {
+    pub use anchor_lang;
+    pub use dexter_client_api;
+    pub use solana_sdk;
+}
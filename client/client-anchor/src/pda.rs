@@ -0,0 +1,40 @@
+use solana_sdk::pubkey::Pubkey;
+
+use anchor_lang::Owner;
+
+/// Usually implemented with [`declare_pda!`](crate::declare_pda).
+pub trait Pda: Owner {
+    type Seeds;
+
+    fn seeds(seeds: &Self::Seeds) -> Vec<Vec<u8>>;
+
+    fn find_pda(seeds: &Self::Seeds) -> (Pubkey, u8) {
+        let seeds = Self::seeds(seeds);
+        let seeds: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+        Pubkey::find_program_address(&seeds, &Self::owner())
+    }
+
+    fn find_pda_address(seeds: &Self::Seeds) -> Pubkey {
+        Self::find_pda(seeds).0
+    }
+}
+
+/// ```ignore
+/// declare_pda!(Vault, (owner: Pubkey, id: u64) => [b"vault", owner, id.to_le_bytes()]);
+///
+/// let (vault, bump) = Vault::find_pda(&(owner, 7));
+/// ```
+#[macro_export]
+macro_rules! declare_pda {
+    ($ty:ty, ($($name:ident: $arg:ty),* $(,)?) => [$($seed:expr),* $(,)?]) => {
+        impl $crate::Pda for $ty {
+            type Seeds = ($($arg,)*);
+
+            #[allow(unused_variables)]
+            fn seeds(seeds: &Self::Seeds) -> Vec<Vec<u8>> {
+                let ($($name,)*) = seeds;
+                vec![$(::core::convert::AsRef::<[u8]>::as_ref(&$seed).to_vec()),*]
+            }
+        }
+    };
+}
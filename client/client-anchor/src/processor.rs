@@ -0,0 +1,115 @@
+use std::io::{self, Read};
+
+use thiserror::Error;
+
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+
+use anchor_lang::{AnchorDeserialize, InstructionData, ToAccountMetas};
+
+use dexter_client_api::base::executor::{ProcessTransaction, SimulateTransaction};
+use dexter_client_api::base::getter::GetLatestBlockhash;
+use dexter_client_api::errors::{ClientError, ClientResult};
+use dexter_client_api::execution::ExecutionOutput;
+use dexter_client_api::exts::executor::{CompileTransaction, CompilingProcessTransaction};
+use dexter_client_api::exts::signer::FeePayer;
+use dexter_client_api::Client;
+
+pub trait AnchorInstruction: Client {
+    fn build_anchor_instruction<A, D>(
+        &self,
+        program_id: Pubkey,
+        accounts: A,
+        args: D,
+    ) -> Instruction
+    where
+        A: ToAccountMetas,
+        D: InstructionData,
+    {
+        Instruction {
+            program_id,
+            accounts: accounts.to_account_metas(None),
+            data: args.data(),
+        }
+    }
+}
+
+impl<C: ?Sized + Client> AnchorInstruction for C {}
+
+pub trait AnchorProcessor: Client {
+    fn process_anchor_instruction<A, D>(
+        &self,
+        payer: &impl FeePayer,
+        program_id: Pubkey,
+        accounts: A,
+        args: D,
+        signers: &[&dyn Signer],
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+        A: ToAccountMetas,
+        D: InstructionData,
+    {
+        let instructions = [self.build_anchor_instruction(program_id, accounts, args)];
+
+        let mut all_signers: Vec<&dyn Signer> = vec![payer.payer()];
+        all_signers.extend_from_slice(signers);
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &all_signers, &[])
+    }
+}
+
+impl<C: ?Sized + Client> AnchorProcessor for C {}
+
+#[derive(Debug, Error)]
+pub enum ViewError {
+    #[error("The return data was set by {0} instead of the view program")]
+    ForeignReturnData(Pubkey),
+    #[error("Failed to deserialize the return data: {0}")]
+    ReturnDataDidNotDeserialize(io::Error),
+}
+
+impl From<ViewError> for ClientError {
+    fn from(error: ViewError) -> Self {
+        ClientError::DomainSpecific(Box::new(error))
+    }
+}
+
+pub trait AnchorView: Client {
+    /// The payer needs to exist but does not sign.
+    fn simulate_view<Ret, A, D>(
+        &self,
+        payer: &Pubkey,
+        program_id: Pubkey,
+        accounts: A,
+        args: D,
+    ) -> ClientResult<Ret>
+    where
+        Self: GetLatestBlockhash + SimulateTransaction<ExecutionOutput>,
+        Ret: AnchorDeserialize,
+        A: ToAccountMetas,
+        D: InstructionData,
+    {
+        let instructions = [self.build_anchor_instruction(program_id, accounts, args)];
+        let transaction = self.compile_unsigned_transaction(&instructions, payer, &[])?;
+
+        let output: ExecutionOutput = self.simulate_transaction_unverified(transaction)?;
+        output.result?;
+
+        // the runtime strips trailing zeros from the return data, and drops it if nothing is left
+        let data = match output.return_data {
+            Some(return_data) if return_data.program_id != program_id => {
+                return Err(ViewError::ForeignReturnData(return_data.program_id).into());
+            }
+            Some(return_data) => return_data.data,
+            None => Vec::new(),
+        };
+
+        let ret = Ret::deserialize_reader(&mut data.as_slice().chain(io::repeat(0)))
+            .map_err(ViewError::ReturnDataDidNotDeserialize)?;
+
+        Ok(ret)
+    }
+}
+
+impl<C: ?Sized + Client> AnchorView for C {}
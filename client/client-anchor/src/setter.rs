@@ -1,11 +1,17 @@
 use solana_sdk::account::Account;
 use solana_sdk::pubkey::Pubkey;
 
-use anchor_lang::{AccountSerialize, Owner, ZeroCopy};
+use anchor_lang::{AccountSerialize, Owner, Space, ZeroCopy};
 
 use dexter_client_api::base::setter::{HasRent, SetAccount};
 use dexter_client_api::Client;
 
+use crate::account::AnchorAccount;
+
+pub fn anchor_account_space<T: Space>() -> usize {
+    8 + T::INIT_SPACE
+}
+
 pub trait AnchorSetter: Client {
     fn set_anchor_account_zero_copy<T>(
         &mut self,
@@ -55,6 +61,45 @@ pub trait AnchorSetter: Client {
 
         self.set_account(pubkey, account);
     }
+
+    /// Zero-pads the data to the size `init` allocates.
+    fn set_anchor_account_with_space<T>(
+        &mut self,
+        pubkey: Pubkey,
+        account: &T,
+        owner: Option<Pubkey>,
+    ) where
+        Self: SetAccount + HasRent,
+        T: AccountSerialize + Owner + Space,
+    {
+        let space = anchor_account_space::<T>();
+
+        let mut data = Vec::with_capacity(space);
+        account.try_serialize(&mut data).unwrap();
+        assert!(
+            data.len() <= space,
+            "serialized account exceeds its space of {space} bytes",
+        );
+        data.resize(space, 0);
+
+        let account = Account {
+            lamports: self.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: owner.unwrap_or(T::owner()),
+            executable: false,
+            rent_epoch: u64::MAX,
+        };
+
+        self.set_account(pubkey, account);
+    }
+
+    fn store_anchor_account<T>(&mut self, account: &AnchorAccount<T>)
+    where
+        Self: SetAccount,
+    {
+        let (key, account, _) = AnchorAccount::as_parts(account);
+        self.set_account(*key, account.clone());
+    }
 }
 
 impl<C: ?Sized + Client> AnchorSetter for C {}
@@ -0,0 +1,193 @@
+use serde_json::{json, Value};
+
+use solana_sdk::instruction::AccountMeta;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_program;
+
+use dexter_client_anchor::idl::{IdlClient, IdlError};
+use dexter_client_api::errors::ClientError;
+
+const IDL: &str = r#"{
+    "address": "Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS",
+    "instructions": [
+        {
+            "name": "initialize",
+            "discriminator": [1, 1, 1, 1, 1, 1, 1, 1],
+            "accounts": [
+                {
+                    "name": "state",
+                    "writable": true,
+                    "pda": {
+                        "seeds": [
+                            { "kind": "const", "value": [115, 116, 97, 116, 101] },
+                            { "kind": "account", "path": "authority" }
+                        ]
+                    }
+                },
+                { "name": "authority", "writable": true, "signer": true },
+                { "name": "system_program", "address": "11111111111111111111111111111111" }
+            ],
+            "args": [{ "name": "state", "type": { "defined": { "name": "State" } } }]
+        },
+        {
+            "name": "widen",
+            "discriminator": [2, 2, 2, 2, 2, 2, 2, 2],
+            "accounts": [],
+            "args": [{ "name": "value", "type": "u256" }]
+        },
+        {
+            "name": "wrap",
+            "discriminator": [3, 3, 3, 3, 3, 3, 3, 3],
+            "accounts": [],
+            "args": [
+                {
+                    "name": "value",
+                    "type": {
+                        "defined": {
+                            "name": "Wrapper",
+                            "generics": [{ "kind": "type", "type": "u8" }]
+                        }
+                    }
+                }
+            ]
+        },
+        {
+            "name": "spin",
+            "discriminator": [4, 4, 4, 4, 4, 4, 4, 4],
+            "accounts": [],
+            "args": [{ "name": "value", "type": { "defined": { "name": "Loop" } } }]
+        }
+    ],
+    "accounts": [{ "name": "State", "discriminator": [8, 7, 6, 5, 4, 3, 2, 1] }],
+    "types": [
+        {
+            "name": "State",
+            "type": {
+                "kind": "struct",
+                "fields": [
+                    { "name": "authority", "type": "pubkey" },
+                    { "name": "amount", "type": "u64" },
+                    { "name": "total", "type": "u128" },
+                    { "name": "label", "type": "string" },
+                    { "name": "limit", "type": { "option": "i32" } },
+                    { "name": "history", "type": { "vec": "u16" } },
+                    { "name": "seed", "type": { "array": ["u8", 4] } },
+                    { "name": "status", "type": { "defined": { "name": "Status" } } }
+                ]
+            }
+        },
+        {
+            "name": "Status",
+            "type": {
+                "kind": "enum",
+                "variants": [
+                    { "name": "Active" },
+                    { "name": "Frozen", "fields": [{ "name": "until", "type": "i64" }] }
+                ]
+            }
+        },
+        {
+            "name": "Wrapper",
+            "generics": [{ "kind": "type", "name": "T" }],
+            "type": {
+                "kind": "struct",
+                "fields": [{ "name": "inner", "type": { "generic": "T" } }]
+            }
+        },
+        {
+            "name": "Loop",
+            "type": { "kind": "type", "alias": { "defined": { "name": "Loop" } } }
+        }
+    ]
+}"#;
+
+fn idl_error(error: ClientError) -> IdlError {
+    match error {
+        ClientError::DomainSpecific(error) => *error.downcast::<IdlError>().unwrap(),
+        error => panic!("unexpected error: {error}"),
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    let client = IdlClient::from_json(IDL).unwrap();
+    let authority = Pubkey::new_unique();
+
+    for (limit, status) in [
+        (json!(-7), json!("Active")),
+        (Value::Null, json!({ "Frozen": { "until": 1_700_000_000 } })),
+    ] {
+        let state = json!({
+            "authority": authority.to_string(),
+            "amount": 42,
+            "total": u128::MAX.to_string(),
+            "label": "dexter",
+            "limit": limit,
+            "history": [1, 2, 3],
+            "seed": [9, 8, 7, 6],
+            "status": status,
+        });
+
+        let instruction = client
+            .build_instruction(
+                "initialize",
+                &[("authority", authority)],
+                &json!({ "state": state }),
+            )
+            .unwrap();
+
+        let mut data = vec![8, 7, 6, 5, 4, 3, 2, 1];
+        data.extend_from_slice(instruction.data.strip_prefix(&[1; 8][..]).unwrap());
+        assert_eq!(
+            client.decode_account(&data).unwrap(),
+            ("State".to_string(), state)
+        );
+    }
+}
+
+#[test]
+fn test_resolve_accounts() {
+    let client = IdlClient::from_json(IDL).unwrap();
+    let authority = Pubkey::new_unique();
+
+    let (state, _) =
+        Pubkey::find_program_address(&[b"state", authority.as_ref()], &client.program_id());
+    let accounts = client
+        .resolve_accounts("initialize", &[("authority", authority)], &Value::Null)
+        .unwrap();
+
+    assert_eq!(
+        accounts,
+        vec![
+            AccountMeta::new(state, false),
+            AccountMeta::new(authority, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ]
+    );
+}
+
+#[test]
+fn test_unsupported_types() {
+    // the idl parses even though some of its types cannot be encoded
+    let client = IdlClient::from_json(IDL).unwrap();
+
+    let error = client
+        .build_instruction("widen", &[], &json!({ "value": "1" }))
+        .unwrap_err();
+    assert!(matches!(idl_error(error), IdlError::Unsupported(_)));
+
+    let error = client
+        .build_instruction("wrap", &[], &json!({ "value": { "inner": 1 } }))
+        .unwrap_err();
+    assert!(matches!(idl_error(error), IdlError::Unsupported(_)));
+}
+
+#[test]
+fn test_self_referential_alias() {
+    let client = IdlClient::from_json(IDL).unwrap();
+
+    let error = client
+        .build_instruction("spin", &[], &json!({ "value": 1 }))
+        .unwrap_err();
+    assert!(matches!(idl_error(error), IdlError::TypeTooDeep(name) if name == "Loop"));
+}
@@ -1,62 +1,287 @@
 use std::sync::Arc;
 
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
 use solana_sdk::transaction::VersionedTransaction;
 
+use crate::base::options::CallOptions;
 use crate::client::Client;
 use crate::errors::ClientResult;
 
 pub trait ProcessTransaction<T>: Client {
     fn process_transaction(&self, transaction: VersionedTransaction) -> ClientResult<T>;
+
+    fn process_transaction_with_options(
+        &self,
+        transaction: VersionedTransaction,
+        _options: &CallOptions,
+    ) -> ClientResult<T> {
+        self.process_transaction(transaction)
+    }
 }
 
 impl<T, C: ?Sized + ProcessTransaction<T>> ProcessTransaction<T> for &C {
     fn process_transaction(&self, transaction: VersionedTransaction) -> ClientResult<T> {
         (**self).process_transaction(transaction)
     }
+
+    fn process_transaction_with_options(
+        &self,
+        transaction: VersionedTransaction,
+        options: &CallOptions,
+    ) -> ClientResult<T> {
+        (**self).process_transaction_with_options(transaction, options)
+    }
 }
 
 impl<T, C: ?Sized + ProcessTransaction<T>> ProcessTransaction<T> for &mut C {
     fn process_transaction(&self, transaction: VersionedTransaction) -> ClientResult<T> {
         (**self).process_transaction(transaction)
     }
+
+    fn process_transaction_with_options(
+        &self,
+        transaction: VersionedTransaction,
+        options: &CallOptions,
+    ) -> ClientResult<T> {
+        (**self).process_transaction_with_options(transaction, options)
+    }
 }
 
 impl<T, C: ?Sized + ProcessTransaction<T>> ProcessTransaction<T> for Box<C> {
     fn process_transaction(&self, transaction: VersionedTransaction) -> ClientResult<T> {
         (**self).process_transaction(transaction)
     }
+
+    fn process_transaction_with_options(
+        &self,
+        transaction: VersionedTransaction,
+        options: &CallOptions,
+    ) -> ClientResult<T> {
+        (**self).process_transaction_with_options(transaction, options)
+    }
 }
 
 impl<T, C: ?Sized + ProcessTransaction<T>> ProcessTransaction<T> for Arc<C> {
     fn process_transaction(&self, transaction: VersionedTransaction) -> ClientResult<T> {
         (**self).process_transaction(transaction)
     }
+
+    fn process_transaction_with_options(
+        &self,
+        transaction: VersionedTransaction,
+        options: &CallOptions,
+    ) -> ClientResult<T> {
+        (**self).process_transaction_with_options(transaction, options)
+    }
+}
+
+/// Fails as a whole only if the backend cannot be reached.
+pub trait ProcessTransactionBatch<T>: ProcessTransaction<T> {
+    fn process_transaction_batch(
+        &self,
+        transactions: Vec<VersionedTransaction>,
+    ) -> ClientResult<Vec<ClientResult<T>>> {
+        Ok(transactions
+            .into_iter()
+            .map(|transaction| self.process_transaction(transaction))
+            .collect())
+    }
+}
+
+impl<T, C: ?Sized + ProcessTransactionBatch<T>> ProcessTransactionBatch<T> for &C {
+    fn process_transaction_batch(
+        &self,
+        transactions: Vec<VersionedTransaction>,
+    ) -> ClientResult<Vec<ClientResult<T>>> {
+        (**self).process_transaction_batch(transactions)
+    }
+}
+
+impl<T, C: ?Sized + ProcessTransactionBatch<T>> ProcessTransactionBatch<T> for &mut C {
+    fn process_transaction_batch(
+        &self,
+        transactions: Vec<VersionedTransaction>,
+    ) -> ClientResult<Vec<ClientResult<T>>> {
+        (**self).process_transaction_batch(transactions)
+    }
+}
+
+impl<T, C: ?Sized + ProcessTransactionBatch<T>> ProcessTransactionBatch<T> for Box<C> {
+    fn process_transaction_batch(
+        &self,
+        transactions: Vec<VersionedTransaction>,
+    ) -> ClientResult<Vec<ClientResult<T>>> {
+        (**self).process_transaction_batch(transactions)
+    }
+}
+
+impl<T, C: ?Sized + ProcessTransactionBatch<T>> ProcessTransactionBatch<T> for Arc<C> {
+    fn process_transaction_batch(
+        &self,
+        transactions: Vec<VersionedTransaction>,
+    ) -> ClientResult<Vec<ClientResult<T>>> {
+        (**self).process_transaction_batch(transactions)
+    }
 }
 
 pub trait SimulateTransaction<T>: Client {
     fn simulate_transaction(&self, transaction: VersionedTransaction) -> ClientResult<T>;
+
+    fn simulate_transaction_with_options(
+        &self,
+        transaction: VersionedTransaction,
+        _options: &CallOptions,
+    ) -> ClientResult<T> {
+        self.simulate_transaction(transaction)
+    }
+
+    // defaults to the plain simulation for backends which do not verify signatures anyway
+    fn simulate_transaction_unverified(
+        &self,
+        transaction: VersionedTransaction,
+    ) -> ClientResult<T> {
+        self.simulate_transaction(transaction)
+    }
 }
 
 impl<T, C: ?Sized + SimulateTransaction<T>> SimulateTransaction<T> for &C {
     fn simulate_transaction(&self, transaction: VersionedTransaction) -> ClientResult<T> {
         (**self).simulate_transaction(transaction)
     }
+
+    fn simulate_transaction_with_options(
+        &self,
+        transaction: VersionedTransaction,
+        options: &CallOptions,
+    ) -> ClientResult<T> {
+        (**self).simulate_transaction_with_options(transaction, options)
+    }
+
+    fn simulate_transaction_unverified(
+        &self,
+        transaction: VersionedTransaction,
+    ) -> ClientResult<T> {
+        (**self).simulate_transaction_unverified(transaction)
+    }
 }
 
 impl<T, C: ?Sized + SimulateTransaction<T>> SimulateTransaction<T> for &mut C {
     fn simulate_transaction(&self, transaction: VersionedTransaction) -> ClientResult<T> {
         (**self).simulate_transaction(transaction)
     }
+
+    fn simulate_transaction_with_options(
+        &self,
+        transaction: VersionedTransaction,
+        options: &CallOptions,
+    ) -> ClientResult<T> {
+        (**self).simulate_transaction_with_options(transaction, options)
+    }
+
+    fn simulate_transaction_unverified(
+        &self,
+        transaction: VersionedTransaction,
+    ) -> ClientResult<T> {
+        (**self).simulate_transaction_unverified(transaction)
+    }
 }
 
 impl<T, C: ?Sized + SimulateTransaction<T>> SimulateTransaction<T> for Box<C> {
     fn simulate_transaction(&self, transaction: VersionedTransaction) -> ClientResult<T> {
         (**self).simulate_transaction(transaction)
     }
+
+    fn simulate_transaction_with_options(
+        &self,
+        transaction: VersionedTransaction,
+        options: &CallOptions,
+    ) -> ClientResult<T> {
+        (**self).simulate_transaction_with_options(transaction, options)
+    }
+
+    fn simulate_transaction_unverified(
+        &self,
+        transaction: VersionedTransaction,
+    ) -> ClientResult<T> {
+        (**self).simulate_transaction_unverified(transaction)
+    }
 }
 
 impl<T, C: ?Sized + SimulateTransaction<T>> SimulateTransaction<T> for Arc<C> {
     fn simulate_transaction(&self, transaction: VersionedTransaction) -> ClientResult<T> {
         (**self).simulate_transaction(transaction)
     }
+
+    fn simulate_transaction_with_options(
+        &self,
+        transaction: VersionedTransaction,
+        options: &CallOptions,
+    ) -> ClientResult<T> {
+        (**self).simulate_transaction_with_options(transaction, options)
+    }
+
+    fn simulate_transaction_unverified(
+        &self,
+        transaction: VersionedTransaction,
+    ) -> ClientResult<T> {
+        (**self).simulate_transaction_unverified(transaction)
+    }
+}
+
+pub trait SendTransaction: Client {
+    fn send_transaction(&self, transaction: VersionedTransaction) -> ClientResult<Signature>;
+}
+
+impl<C: ?Sized + SendTransaction> SendTransaction for &C {
+    fn send_transaction(&self, transaction: VersionedTransaction) -> ClientResult<Signature> {
+        (**self).send_transaction(transaction)
+    }
+}
+
+impl<C: ?Sized + SendTransaction> SendTransaction for &mut C {
+    fn send_transaction(&self, transaction: VersionedTransaction) -> ClientResult<Signature> {
+        (**self).send_transaction(transaction)
+    }
+}
+
+impl<C: ?Sized + SendTransaction> SendTransaction for Box<C> {
+    fn send_transaction(&self, transaction: VersionedTransaction) -> ClientResult<Signature> {
+        (**self).send_transaction(transaction)
+    }
+}
+
+impl<C: ?Sized + SendTransaction> SendTransaction for Arc<C> {
+    fn send_transaction(&self, transaction: VersionedTransaction) -> ClientResult<Signature> {
+        (**self).send_transaction(transaction)
+    }
+}
+
+/// Does not wait for the airdrop to land.
+pub trait RequestAirdrop: Client {
+    fn request_airdrop(&self, pubkey: &Pubkey, lamports: u64) -> ClientResult<Signature>;
+}
+
+impl<C: ?Sized + RequestAirdrop> RequestAirdrop for &C {
+    fn request_airdrop(&self, pubkey: &Pubkey, lamports: u64) -> ClientResult<Signature> {
+        (**self).request_airdrop(pubkey, lamports)
+    }
+}
+
+impl<C: ?Sized + RequestAirdrop> RequestAirdrop for &mut C {
+    fn request_airdrop(&self, pubkey: &Pubkey, lamports: u64) -> ClientResult<Signature> {
+        (**self).request_airdrop(pubkey, lamports)
+    }
+}
+
+impl<C: ?Sized + RequestAirdrop> RequestAirdrop for Box<C> {
+    fn request_airdrop(&self, pubkey: &Pubkey, lamports: u64) -> ClientResult<Signature> {
+        (**self).request_airdrop(pubkey, lamports)
+    }
+}
+
+impl<C: ?Sized + RequestAirdrop> RequestAirdrop for Arc<C> {
+    fn request_airdrop(&self, pubkey: &Pubkey, lamports: u64) -> ClientResult<Signature> {
+        (**self).request_airdrop(pubkey, lamports)
+    }
 }
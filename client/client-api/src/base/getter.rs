@@ -3,38 +3,83 @@ use std::sync::Arc;
 use solana_sdk::account::Account;
 use solana_sdk::hash::Hash;
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_transaction_status::{EncodedConfirmedTransactionWithStatusMeta, TransactionStatus};
 
+use crate::base::options::CallOptions;
 use crate::client::Client;
 use crate::errors::ClientResult;
 
+pub use solana_account_decoder::UiDataSliceConfig as DataSlice;
 pub use solana_rpc_client_api::filter::{Memcmp, RpcFilterType as ProgramAccountsFilter};
+pub use solana_rpc_client_api::response::RpcConfirmedTransactionStatusWithSignature;
 
 pub trait GetAccount: Client {
     fn get_account(&self, pubkey: &Pubkey) -> ClientResult<Option<Account>>;
+
+    fn get_account_with_options(
+        &self,
+        pubkey: &Pubkey,
+        _options: &CallOptions,
+    ) -> ClientResult<Option<Account>> {
+        self.get_account(pubkey)
+    }
 }
 
 impl<C: ?Sized + GetAccount> GetAccount for &C {
     fn get_account(&self, pubkey: &Pubkey) -> ClientResult<Option<Account>> {
         (**self).get_account(pubkey)
     }
+
+    fn get_account_with_options(
+        &self,
+        pubkey: &Pubkey,
+        options: &CallOptions,
+    ) -> ClientResult<Option<Account>> {
+        (**self).get_account_with_options(pubkey, options)
+    }
 }
 
 impl<C: ?Sized + GetAccount> GetAccount for &mut C {
     fn get_account(&self, pubkey: &Pubkey) -> ClientResult<Option<Account>> {
         (**self).get_account(pubkey)
     }
+
+    fn get_account_with_options(
+        &self,
+        pubkey: &Pubkey,
+        options: &CallOptions,
+    ) -> ClientResult<Option<Account>> {
+        (**self).get_account_with_options(pubkey, options)
+    }
 }
 
 impl<C: ?Sized + GetAccount> GetAccount for Box<C> {
     fn get_account(&self, pubkey: &Pubkey) -> ClientResult<Option<Account>> {
         (**self).get_account(pubkey)
     }
+
+    fn get_account_with_options(
+        &self,
+        pubkey: &Pubkey,
+        options: &CallOptions,
+    ) -> ClientResult<Option<Account>> {
+        (**self).get_account_with_options(pubkey, options)
+    }
 }
 
 impl<C: ?Sized + GetAccount> GetAccount for Arc<C> {
     fn get_account(&self, pubkey: &Pubkey) -> ClientResult<Option<Account>> {
         (**self).get_account(pubkey)
     }
+
+    fn get_account_with_options(
+        &self,
+        pubkey: &Pubkey,
+        options: &CallOptions,
+    ) -> ClientResult<Option<Account>> {
+        (**self).get_account_with_options(pubkey, options)
+    }
 }
 
 pub trait GetProgramAccounts: Client + GetAccount {
@@ -43,6 +88,44 @@ pub trait GetProgramAccounts: Client + GetAccount {
         program_id: &Pubkey,
         filters: Option<Vec<ProgramAccountsFilter>>,
     ) -> ClientResult<Vec<(Pubkey, Account)>>;
+
+    fn get_program_accounts_with_options(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+        _options: &CallOptions,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        self.get_program_accounts(program_id, filters)
+    }
+
+    /// Sorted, and fetched without the data where possible.
+    fn get_program_account_keys(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+    ) -> ClientResult<Vec<Pubkey>> {
+        let mut keys: Vec<_> = self
+            .get_program_accounts(program_id, filters)?
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect();
+        keys.sort();
+        Ok(keys)
+    }
+
+    // sliced locally unless the backend supports slicing
+    fn get_program_accounts_sliced(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+        data_slice: DataSlice,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        let mut accounts = self.get_program_accounts(program_id, filters)?;
+        for (_, account) in &mut accounts {
+            slice_data(account, &data_slice);
+        }
+        Ok(accounts)
+    }
 }
 
 impl<C: ?Sized + GetProgramAccounts> GetProgramAccounts for &C {
@@ -53,6 +136,32 @@ impl<C: ?Sized + GetProgramAccounts> GetProgramAccounts for &C {
     ) -> ClientResult<Vec<(Pubkey, Account)>> {
         (**self).get_program_accounts(program_id, filters)
     }
+
+    fn get_program_accounts_with_options(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+        options: &CallOptions,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        (**self).get_program_accounts_with_options(program_id, filters, options)
+    }
+
+    fn get_program_account_keys(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+    ) -> ClientResult<Vec<Pubkey>> {
+        (**self).get_program_account_keys(program_id, filters)
+    }
+
+    fn get_program_accounts_sliced(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+        data_slice: DataSlice,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        (**self).get_program_accounts_sliced(program_id, filters, data_slice)
+    }
 }
 
 impl<C: ?Sized + GetProgramAccounts> GetProgramAccounts for &mut C {
@@ -63,6 +172,32 @@ impl<C: ?Sized + GetProgramAccounts> GetProgramAccounts for &mut C {
     ) -> ClientResult<Vec<(Pubkey, Account)>> {
         (**self).get_program_accounts(program_id, filters)
     }
+
+    fn get_program_accounts_with_options(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+        options: &CallOptions,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        (**self).get_program_accounts_with_options(program_id, filters, options)
+    }
+
+    fn get_program_account_keys(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+    ) -> ClientResult<Vec<Pubkey>> {
+        (**self).get_program_account_keys(program_id, filters)
+    }
+
+    fn get_program_accounts_sliced(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+        data_slice: DataSlice,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        (**self).get_program_accounts_sliced(program_id, filters, data_slice)
+    }
 }
 
 impl<C: ?Sized + GetProgramAccounts> GetProgramAccounts for Box<C> {
@@ -73,6 +208,32 @@ impl<C: ?Sized + GetProgramAccounts> GetProgramAccounts for Box<C> {
     ) -> ClientResult<Vec<(Pubkey, Account)>> {
         (**self).get_program_accounts(program_id, filters)
     }
+
+    fn get_program_accounts_with_options(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+        options: &CallOptions,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        (**self).get_program_accounts_with_options(program_id, filters, options)
+    }
+
+    fn get_program_account_keys(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+    ) -> ClientResult<Vec<Pubkey>> {
+        (**self).get_program_account_keys(program_id, filters)
+    }
+
+    fn get_program_accounts_sliced(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+        data_slice: DataSlice,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        (**self).get_program_accounts_sliced(program_id, filters, data_slice)
+    }
 }
 
 impl<C: ?Sized + GetProgramAccounts> GetProgramAccounts for Arc<C> {
@@ -83,88 +244,468 @@ impl<C: ?Sized + GetProgramAccounts> GetProgramAccounts for Arc<C> {
     ) -> ClientResult<Vec<(Pubkey, Account)>> {
         (**self).get_program_accounts(program_id, filters)
     }
+
+    fn get_program_accounts_with_options(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+        options: &CallOptions,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        (**self).get_program_accounts_with_options(program_id, filters, options)
+    }
+
+    fn get_program_account_keys(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+    ) -> ClientResult<Vec<Pubkey>> {
+        (**self).get_program_account_keys(program_id, filters)
+    }
+
+    fn get_program_accounts_sliced(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+        data_slice: DataSlice,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        (**self).get_program_accounts_sliced(program_id, filters, data_slice)
+    }
 }
 
 pub trait GetMultipleAccounts: Client + GetAccount {
     fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> ClientResult<Vec<Option<Account>>>;
+
+    fn get_multiple_accounts_with_options(
+        &self,
+        pubkeys: &[Pubkey],
+        _options: &CallOptions,
+    ) -> ClientResult<Vec<Option<Account>>> {
+        self.get_multiple_accounts(pubkeys)
+    }
+
+    fn get_multiple_accounts_sliced(
+        &self,
+        pubkeys: &[Pubkey],
+        data_slice: DataSlice,
+    ) -> ClientResult<Vec<Option<Account>>> {
+        let mut accounts = self.get_multiple_accounts(pubkeys)?;
+        for account in accounts.iter_mut().flatten() {
+            slice_data(account, &data_slice);
+        }
+        Ok(accounts)
+    }
+}
+
+fn slice_data(account: &mut Account, data_slice: &DataSlice) {
+    let start = data_slice.offset.min(account.data.len());
+    let end = data_slice
+        .offset
+        .saturating_add(data_slice.length)
+        .min(account.data.len());
+    account.data = account.data[start..end].to_vec();
 }
 
 impl<C: ?Sized + GetMultipleAccounts> GetMultipleAccounts for &C {
     fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> ClientResult<Vec<Option<Account>>> {
         (**self).get_multiple_accounts(pubkeys)
     }
+
+    fn get_multiple_accounts_with_options(
+        &self,
+        pubkeys: &[Pubkey],
+        options: &CallOptions,
+    ) -> ClientResult<Vec<Option<Account>>> {
+        (**self).get_multiple_accounts_with_options(pubkeys, options)
+    }
+
+    fn get_multiple_accounts_sliced(
+        &self,
+        pubkeys: &[Pubkey],
+        data_slice: DataSlice,
+    ) -> ClientResult<Vec<Option<Account>>> {
+        (**self).get_multiple_accounts_sliced(pubkeys, data_slice)
+    }
 }
 
 impl<C: ?Sized + GetMultipleAccounts> GetMultipleAccounts for &mut C {
     fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> ClientResult<Vec<Option<Account>>> {
         (**self).get_multiple_accounts(pubkeys)
     }
+
+    fn get_multiple_accounts_with_options(
+        &self,
+        pubkeys: &[Pubkey],
+        options: &CallOptions,
+    ) -> ClientResult<Vec<Option<Account>>> {
+        (**self).get_multiple_accounts_with_options(pubkeys, options)
+    }
+
+    fn get_multiple_accounts_sliced(
+        &self,
+        pubkeys: &[Pubkey],
+        data_slice: DataSlice,
+    ) -> ClientResult<Vec<Option<Account>>> {
+        (**self).get_multiple_accounts_sliced(pubkeys, data_slice)
+    }
 }
 
 impl<C: ?Sized + GetMultipleAccounts> GetMultipleAccounts for Box<C> {
     fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> ClientResult<Vec<Option<Account>>> {
         (**self).get_multiple_accounts(pubkeys)
     }
+
+    fn get_multiple_accounts_with_options(
+        &self,
+        pubkeys: &[Pubkey],
+        options: &CallOptions,
+    ) -> ClientResult<Vec<Option<Account>>> {
+        (**self).get_multiple_accounts_with_options(pubkeys, options)
+    }
+
+    fn get_multiple_accounts_sliced(
+        &self,
+        pubkeys: &[Pubkey],
+        data_slice: DataSlice,
+    ) -> ClientResult<Vec<Option<Account>>> {
+        (**self).get_multiple_accounts_sliced(pubkeys, data_slice)
+    }
 }
 
 impl<C: ?Sized + GetMultipleAccounts> GetMultipleAccounts for Arc<C> {
     fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> ClientResult<Vec<Option<Account>>> {
         (**self).get_multiple_accounts(pubkeys)
     }
+
+    fn get_multiple_accounts_with_options(
+        &self,
+        pubkeys: &[Pubkey],
+        options: &CallOptions,
+    ) -> ClientResult<Vec<Option<Account>>> {
+        (**self).get_multiple_accounts_with_options(pubkeys, options)
+    }
+
+    fn get_multiple_accounts_sliced(
+        &self,
+        pubkeys: &[Pubkey],
+        data_slice: DataSlice,
+    ) -> ClientResult<Vec<Option<Account>>> {
+        (**self).get_multiple_accounts_sliced(pubkeys, data_slice)
+    }
 }
 
 pub trait GetMinimumBalanceForRentExemption: Client {
     fn get_minimum_balance_for_rent_exemption(&self, data_len: usize) -> ClientResult<u64>;
+
+    fn get_minimum_balance_for_rent_exemption_with_options(
+        &self,
+        data_len: usize,
+        _options: &CallOptions,
+    ) -> ClientResult<u64> {
+        self.get_minimum_balance_for_rent_exemption(data_len)
+    }
 }
 
 impl<C: ?Sized + GetMinimumBalanceForRentExemption> GetMinimumBalanceForRentExemption for &C {
     fn get_minimum_balance_for_rent_exemption(&self, data_len: usize) -> ClientResult<u64> {
         (**self).get_minimum_balance_for_rent_exemption(data_len)
     }
+
+    fn get_minimum_balance_for_rent_exemption_with_options(
+        &self,
+        data_len: usize,
+        options: &CallOptions,
+    ) -> ClientResult<u64> {
+        (**self).get_minimum_balance_for_rent_exemption_with_options(data_len, options)
+    }
 }
 
 impl<C: ?Sized + GetMinimumBalanceForRentExemption> GetMinimumBalanceForRentExemption for &mut C {
     fn get_minimum_balance_for_rent_exemption(&self, data_len: usize) -> ClientResult<u64> {
         (**self).get_minimum_balance_for_rent_exemption(data_len)
     }
+
+    fn get_minimum_balance_for_rent_exemption_with_options(
+        &self,
+        data_len: usize,
+        options: &CallOptions,
+    ) -> ClientResult<u64> {
+        (**self).get_minimum_balance_for_rent_exemption_with_options(data_len, options)
+    }
 }
 
 impl<C: ?Sized + GetMinimumBalanceForRentExemption> GetMinimumBalanceForRentExemption for Box<C> {
     fn get_minimum_balance_for_rent_exemption(&self, data_len: usize) -> ClientResult<u64> {
         (**self).get_minimum_balance_for_rent_exemption(data_len)
     }
+
+    fn get_minimum_balance_for_rent_exemption_with_options(
+        &self,
+        data_len: usize,
+        options: &CallOptions,
+    ) -> ClientResult<u64> {
+        (**self).get_minimum_balance_for_rent_exemption_with_options(data_len, options)
+    }
 }
 
 impl<C: ?Sized + GetMinimumBalanceForRentExemption> GetMinimumBalanceForRentExemption for Arc<C> {
     fn get_minimum_balance_for_rent_exemption(&self, data_len: usize) -> ClientResult<u64> {
         (**self).get_minimum_balance_for_rent_exemption(data_len)
     }
+
+    fn get_minimum_balance_for_rent_exemption_with_options(
+        &self,
+        data_len: usize,
+        options: &CallOptions,
+    ) -> ClientResult<u64> {
+        (**self).get_minimum_balance_for_rent_exemption_with_options(data_len, options)
+    }
 }
 
 pub trait GetLatestBlockhash: Client {
     fn get_latest_blockhash(&self) -> ClientResult<Hash>;
+
+    fn get_latest_blockhash_with_options(&self, _options: &CallOptions) -> ClientResult<Hash> {
+        self.get_latest_blockhash()
+    }
+}
+
+pub trait GetLatestBlockhashWithExpiry: GetLatestBlockhash {
+    fn get_latest_blockhash_with_last_valid_block_height(&self) -> ClientResult<(Hash, u64)>;
 }
 
 impl<C: ?Sized + GetLatestBlockhash> GetLatestBlockhash for &C {
     fn get_latest_blockhash(&self) -> ClientResult<Hash> {
         (**self).get_latest_blockhash()
     }
+
+    fn get_latest_blockhash_with_options(&self, options: &CallOptions) -> ClientResult<Hash> {
+        (**self).get_latest_blockhash_with_options(options)
+    }
+}
+
+impl<C: ?Sized + GetLatestBlockhashWithExpiry> GetLatestBlockhashWithExpiry for &C {
+    fn get_latest_blockhash_with_last_valid_block_height(&self) -> ClientResult<(Hash, u64)> {
+        (**self).get_latest_blockhash_with_last_valid_block_height()
+    }
 }
 
 impl<C: ?Sized + GetLatestBlockhash> GetLatestBlockhash for &mut C {
     fn get_latest_blockhash(&self) -> ClientResult<Hash> {
         (**self).get_latest_blockhash()
     }
+
+    fn get_latest_blockhash_with_options(&self, options: &CallOptions) -> ClientResult<Hash> {
+        (**self).get_latest_blockhash_with_options(options)
+    }
+}
+
+impl<C: ?Sized + GetLatestBlockhashWithExpiry> GetLatestBlockhashWithExpiry for &mut C {
+    fn get_latest_blockhash_with_last_valid_block_height(&self) -> ClientResult<(Hash, u64)> {
+        (**self).get_latest_blockhash_with_last_valid_block_height()
+    }
 }
 
 impl<C: ?Sized + GetLatestBlockhash> GetLatestBlockhash for Box<C> {
     fn get_latest_blockhash(&self) -> ClientResult<Hash> {
         (**self).get_latest_blockhash()
     }
+
+    fn get_latest_blockhash_with_options(&self, options: &CallOptions) -> ClientResult<Hash> {
+        (**self).get_latest_blockhash_with_options(options)
+    }
+}
+
+impl<C: ?Sized + GetLatestBlockhashWithExpiry> GetLatestBlockhashWithExpiry for Box<C> {
+    fn get_latest_blockhash_with_last_valid_block_height(&self) -> ClientResult<(Hash, u64)> {
+        (**self).get_latest_blockhash_with_last_valid_block_height()
+    }
 }
 
 impl<C: ?Sized + GetLatestBlockhash> GetLatestBlockhash for Arc<C> {
     fn get_latest_blockhash(&self) -> ClientResult<Hash> {
         (**self).get_latest_blockhash()
     }
+
+    fn get_latest_blockhash_with_options(&self, options: &CallOptions) -> ClientResult<Hash> {
+        (**self).get_latest_blockhash_with_options(options)
+    }
+}
+
+impl<C: ?Sized + GetLatestBlockhashWithExpiry> GetLatestBlockhashWithExpiry for Arc<C> {
+    fn get_latest_blockhash_with_last_valid_block_height(&self) -> ClientResult<(Hash, u64)> {
+        (**self).get_latest_blockhash_with_last_valid_block_height()
+    }
+}
+
+pub trait GetTransaction: Client {
+    fn get_transaction(
+        &self,
+        signature: &Signature,
+    ) -> ClientResult<Option<EncodedConfirmedTransactionWithStatusMeta>>;
+}
+
+impl<C: ?Sized + GetTransaction> GetTransaction for &C {
+    fn get_transaction(
+        &self,
+        signature: &Signature,
+    ) -> ClientResult<Option<EncodedConfirmedTransactionWithStatusMeta>> {
+        (**self).get_transaction(signature)
+    }
+}
+
+impl<C: ?Sized + GetTransaction> GetTransaction for &mut C {
+    fn get_transaction(
+        &self,
+        signature: &Signature,
+    ) -> ClientResult<Option<EncodedConfirmedTransactionWithStatusMeta>> {
+        (**self).get_transaction(signature)
+    }
+}
+
+impl<C: ?Sized + GetTransaction> GetTransaction for Box<C> {
+    fn get_transaction(
+        &self,
+        signature: &Signature,
+    ) -> ClientResult<Option<EncodedConfirmedTransactionWithStatusMeta>> {
+        (**self).get_transaction(signature)
+    }
+}
+
+impl<C: ?Sized + GetTransaction> GetTransaction for Arc<C> {
+    fn get_transaction(
+        &self,
+        signature: &Signature,
+    ) -> ClientResult<Option<EncodedConfirmedTransactionWithStatusMeta>> {
+        (**self).get_transaction(signature)
+    }
+}
+
+pub trait GetSignaturesForAddress: Client {
+    /// Newest first.
+    fn get_signatures_for_address(
+        &self,
+        address: &Pubkey,
+        before: Option<Signature>,
+        until: Option<Signature>,
+        limit: Option<usize>,
+    ) -> ClientResult<Vec<RpcConfirmedTransactionStatusWithSignature>>;
+}
+
+impl<C: ?Sized + GetSignaturesForAddress> GetSignaturesForAddress for &C {
+    fn get_signatures_for_address(
+        &self,
+        address: &Pubkey,
+        before: Option<Signature>,
+        until: Option<Signature>,
+        limit: Option<usize>,
+    ) -> ClientResult<Vec<RpcConfirmedTransactionStatusWithSignature>> {
+        (**self).get_signatures_for_address(address, before, until, limit)
+    }
+}
+
+impl<C: ?Sized + GetSignaturesForAddress> GetSignaturesForAddress for &mut C {
+    fn get_signatures_for_address(
+        &self,
+        address: &Pubkey,
+        before: Option<Signature>,
+        until: Option<Signature>,
+        limit: Option<usize>,
+    ) -> ClientResult<Vec<RpcConfirmedTransactionStatusWithSignature>> {
+        (**self).get_signatures_for_address(address, before, until, limit)
+    }
+}
+
+impl<C: ?Sized + GetSignaturesForAddress> GetSignaturesForAddress for Box<C> {
+    fn get_signatures_for_address(
+        &self,
+        address: &Pubkey,
+        before: Option<Signature>,
+        until: Option<Signature>,
+        limit: Option<usize>,
+    ) -> ClientResult<Vec<RpcConfirmedTransactionStatusWithSignature>> {
+        (**self).get_signatures_for_address(address, before, until, limit)
+    }
+}
+
+impl<C: ?Sized + GetSignaturesForAddress> GetSignaturesForAddress for Arc<C> {
+    fn get_signatures_for_address(
+        &self,
+        address: &Pubkey,
+        before: Option<Signature>,
+        until: Option<Signature>,
+        limit: Option<usize>,
+    ) -> ClientResult<Vec<RpcConfirmedTransactionStatusWithSignature>> {
+        (**self).get_signatures_for_address(address, before, until, limit)
+    }
+}
+
+pub trait GetSignatureStatuses: Client {
+    fn get_signature_statuses(
+        &self,
+        signatures: &[Signature],
+    ) -> ClientResult<Vec<Option<TransactionStatus>>>;
+}
+
+impl<C: ?Sized + GetSignatureStatuses> GetSignatureStatuses for &C {
+    fn get_signature_statuses(
+        &self,
+        signatures: &[Signature],
+    ) -> ClientResult<Vec<Option<TransactionStatus>>> {
+        (**self).get_signature_statuses(signatures)
+    }
+}
+
+impl<C: ?Sized + GetSignatureStatuses> GetSignatureStatuses for &mut C {
+    fn get_signature_statuses(
+        &self,
+        signatures: &[Signature],
+    ) -> ClientResult<Vec<Option<TransactionStatus>>> {
+        (**self).get_signature_statuses(signatures)
+    }
+}
+
+impl<C: ?Sized + GetSignatureStatuses> GetSignatureStatuses for Box<C> {
+    fn get_signature_statuses(
+        &self,
+        signatures: &[Signature],
+    ) -> ClientResult<Vec<Option<TransactionStatus>>> {
+        (**self).get_signature_statuses(signatures)
+    }
+}
+
+impl<C: ?Sized + GetSignatureStatuses> GetSignatureStatuses for Arc<C> {
+    fn get_signature_statuses(
+        &self,
+        signatures: &[Signature],
+    ) -> ClientResult<Vec<Option<TransactionStatus>>> {
+        (**self).get_signature_statuses(signatures)
+    }
+}
+
+pub trait GetBlockHeight: Client {
+    fn get_block_height(&self) -> ClientResult<u64>;
+}
+
+impl<C: ?Sized + GetBlockHeight> GetBlockHeight for &C {
+    fn get_block_height(&self) -> ClientResult<u64> {
+        (**self).get_block_height()
+    }
+}
+
+impl<C: ?Sized + GetBlockHeight> GetBlockHeight for &mut C {
+    fn get_block_height(&self) -> ClientResult<u64> {
+        (**self).get_block_height()
+    }
+}
+
+impl<C: ?Sized + GetBlockHeight> GetBlockHeight for Box<C> {
+    fn get_block_height(&self) -> ClientResult<u64> {
+        (**self).get_block_height()
+    }
+}
+
+impl<C: ?Sized + GetBlockHeight> GetBlockHeight for Arc<C> {
+    fn get_block_height(&self) -> ClientResult<u64> {
+        (**self).get_block_height()
+    }
 }
@@ -1,3 +1,4 @@
 pub mod executor;
 pub mod getter;
+pub mod options;
 pub mod setter;
@@ -0,0 +1,18 @@
+use std::time::{Duration, SystemTime};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CallOptions {
+    pub timeout: Option<Duration>,
+}
+
+impl CallOptions {
+    pub fn with_timeout(timeout: Duration) -> Self {
+        Self {
+            timeout: Some(timeout),
+        }
+    }
+
+    pub fn deadline(&self) -> Option<SystemTime> {
+        self.timeout.map(|timeout| SystemTime::now() + timeout)
+    }
+}
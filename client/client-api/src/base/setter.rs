@@ -1,10 +1,13 @@
 use std::sync::Arc;
 
 use solana_sdk::account::Account;
+use solana_sdk::clock::Slot;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::rent::Rent;
+use solana_sdk::sysvar::Sysvar;
 
 use crate::client::Client;
+use crate::errors::ClientResult;
 
 pub trait SetAccount: Client {
     fn set_account(&mut self, pubkey: Pubkey, account: Account);
@@ -22,6 +25,55 @@ impl<C: ?Sized + SetAccount> SetAccount for Box<C> {
     }
 }
 
+// storing the account alone is not enough, as backends may cache the sysvars
+pub trait SetSysvar: Client {
+    fn set_sysvar<T: Sysvar>(&mut self, sysvar: &T);
+}
+
+impl<C: ?Sized + SetSysvar> SetSysvar for &mut C {
+    fn set_sysvar<T: Sysvar>(&mut self, sysvar: &T) {
+        (**self).set_sysvar(sysvar)
+    }
+}
+
+impl<C: ?Sized + SetSysvar> SetSysvar for Box<C> {
+    fn set_sysvar<T: Sysvar>(&mut self, sysvar: &T) {
+        (**self).set_sysvar(sysvar)
+    }
+}
+
+pub trait FundAccount: Client {
+    fn fund_account(&mut self, pubkey: &Pubkey, lamports: u64) -> ClientResult<()>;
+}
+
+impl<C: ?Sized + FundAccount> FundAccount for &mut C {
+    fn fund_account(&mut self, pubkey: &Pubkey, lamports: u64) -> ClientResult<()> {
+        (**self).fund_account(pubkey, lamports)
+    }
+}
+
+impl<C: ?Sized + FundAccount> FundAccount for Box<C> {
+    fn fund_account(&mut self, pubkey: &Pubkey, lamports: u64) -> ClientResult<()> {
+        (**self).fund_account(pubkey, lamports)
+    }
+}
+
+pub trait WarpSlot: Client {
+    fn warp_to_slot(&mut self, slot: Slot) -> ClientResult<()>;
+}
+
+impl<C: ?Sized + WarpSlot> WarpSlot for &mut C {
+    fn warp_to_slot(&mut self, slot: Slot) -> ClientResult<()> {
+        (**self).warp_to_slot(slot)
+    }
+}
+
+impl<C: ?Sized + WarpSlot> WarpSlot for Box<C> {
+    fn warp_to_slot(&mut self, slot: Slot) -> ClientResult<()> {
+        (**self).warp_to_slot(slot)
+    }
+}
+
 pub trait HasRent: Client {
     fn rent(&self) -> Rent;
 
@@ -3,21 +3,28 @@ use solana_accounts_db::transaction_results::{
     DurableNonceFee, TransactionExecutionDetails, TransactionExecutionResult,
 };
 use solana_runtime::bank::{Bank, TransactionSimulationResult};
-use solana_sdk::account::{Account, AccountSharedData, ReadableAccount};
+use solana_sdk::account::{Account, AccountSharedData, ReadableAccount, WritableAccount};
 use solana_sdk::hash::Hash;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::rent::Rent;
+use solana_sdk::signature::Signature;
 use solana_sdk::system_program;
+use solana_sdk::sysvar::Sysvar;
 use solana_sdk::transaction::{
     SanitizedTransaction, TransactionVerificationMode, VersionedTransaction,
 };
 
-use crate::base::executor::{ProcessTransaction, SimulateTransaction};
+use solana_transaction_status::{TransactionConfirmationStatus, TransactionStatus};
+
+use crate::base::executor::{
+    ProcessTransaction, ProcessTransactionBatch, SendTransaction, SimulateTransaction,
+};
 use crate::base::getter::{
-    GetAccount, GetLatestBlockhash, GetMinimumBalanceForRentExemption, GetMultipleAccounts,
-    GetProgramAccounts, ProgramAccountsFilter,
+    GetAccount, GetBlockHeight, GetLatestBlockhash, GetLatestBlockhashWithExpiry,
+    GetMinimumBalanceForRentExemption, GetMultipleAccounts, GetProgramAccounts,
+    GetSignatureStatuses, ProgramAccountsFilter,
 };
-use crate::base::setter::{HasRent, SetAccount};
+use crate::base::setter::{FundAccount, HasRent, SetAccount, SetSysvar};
 use crate::client::Client;
 use crate::errors::{ClientError, ClientResult};
 use crate::execution::{ExecutionEffect, ExecutionOutput};
@@ -97,12 +104,68 @@ impl GetLatestBlockhash for Bank {
     }
 }
 
+impl GetLatestBlockhashWithExpiry for Bank {
+    fn get_latest_blockhash_with_last_valid_block_height(&self) -> ClientResult<(Hash, u64)> {
+        let blockhash = self.last_blockhash();
+        let last_valid_block_height = self
+            .get_blockhash_last_valid_block_height(&blockhash)
+            .expect("missing blockhash");
+        Ok((blockhash, last_valid_block_height))
+    }
+}
+
+impl GetSignatureStatuses for Bank {
+    fn get_signature_statuses(
+        &self,
+        signatures: &[Signature],
+    ) -> ClientResult<Vec<Option<TransactionStatus>>> {
+        let statuses = signatures
+            .iter()
+            .map(|signature| {
+                self.get_signature_status_slot(signature)
+                    .map(|(slot, status)| TransactionStatus {
+                        slot,
+                        confirmations: None,
+                        err: status.clone().err(),
+                        status,
+                        confirmation_status: Some(TransactionConfirmationStatus::Finalized),
+                    })
+            })
+            .collect();
+        Ok(statuses)
+    }
+}
+
+impl GetBlockHeight for Bank {
+    fn get_block_height(&self) -> ClientResult<u64> {
+        Ok(self.block_height())
+    }
+}
+
 impl SetAccount for Bank {
     fn set_account(&mut self, pubkey: Pubkey, account: Account) {
         self.store_account(&pubkey, &account);
     }
 }
 
+impl SetSysvar for Bank {
+    fn set_sysvar<T: Sysvar>(&mut self, sysvar: &T) {
+        self.set_sysvar_for_tests(sysvar);
+    }
+}
+
+impl FundAccount for Bank {
+    fn fund_account(&mut self, pubkey: &Pubkey, lamports: u64) -> ClientResult<()> {
+        let mut account = Bank::get_account(self, pubkey)
+            .unwrap_or_else(|| AccountSharedData::new(0, 0, &system_program::id()));
+        account
+            .checked_add_lamports(lamports)
+            .map_err(|err| ClientError::DomainSpecific(Box::new(err)))?;
+        self.store_account(pubkey, &account);
+        Ok(())
+    }
+}
+
 impl HasRent for Bank {
     fn rent(&self) -> Rent {
         self.rent_collector().rent
@@ -130,7 +193,7 @@ impl ProcessTransaction<ExecutionOutput> for Bank {
         let TransactionExecutionDetails {
             status,
             log_messages,
-            inner_instructions: _,
+            inner_instructions,
             durable_nonce_fee,
             return_data,
             executed_units,
@@ -162,10 +225,23 @@ impl ProcessTransaction<ExecutionOutput> for Bank {
             compute_units_consumed: executed_units,
             return_data,
             fee,
+            inner_instructions,
         })
     }
 }
 
+impl ProcessTransactionBatch<ExecutionOutput> for Bank {}
+
+impl SendTransaction for Bank {
+    fn send_transaction(&self, transaction: VersionedTransaction) -> ClientResult<Signature> {
+        let signature = transaction.signatures[0];
+        match self.process_transaction_with_metadata(transaction) {
+            TransactionExecutionResult::Executed { .. } => Ok(signature),
+            TransactionExecutionResult::NotExecuted(tx_error) => Err(tx_error.into()),
+        }
+    }
+}
+
 impl SimulateTransaction<ExecutionOutput> for Bank {
     fn simulate_transaction(
         &self,
@@ -174,6 +250,14 @@ impl SimulateTransaction<ExecutionOutput> for Bank {
         SimulateTransaction::<ExecutionEffect>::simulate_transaction(self, transaction)
             .map(Into::into)
     }
+
+    fn simulate_transaction_unverified(
+        &self,
+        transaction: VersionedTransaction,
+    ) -> ClientResult<ExecutionOutput> {
+        SimulateTransaction::<ExecutionEffect>::simulate_transaction_unverified(self, transaction)
+            .map(Into::into)
+    }
 }
 
 impl SimulateTransaction<ExecutionEffect> for Bank {
@@ -181,32 +265,51 @@ impl SimulateTransaction<ExecutionEffect> for Bank {
         &self,
         transaction: VersionedTransaction,
     ) -> ClientResult<ExecutionEffect> {
-        let sanitized_transaction = self.fully_verify_transaction(transaction.clone())?;
-        let result = self.simulate_transaction_unchecked(&sanitized_transaction, false);
-
-        if result.units_consumed == 0 {
-            return Err(result.result.unwrap_err().into());
-        }
+        simulate_with_verification_mode(
+            self,
+            transaction,
+            TransactionVerificationMode::FullVerification,
+        )
+    }
 
-        let lamports_per_signature = self
-            .get_lamports_per_signature_for_blockhash(
-                sanitized_transaction.message().recent_blockhash(),
-            )
-            .unwrap();
+    fn simulate_transaction_unverified(
+        &self,
+        transaction: VersionedTransaction,
+    ) -> ClientResult<ExecutionEffect> {
+        simulate_with_verification_mode(self, transaction, TransactionVerificationMode::HashOnly)
+    }
+}
 
-        let fee = self.get_fee_for_message_with_lamports_per_signature(
-            sanitized_transaction.message(),
-            lamports_per_signature,
-        );
+fn simulate_with_verification_mode(
+    bank: &Bank,
+    transaction: VersionedTransaction,
+    verification_mode: TransactionVerificationMode,
+) -> ClientResult<ExecutionEffect> {
+    let sanitized_transaction = bank.verify_transaction(transaction.clone(), verification_mode)?;
+    let result = bank.simulate_transaction_unchecked(&sanitized_transaction, true);
 
-        Ok(convert_simulation_result(
-            self,
-            transaction,
-            sanitized_transaction,
-            result,
-            fee,
-        ))
+    if result.units_consumed == 0 {
+        return Err(result.result.unwrap_err().into());
     }
+
+    let lamports_per_signature = bank
+        .get_lamports_per_signature_for_blockhash(
+            sanitized_transaction.message().recent_blockhash(),
+        )
+        .unwrap();
+
+    let fee = bank.get_fee_for_message_with_lamports_per_signature(
+        sanitized_transaction.message(),
+        lamports_per_signature,
+    );
+
+    Ok(convert_simulation_result(
+        bank,
+        transaction,
+        sanitized_transaction,
+        result,
+        fee,
+    ))
 }
 
 fn convert_simulation_result(
@@ -222,7 +325,7 @@ fn convert_simulation_result(
         post_simulation_accounts,
         units_consumed,
         return_data,
-        inner_instructions: _,
+        inner_instructions,
     } = result;
 
     // TODO: missing post accounts if the tx is not executed (e.g. blockhash not found)
@@ -258,6 +361,7 @@ fn convert_simulation_result(
         compute_units_consumed: units_consumed,
         return_data,
         fee,
+        inner_instructions,
         post_accounts,
     }
 }
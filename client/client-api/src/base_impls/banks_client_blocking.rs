@@ -2,40 +2,113 @@ use solana_banks_interface::{
     BanksTransactionResultWithMetadata, BanksTransactionResultWithSimulation, TransactionMetadata,
     TransactionSimulationDetails,
 };
-use solana_sdk::account::Account;
+use solana_sdk::account::{Account, AccountSharedData};
+use solana_sdk::clock::Slot;
 use solana_sdk::commitment_config::CommitmentLevel;
 use solana_sdk::hash::Hash;
+use solana_sdk::message::{Message, MessageHeader};
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::rent::Rent;
 use solana_sdk::signature::Signature;
+use solana_sdk::sysvar::rent;
 use solana_sdk::transaction::VersionedTransaction;
+use solana_transaction_status::{TransactionConfirmationStatus, TransactionStatus};
+use tarpc::context::Context;
 
 use dexter_solana_banks_client_blocking::BanksClient;
 
-use crate::base::executor::{ProcessTransaction, SimulateTransaction};
+use crate::base::executor::{
+    ProcessTransaction, ProcessTransactionBatch, RequestAirdrop, SendTransaction,
+    SimulateTransaction,
+};
 use crate::base::getter::{
-    GetAccount, GetLatestBlockhash, GetMinimumBalanceForRentExemption, GetMultipleAccounts,
+    GetAccount, GetBlockHeight, GetLatestBlockhash, GetLatestBlockhashWithExpiry,
+    GetMinimumBalanceForRentExemption, GetMultipleAccounts, GetProgramAccounts,
+    GetSignatureStatuses, ProgramAccountsFilter,
 };
+use crate::base::options::CallOptions;
+use crate::base::setter::{FundAccount, WarpSlot};
 use crate::client::Client;
-use crate::errors::ClientResult;
+use crate::errors::{ClientError, ClientResult};
 use crate::execution::ExecutionOutput;
+use crate::internals::fee::CalculateFee;
 use crate::internals::sanitize::SanitizeTransaction;
 
 impl Client for BanksClient {}
 
 impl GetAccount for BanksClient {
     fn get_account(&self, pubkey: &Pubkey) -> ClientResult<Option<Account>> {
-        Ok(self
-            .clone()
-            .get_account_with_commitment(*pubkey, CommitmentLevel::Processed)?)
+        self.get_account_with_options(pubkey, &CallOptions::default())
+    }
+
+    fn get_account_with_options(
+        &self,
+        pubkey: &Pubkey,
+        options: &CallOptions,
+    ) -> ClientResult<Option<Account>> {
+        Ok(self.get_account_with_commitment_and_context(
+            call_context(self, options),
+            *pubkey,
+            CommitmentLevel::Processed,
+        )?)
+    }
+}
+
+// the banks interface cannot enumerate accounts, so only registered ones are scanned
+impl GetProgramAccounts for BanksClient {
+    fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        self.get_program_accounts_with_options(program_id, filters, &CallOptions::default())
+    }
+
+    fn get_program_accounts_with_options(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+        options: &CallOptions,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        let filters = filters.unwrap_or_default();
+        let program_accounts = self
+            .get_registered_accounts_with_commitment_and_context(
+                call_context(self, options),
+                CommitmentLevel::Processed,
+            )?
+            .into_iter()
+            .filter(|(_, account)| account.owner == *program_id)
+            .filter(|(_, account)| {
+                let account = AccountSharedData::from(account.clone());
+                filters
+                    .iter()
+                    .all(|filter_type| filter_type.allows(&account))
+            })
+            .collect();
+
+        Ok(program_accounts)
     }
 }
 
 impl GetMultipleAccounts for BanksClient {
     fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> ClientResult<Vec<Option<Account>>> {
-        let mut client = self.clone();
+        self.get_multiple_accounts_with_options(pubkeys, &CallOptions::default())
+    }
+
+    fn get_multiple_accounts_with_options(
+        &self,
+        pubkeys: &[Pubkey],
+        options: &CallOptions,
+    ) -> ClientResult<Vec<Option<Account>>> {
+        // a single context so that the deadline bounds the whole batch
+        let ctx = call_context(self, options);
         let mut accounts = Vec::new();
         for pubkey in pubkeys {
-            accounts.push(client.get_account_with_commitment(*pubkey, CommitmentLevel::Processed)?);
+            accounts.push(self.get_account_with_commitment_and_context(
+                ctx,
+                *pubkey,
+                CommitmentLevel::Processed,
+            )?);
         }
         Ok(accounts)
     }
@@ -43,60 +116,172 @@ impl GetMultipleAccounts for BanksClient {
 
 impl GetMinimumBalanceForRentExemption for BanksClient {
     fn get_minimum_balance_for_rent_exemption(&self, data_len: usize) -> ClientResult<u64> {
-        Ok(self.clone().get_rent()?.minimum_balance(data_len))
+        self.get_minimum_balance_for_rent_exemption_with_options(data_len, &CallOptions::default())
+    }
+
+    fn get_minimum_balance_for_rent_exemption_with_options(
+        &self,
+        data_len: usize,
+        options: &CallOptions,
+    ) -> ClientResult<u64> {
+        let account = self
+            .get_account_with_commitment_and_context(
+                call_context(self, options),
+                rent::id(),
+                CommitmentLevel::Processed,
+            )?
+            .ok_or(ClientError::AccountNotFound(rent::id()))?;
+        let rent: Rent = bincode::deserialize(&account.data)
+            .map_err(|_| ClientError::AccountDidNotDeserialize(rent::id()))?;
+        Ok(rent.minimum_balance(data_len))
     }
 }
 
 impl GetLatestBlockhash for BanksClient {
     fn get_latest_blockhash(&self) -> ClientResult<Hash> {
+        self.get_latest_blockhash_with_options(&CallOptions::default())
+    }
+
+    fn get_latest_blockhash_with_options(&self, options: &CallOptions) -> ClientResult<Hash> {
         let (blockhash, _) = self
-            .clone()
-            .get_latest_blockhash_with_commitment(CommitmentLevel::Processed)?
+            .get_latest_blockhash_with_commitment_and_context(
+                call_context(self, options),
+                CommitmentLevel::Processed,
+            )?
             .expect("missing blockhash");
         Ok(blockhash)
     }
 }
 
+impl GetLatestBlockhashWithExpiry for BanksClient {
+    fn get_latest_blockhash_with_last_valid_block_height(&self) -> ClientResult<(Hash, u64)> {
+        Ok(self
+            .get_latest_blockhash_with_commitment_and_context(
+                self.context(),
+                CommitmentLevel::Processed,
+            )?
+            .expect("missing blockhash"))
+    }
+}
+
+impl GetSignatureStatuses for BanksClient {
+    fn get_signature_statuses(
+        &self,
+        signatures: &[Signature],
+    ) -> ClientResult<Vec<Option<TransactionStatus>>> {
+        let statuses = self
+            .get_transaction_statuses(signatures.to_vec())?
+            .into_iter()
+            .map(|status| {
+                status.map(|status| TransactionStatus {
+                    slot: status.slot,
+                    confirmations: status.confirmations,
+                    status: status.err.clone().map_or(Ok(()), Err),
+                    err: status.err,
+                    confirmation_status: status.confirmation_status.map(|confirmation_status| {
+                        match confirmation_status {
+                            solana_banks_interface::TransactionConfirmationStatus::Processed => {
+                                TransactionConfirmationStatus::Processed
+                            }
+                            solana_banks_interface::TransactionConfirmationStatus::Confirmed => {
+                                TransactionConfirmationStatus::Confirmed
+                            }
+                            solana_banks_interface::TransactionConfirmationStatus::Finalized => {
+                                TransactionConfirmationStatus::Finalized
+                            }
+                        }
+                    }),
+                })
+            })
+            .collect();
+        Ok(statuses)
+    }
+}
+
+impl GetBlockHeight for BanksClient {
+    fn get_block_height(&self) -> ClientResult<u64> {
+        Ok(self.get_block_height_with_context(self.context(), CommitmentLevel::Processed)?)
+    }
+}
+
 impl ProcessTransaction<Signature> for BanksClient {
     fn process_transaction(&self, transaction: VersionedTransaction) -> ClientResult<Signature> {
+        self.process_transaction_with_options(transaction, &CallOptions::default())
+    }
+
+    fn process_transaction_with_options(
+        &self,
+        transaction: VersionedTransaction,
+        options: &CallOptions,
+    ) -> ClientResult<Signature> {
         let signature = transaction.signatures[0];
-        self.clone()
-            .process_transaction_with_metadata(transaction)?;
+        self.process_transaction_with_metadata_and_context(
+            call_context(self, options),
+            transaction,
+        )?;
         Ok(signature)
     }
 }
 
+impl ProcessTransactionBatch<Signature> for BanksClient {}
+
 impl ProcessTransaction<ExecutionOutput> for BanksClient {
     fn process_transaction(
         &self,
         transaction: VersionedTransaction,
     ) -> ClientResult<ExecutionOutput> {
-        let BanksTransactionResultWithMetadata { result, metadata } = self
-            .clone()
-            .process_transaction_with_metadata(transaction.clone())?;
+        self.process_transaction_with_options(transaction, &CallOptions::default())
+    }
 
-        let Some(metadata) = metadata else {
-            return Err(result.unwrap_err().into());
-        };
+    fn process_transaction_with_options(
+        &self,
+        transaction: VersionedTransaction,
+        options: &CallOptions,
+    ) -> ClientResult<ExecutionOutput> {
+        let result_with_metadata = self.process_transaction_with_metadata_and_context(
+            call_context(self, options),
+            transaction.clone(),
+        )?;
+        self.execution_output(transaction, result_with_metadata)
+    }
+}
 
-        let fee = self
-            .get_fee_for_versioned_transaction(transaction.clone())?
-            .unwrap();
+impl ProcessTransactionBatch<ExecutionOutput> for BanksClient {
+    fn process_transaction_batch(
+        &self,
+        transactions: Vec<VersionedTransaction>,
+    ) -> ClientResult<Vec<ClientResult<ExecutionOutput>>> {
+        let results = self.process_transactions_with_metadata(transactions.clone())?;
+        Ok(transactions
+            .into_iter()
+            .zip(results)
+            .map(|(transaction, result_with_metadata)| {
+                self.execution_output(transaction, result_with_metadata)
+            })
+            .collect())
+    }
+}
 
-        let TransactionMetadata {
-            log_messages,
-            compute_units_consumed,
-            return_data,
-        } = metadata;
+impl SendTransaction for BanksClient {
+    fn send_transaction(&self, transaction: VersionedTransaction) -> ClientResult<Signature> {
+        let signature = transaction.signatures[0];
+        BanksClient::send_transaction(self, transaction)?;
+        Ok(signature)
+    }
+}
 
-        Ok(ExecutionOutput {
-            transaction,
-            result,
-            logs: log_messages,
-            compute_units_consumed,
-            return_data,
-            fee,
-        })
+// requires a faucet set with `with_faucet`
+impl RequestAirdrop for BanksClient {
+    fn request_airdrop(&self, pubkey: &Pubkey, lamports: u64) -> ClientResult<Signature> {
+        Ok(BanksClient::request_airdrop(self, pubkey, lamports)?)
+    }
+}
+
+// the airdrop is processed by the time it returns
+impl FundAccount for BanksClient {
+    fn fund_account(&mut self, pubkey: &Pubkey, lamports: u64) -> ClientResult<()> {
+        RequestAirdrop::request_airdrop(self, pubkey, lamports)?;
+        Ok(())
     }
 }
 
@@ -105,9 +290,19 @@ impl SimulateTransaction<ExecutionOutput> for BanksClient {
         &self,
         transaction: VersionedTransaction,
     ) -> ClientResult<ExecutionOutput> {
-        let result = self
-            .clone()
-            .simulate_transaction_with_commitment(transaction.clone(), CommitmentLevel::Processed);
+        self.simulate_transaction_with_options(transaction, &CallOptions::default())
+    }
+
+    fn simulate_transaction_with_options(
+        &self,
+        transaction: VersionedTransaction,
+        options: &CallOptions,
+    ) -> ClientResult<ExecutionOutput> {
+        let result = self.simulate_transaction_with_commitment_and_context(
+            call_context(self, options),
+            transaction.clone(),
+            CommitmentLevel::Processed,
+        );
 
         match result {
             Ok(BanksTransactionResultWithSimulation {
@@ -120,7 +315,7 @@ impl SimulateTransaction<ExecutionOutput> for BanksClient {
                     logs,
                     units_consumed,
                     return_data,
-                    inner_instructions: _,
+                    inner_instructions,
                 } = simulation_details.expect("missing transaction simulation details");
 
                 if units_consumed == 0 {
@@ -138,6 +333,9 @@ impl SimulateTransaction<ExecutionOutput> for BanksClient {
                     compute_units_consumed: units_consumed,
                     return_data,
                     fee,
+                    // the interface has no switch for cpi recording, so these are present only
+                    // if the banks server records them
+                    inner_instructions,
                 })
             }
             Err(err) => Err(err.into()),
@@ -145,11 +343,33 @@ impl SimulateTransaction<ExecutionOutput> for BanksClient {
     }
 }
 
+// requires a bank set with `with_warp`
+impl WarpSlot for BanksClient {
+    fn warp_to_slot(&mut self, slot: Slot) -> ClientResult<()> {
+        Ok(BanksClient::warp_to_slot(self, slot)?)
+    }
+}
+
+// the default deadline of the client, which the options may override
+fn call_context(client: &BanksClient, options: &CallOptions) -> Context {
+    let mut ctx = client.context();
+    if let Some(deadline) = options.deadline() {
+        ctx.deadline = deadline;
+    }
+    ctx
+}
+
 trait BanksClientExt {
     fn get_fee_for_versioned_transaction(
         &self,
         transaction: VersionedTransaction,
     ) -> ClientResult<Option<u64>>;
+
+    fn execution_output(
+        &self,
+        transaction: VersionedTransaction,
+        result_with_metadata: BanksTransactionResultWithMetadata,
+    ) -> ClientResult<ExecutionOutput>;
 }
 
 impl BanksClientExt for BanksClient {
@@ -157,22 +377,60 @@ impl BanksClientExt for BanksClient {
         &self,
         transaction: VersionedTransaction,
     ) -> ClientResult<Option<u64>> {
-        let sanitized_transaction = self.sanitize_transaction(transaction.clone())?;
+        // the banks interface prices only legacy messages, so the signature fee at the blockhash
+        // is queried with a message signed by the fee payer alone and the rest is priced locally
+        let fee_payer = transaction.message.static_account_keys()[0];
+        let signer_message = Message {
+            header: MessageHeader {
+                num_required_signatures: 1,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 0,
+            },
+            account_keys: vec![fee_payer],
+            recent_blockhash: *transaction.message.recent_blockhash(),
+            instructions: vec![],
+        };
+        let Some(lamports_per_signature) = self.get_fee_for_message(signer_message)? else {
+            return Ok(None);
+        };
 
-        let account_keys: Vec<_> = sanitized_transaction
-            .message()
-            .account_keys()
-            .iter()
-            .copied()
-            .collect();
+        let sanitized_transaction = self.sanitize_transaction(transaction)?;
+        Ok(Some(self.calculate_fee(
+            &sanitized_transaction,
+            lamports_per_signature,
+        )))
+    }
 
-        let legacy_message = solana_sdk::message::legacy::Message {
-            header: *transaction.message.header(),
-            account_keys,
-            recent_blockhash: *transaction.message.recent_blockhash(),
-            instructions: transaction.message.instructions().to_vec(),
+    fn execution_output(
+        &self,
+        transaction: VersionedTransaction,
+        result_with_metadata: BanksTransactionResultWithMetadata,
+    ) -> ClientResult<ExecutionOutput> {
+        let BanksTransactionResultWithMetadata { result, metadata } = result_with_metadata;
+
+        let Some(metadata) = metadata else {
+            return Err(result.unwrap_err().into());
         };
 
-        Ok(self.clone().get_fee_for_message(legacy_message)?)
+        let fee = self
+            .get_fee_for_versioned_transaction(transaction.clone())?
+            .unwrap();
+
+        let TransactionMetadata {
+            log_messages,
+            compute_units_consumed,
+            return_data,
+        } = metadata;
+
+        Ok(ExecutionOutput {
+            transaction,
+            result,
+            logs: log_messages,
+            compute_units_consumed,
+            return_data,
+            fee,
+            // not carried by the transaction metadata of banks, unlike the simulation details
+            inner_instructions: None,
+        })
     }
 }
@@ -1,30 +1,24 @@
-use once_cell::sync::Lazy;
-use std::sync::Arc;
-
 use solana_sdk::account::Account;
 use solana_sdk::hash::Hash;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Signature;
 use solana_sdk::transaction::VersionedTransaction;
+use solana_transaction_status::TransactionStatus;
 
 use solana_banks_client::BanksClient;
 
-use crate::base::executor::{ProcessTransaction, SimulateTransaction};
+use crate::base::executor::{
+    ProcessTransaction, ProcessTransactionBatch, SendTransaction, SimulateTransaction,
+};
 use crate::base::getter::{
-    GetAccount, GetLatestBlockhash, GetMinimumBalanceForRentExemption, GetMultipleAccounts,
+    GetAccount, GetBlockHeight, GetLatestBlockhash, GetLatestBlockhashWithExpiry,
+    GetMinimumBalanceForRentExemption, GetMultipleAccounts, GetSignatureStatuses,
 };
+use crate::base::options::CallOptions;
 use crate::client::Client;
 use crate::errors::ClientResult;
 use crate::execution::ExecutionOutput;
-
-static RUNTIME: Lazy<Arc<tokio::runtime::Runtime>> = Lazy::new(|| {
-    tokio::runtime::Builder::new_multi_thread()
-        .thread_name("dexter-bank-clients")
-        .enable_all()
-        .build()
-        .map(Arc::new)
-        .unwrap()
-});
+use crate::internals::runtime::RUNTIME;
 
 trait BanksClientExt {
     fn blocking(&self) -> dexter_solana_banks_client_blocking::BanksClient;
@@ -32,7 +26,10 @@ trait BanksClientExt {
 
 impl BanksClientExt for BanksClient {
     fn blocking(&self) -> dexter_solana_banks_client_blocking::BanksClient {
-        dexter_solana_banks_client_blocking::BanksClient::with_runtime(self.clone(), RUNTIME.clone())
+        dexter_solana_banks_client_blocking::BanksClient::with_runtime(
+            self.clone(),
+            RUNTIME.clone(),
+        )
     }
 }
 
@@ -42,12 +39,29 @@ impl GetAccount for BanksClient {
     fn get_account(&self, pubkey: &Pubkey) -> ClientResult<Option<Account>> {
         self.blocking().get_account(pubkey)
     }
+
+    fn get_account_with_options(
+        &self,
+        pubkey: &Pubkey,
+        options: &CallOptions,
+    ) -> ClientResult<Option<Account>> {
+        self.blocking().get_account_with_options(pubkey, options)
+    }
 }
 
 impl GetMultipleAccounts for BanksClient {
     fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> ClientResult<Vec<Option<Account>>> {
         self.blocking().get_multiple_accounts(pubkeys)
     }
+
+    fn get_multiple_accounts_with_options(
+        &self,
+        pubkeys: &[Pubkey],
+        options: &CallOptions,
+    ) -> ClientResult<Vec<Option<Account>>> {
+        self.blocking()
+            .get_multiple_accounts_with_options(pubkeys, options)
+    }
 }
 
 impl GetMinimumBalanceForRentExemption for BanksClient {
@@ -55,18 +69,71 @@ impl GetMinimumBalanceForRentExemption for BanksClient {
         self.blocking()
             .get_minimum_balance_for_rent_exemption(data_len)
     }
+
+    fn get_minimum_balance_for_rent_exemption_with_options(
+        &self,
+        data_len: usize,
+        options: &CallOptions,
+    ) -> ClientResult<u64> {
+        self.blocking()
+            .get_minimum_balance_for_rent_exemption_with_options(data_len, options)
+    }
 }
 
 impl GetLatestBlockhash for BanksClient {
     fn get_latest_blockhash(&self) -> ClientResult<Hash> {
         self.blocking().get_latest_blockhash()
     }
+
+    fn get_latest_blockhash_with_options(&self, options: &CallOptions) -> ClientResult<Hash> {
+        self.blocking().get_latest_blockhash_with_options(options)
+    }
+}
+
+impl GetLatestBlockhashWithExpiry for BanksClient {
+    fn get_latest_blockhash_with_last_valid_block_height(&self) -> ClientResult<(Hash, u64)> {
+        self.blocking()
+            .get_latest_blockhash_with_last_valid_block_height()
+    }
+}
+
+impl GetSignatureStatuses for BanksClient {
+    fn get_signature_statuses(
+        &self,
+        signatures: &[Signature],
+    ) -> ClientResult<Vec<Option<TransactionStatus>>> {
+        self.blocking().get_signature_statuses(signatures)
+    }
+}
+
+impl GetBlockHeight for BanksClient {
+    fn get_block_height(&self) -> ClientResult<u64> {
+        self.blocking().get_block_height()
+    }
 }
 
 impl ProcessTransaction<Signature> for BanksClient {
     fn process_transaction(&self, transaction: VersionedTransaction) -> ClientResult<Signature> {
         self.blocking().process_transaction(transaction)
     }
+
+    fn process_transaction_with_options(
+        &self,
+        transaction: VersionedTransaction,
+        options: &CallOptions,
+    ) -> ClientResult<Signature> {
+        self.blocking()
+            .process_transaction_with_options(transaction, options)
+    }
+}
+
+impl ProcessTransactionBatch<Signature> for BanksClient {
+    fn process_transaction_batch(
+        &self,
+        transactions: Vec<VersionedTransaction>,
+    ) -> ClientResult<Vec<ClientResult<Signature>>> {
+        self.blocking().process_transaction_batch(transactions)
+    }
 }
 
 impl ProcessTransaction<ExecutionOutput> for BanksClient {
@@ -76,6 +143,30 @@ impl ProcessTransaction<ExecutionOutput> for BanksClient {
     ) -> ClientResult<ExecutionOutput> {
         self.blocking().process_transaction(transaction)
     }
+
+    fn process_transaction_with_options(
+        &self,
+        transaction: VersionedTransaction,
+        options: &CallOptions,
+    ) -> ClientResult<ExecutionOutput> {
+        self.blocking()
+            .process_transaction_with_options(transaction, options)
+    }
+}
+
+impl ProcessTransactionBatch<ExecutionOutput> for BanksClient {
+    fn process_transaction_batch(
+        &self,
+        transactions: Vec<VersionedTransaction>,
+    ) -> ClientResult<Vec<ClientResult<ExecutionOutput>>> {
+        self.blocking().process_transaction_batch(transactions)
+    }
+}
+
+impl SendTransaction for BanksClient {
+    fn send_transaction(&self, transaction: VersionedTransaction) -> ClientResult<Signature> {
+        SendTransaction::send_transaction(&self.blocking(), transaction)
+    }
 }
 
 impl SimulateTransaction<ExecutionOutput> for BanksClient {
@@ -85,4 +176,13 @@ impl SimulateTransaction<ExecutionOutput> for BanksClient {
     ) -> ClientResult<ExecutionOutput> {
         self.blocking().simulate_transaction(transaction)
     }
+
+    fn simulate_transaction_with_options(
+        &self,
+        transaction: VersionedTransaction,
+        options: &CallOptions,
+    ) -> ClientResult<ExecutionOutput> {
+        self.blocking()
+            .simulate_transaction_with_options(transaction, options)
+    }
 }
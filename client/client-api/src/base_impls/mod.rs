@@ -1,4 +1,6 @@
 pub mod bank;
 pub mod banks_client_blocking;
 pub mod banks_client_nonblocking;
+#[cfg(feature = "program-test")]
+pub mod program_test_context;
 pub mod rpc_client;
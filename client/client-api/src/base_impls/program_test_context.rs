@@ -0,0 +1,250 @@
+use solana_sdk::account::{Account, AccountSharedData, WritableAccount};
+use solana_sdk::clock::Slot;
+use solana_sdk::hash::Hash;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::rent::Rent;
+use solana_sdk::signature::Signature;
+use solana_sdk::system_program;
+use solana_sdk::sysvar::Sysvar;
+use solana_sdk::transaction::VersionedTransaction;
+use solana_transaction_status::TransactionStatus;
+
+use dexter_solana_banks_client_blocking::program_test::ProgramTestContext;
+
+use crate::base::executor::{
+    ProcessTransaction, ProcessTransactionBatch, RequestAirdrop, SendTransaction,
+    SimulateTransaction,
+};
+use crate::base::getter::{
+    GetAccount, GetBlockHeight, GetLatestBlockhash, GetLatestBlockhashWithExpiry,
+    GetMinimumBalanceForRentExemption, GetMultipleAccounts, GetProgramAccounts,
+    GetSignatureStatuses, ProgramAccountsFilter,
+};
+use crate::base::options::CallOptions;
+use crate::base::setter::{FundAccount, HasRent, SetAccount, SetSysvar, WarpSlot};
+use crate::client::Client;
+use crate::errors::{ClientError, ClientResult};
+use crate::execution::ExecutionOutput;
+
+// reads and transactions go through the banks client, while the setters reach the bank directly
+
+impl Client for ProgramTestContext {}
+
+impl GetAccount for ProgramTestContext {
+    fn get_account(&self, pubkey: &Pubkey) -> ClientResult<Option<Account>> {
+        GetAccount::get_account(self.banks_client(), pubkey)
+    }
+
+    fn get_account_with_options(
+        &self,
+        pubkey: &Pubkey,
+        options: &CallOptions,
+    ) -> ClientResult<Option<Account>> {
+        self.banks_client()
+            .get_account_with_options(pubkey, options)
+    }
+}
+
+impl GetProgramAccounts for ProgramTestContext {
+    fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        self.banks_client()
+            .get_program_accounts(program_id, filters)
+    }
+
+    fn get_program_accounts_with_options(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+        options: &CallOptions,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        self.banks_client()
+            .get_program_accounts_with_options(program_id, filters, options)
+    }
+}
+
+impl GetMultipleAccounts for ProgramTestContext {
+    fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> ClientResult<Vec<Option<Account>>> {
+        self.banks_client().get_multiple_accounts(pubkeys)
+    }
+
+    fn get_multiple_accounts_with_options(
+        &self,
+        pubkeys: &[Pubkey],
+        options: &CallOptions,
+    ) -> ClientResult<Vec<Option<Account>>> {
+        self.banks_client()
+            .get_multiple_accounts_with_options(pubkeys, options)
+    }
+}
+
+impl GetMinimumBalanceForRentExemption for ProgramTestContext {
+    fn get_minimum_balance_for_rent_exemption(&self, data_len: usize) -> ClientResult<u64> {
+        self.banks_client()
+            .get_minimum_balance_for_rent_exemption(data_len)
+    }
+
+    fn get_minimum_balance_for_rent_exemption_with_options(
+        &self,
+        data_len: usize,
+        options: &CallOptions,
+    ) -> ClientResult<u64> {
+        self.banks_client()
+            .get_minimum_balance_for_rent_exemption_with_options(data_len, options)
+    }
+}
+
+impl GetLatestBlockhash for ProgramTestContext {
+    fn get_latest_blockhash(&self) -> ClientResult<Hash> {
+        GetLatestBlockhash::get_latest_blockhash(self.banks_client())
+    }
+
+    fn get_latest_blockhash_with_options(&self, options: &CallOptions) -> ClientResult<Hash> {
+        self.banks_client()
+            .get_latest_blockhash_with_options(options)
+    }
+}
+
+impl GetLatestBlockhashWithExpiry for ProgramTestContext {
+    fn get_latest_blockhash_with_last_valid_block_height(&self) -> ClientResult<(Hash, u64)> {
+        self.banks_client()
+            .get_latest_blockhash_with_last_valid_block_height()
+    }
+}
+
+impl GetSignatureStatuses for ProgramTestContext {
+    fn get_signature_statuses(
+        &self,
+        signatures: &[Signature],
+    ) -> ClientResult<Vec<Option<TransactionStatus>>> {
+        self.banks_client().get_signature_statuses(signatures)
+    }
+}
+
+impl GetBlockHeight for ProgramTestContext {
+    fn get_block_height(&self) -> ClientResult<u64> {
+        GetBlockHeight::get_block_height(self.banks_client())
+    }
+}
+
+impl ProcessTransaction<Signature> for ProgramTestContext {
+    fn process_transaction(&self, transaction: VersionedTransaction) -> ClientResult<Signature> {
+        ProcessTransaction::process_transaction(self.banks_client(), transaction)
+    }
+
+    fn process_transaction_with_options(
+        &self,
+        transaction: VersionedTransaction,
+        options: &CallOptions,
+    ) -> ClientResult<Signature> {
+        self.banks_client()
+            .process_transaction_with_options(transaction, options)
+    }
+}
+
+impl ProcessTransactionBatch<Signature> for ProgramTestContext {
+    fn process_transaction_batch(
+        &self,
+        transactions: Vec<VersionedTransaction>,
+    ) -> ClientResult<Vec<ClientResult<Signature>>> {
+        self.banks_client().process_transaction_batch(transactions)
+    }
+}
+
+impl ProcessTransaction<ExecutionOutput> for ProgramTestContext {
+    fn process_transaction(
+        &self,
+        transaction: VersionedTransaction,
+    ) -> ClientResult<ExecutionOutput> {
+        ProcessTransaction::process_transaction(self.banks_client(), transaction)
+    }
+
+    fn process_transaction_with_options(
+        &self,
+        transaction: VersionedTransaction,
+        options: &CallOptions,
+    ) -> ClientResult<ExecutionOutput> {
+        self.banks_client()
+            .process_transaction_with_options(transaction, options)
+    }
+}
+
+impl ProcessTransactionBatch<ExecutionOutput> for ProgramTestContext {
+    fn process_transaction_batch(
+        &self,
+        transactions: Vec<VersionedTransaction>,
+    ) -> ClientResult<Vec<ClientResult<ExecutionOutput>>> {
+        self.banks_client().process_transaction_batch(transactions)
+    }
+}
+
+impl SendTransaction for ProgramTestContext {
+    fn send_transaction(&self, transaction: VersionedTransaction) -> ClientResult<Signature> {
+        SendTransaction::send_transaction(self.banks_client(), transaction)
+    }
+}
+
+impl RequestAirdrop for ProgramTestContext {
+    fn request_airdrop(&self, pubkey: &Pubkey, lamports: u64) -> ClientResult<Signature> {
+        RequestAirdrop::request_airdrop(self.banks_client(), pubkey, lamports)
+    }
+}
+
+impl SimulateTransaction<ExecutionOutput> for ProgramTestContext {
+    fn simulate_transaction(
+        &self,
+        transaction: VersionedTransaction,
+    ) -> ClientResult<ExecutionOutput> {
+        SimulateTransaction::simulate_transaction(self.banks_client(), transaction)
+    }
+
+    fn simulate_transaction_with_options(
+        &self,
+        transaction: VersionedTransaction,
+        options: &CallOptions,
+    ) -> ClientResult<ExecutionOutput> {
+        self.banks_client()
+            .simulate_transaction_with_options(transaction, options)
+    }
+}
+
+impl SetAccount for ProgramTestContext {
+    fn set_account(&mut self, pubkey: Pubkey, account: Account) {
+        ProgramTestContext::set_account(self, &pubkey, &account.into());
+    }
+}
+
+impl SetSysvar for ProgramTestContext {
+    fn set_sysvar<T: Sysvar>(&mut self, sysvar: &T) {
+        ProgramTestContext::set_sysvar(self, sysvar);
+    }
+}
+
+impl FundAccount for ProgramTestContext {
+    fn fund_account(&mut self, pubkey: &Pubkey, lamports: u64) -> ClientResult<()> {
+        let mut account = GetAccount::get_account(self, pubkey)?
+            .map(AccountSharedData::from)
+            .unwrap_or_else(|| AccountSharedData::new(0, 0, &system_program::id()));
+        account
+            .checked_add_lamports(lamports)
+            .map_err(|err| ClientError::DomainSpecific(Box::new(err)))?;
+        ProgramTestContext::set_account(self, pubkey, &account);
+        Ok(())
+    }
+}
+
+impl HasRent for ProgramTestContext {
+    fn rent(&self) -> Rent {
+        self.genesis_config().rent
+    }
+}
+
+impl WarpSlot for ProgramTestContext {
+    fn warp_to_slot(&mut self, slot: Slot) -> ClientResult<()> {
+        ProgramTestContext::warp_to_slot(self, slot)
+            .map_err(|err| ClientError::DomainSpecific(Box::new(err)))
+    }
+}
@@ -1,11 +1,13 @@
 use std::collections::BTreeMap;
+use std::future::Future;
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use base64::prelude::{Engine, BASE64_STANDARD};
 
 use solana_account_decoder::UiAccountEncoding;
-use solana_rpc_client::rpc_client::RpcClient;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient as NonblockingRpcClient;
+use solana_rpc_client::rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient};
 use solana_rpc_client_api::client_error::{
     Error as RpcClientError, ErrorKind as RpcClientErrorKind, Result as RpcClientResult,
 };
@@ -25,29 +27,43 @@ use solana_sdk::signature::Signature;
 use solana_sdk::system_program;
 use solana_sdk::transaction::{SanitizedTransaction, VersionedTransaction};
 use solana_sdk::transaction_context::TransactionReturnData;
-use solana_transaction_status::option_serializer::OptionSerializer;
 use solana_transaction_status::{
-    EncodedConfirmedTransactionWithStatusMeta, EncodedTransactionWithStatusMeta,
-    UiTransactionEncoding, UiTransactionReturnData, UiTransactionStatusMeta,
+    EncodedConfirmedTransactionWithStatusMeta, TransactionStatus, UiTransactionEncoding,
+    UiTransactionReturnData,
 };
 
-use crate::base::executor::{ProcessTransaction, SimulateTransaction};
+use crate::base::executor::{
+    ProcessTransaction, ProcessTransactionBatch, RequestAirdrop, SendTransaction,
+    SimulateTransaction,
+};
 use crate::base::getter::{
-    GetAccount, GetLatestBlockhash, GetMinimumBalanceForRentExemption, GetMultipleAccounts,
-    GetProgramAccounts, ProgramAccountsFilter,
+    DataSlice, GetAccount, GetBlockHeight, GetLatestBlockhash, GetLatestBlockhashWithExpiry,
+    GetMinimumBalanceForRentExemption, GetMultipleAccounts, GetProgramAccounts,
+    GetSignatureStatuses, GetSignaturesForAddress, GetTransaction, ProgramAccountsFilter,
+    RpcConfirmedTransactionStatusWithSignature,
 };
+use crate::base::options::CallOptions;
+use crate::base::setter::FundAccount;
 use crate::client::Client;
-use crate::errors::ClientResult;
+use crate::errors::{ClientError, ClientResult};
 use crate::execution::{ExecutionEffect, ExecutionOutput};
-use crate::exts::getter::GetMultipleAccountsExt;
+use crate::exts::getter::{GetMultipleAccountsExt, GetTransactionExt};
+use crate::internals::inner_instructions::decode_ui_inner_instructions;
 use crate::internals::sanitize::SanitizeTransaction;
 
 impl Client for RpcClient {}
 
 impl GetAccount for RpcClient {
     fn get_account(&self, pubkey: &Pubkey) -> ClientResult<Option<Account>> {
-        let response = self.get_account_with_commitment(pubkey, self.commitment())?;
-        Ok(response.value)
+        self.get_account_with_options(pubkey, &CallOptions::default())
+    }
+
+    fn get_account_with_options(
+        &self,
+        pubkey: &Pubkey,
+        options: &CallOptions,
+    ) -> ClientResult<Option<Account>> {
+        GetAccount::get_account(&self.call(options), pubkey)
     }
 }
 
@@ -57,31 +73,66 @@ impl GetProgramAccounts for RpcClient {
         program_id: &Pubkey,
         filters: Option<Vec<ProgramAccountsFilter>>,
     ) -> ClientResult<Vec<(Pubkey, Account)>> {
-        let mut program_accounts = self.get_program_accounts_with_config(
-            program_id,
-            RpcProgramAccountsConfig {
-                filters,
-                account_config: RpcAccountInfoConfig {
-                    encoding: Some(UiAccountEncoding::Base64),
-                    data_slice: None,
-                    commitment: Some(self.commitment()),
-                    min_context_slot: None,
-                },
-                with_context: None,
-            },
-        )?;
+        self.get_program_accounts_with_options(program_id, filters, &CallOptions::default())
+    }
 
-        // returned accounts are not sorted if the underlying bank enables indexing
-        program_accounts.sort_by_key(|(key, _)| *key);
+    fn get_program_accounts_with_options(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+        options: &CallOptions,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        GetProgramAccounts::get_program_accounts(&self.call(options), program_id, filters)
+    }
 
-        Ok(program_accounts)
+    fn get_program_account_keys(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+    ) -> ClientResult<Vec<Pubkey>> {
+        self.call(&CallOptions::default())
+            .get_program_account_keys(program_id, filters)
+    }
+
+    fn get_program_accounts_sliced(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+        data_slice: DataSlice,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        self.call(&CallOptions::default())
+            .get_program_accounts_sliced(program_id, filters, data_slice)
     }
 }
 
 impl GetMultipleAccounts for RpcClient {
     fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> ClientResult<Vec<Option<Account>>> {
+        self.get_multiple_accounts_with_options(pubkeys, &CallOptions::default())
+    }
+
+    fn get_multiple_accounts_with_options(
+        &self,
+        pubkeys: &[Pubkey],
+        options: &CallOptions,
+    ) -> ClientResult<Vec<Option<Account>>> {
+        GetMultipleAccounts::get_multiple_accounts(&self.call(options), pubkeys)
+    }
+
+    fn get_multiple_accounts_sliced(
+        &self,
+        pubkeys: &[Pubkey],
+        data_slice: DataSlice,
+    ) -> ClientResult<Vec<Option<Account>>> {
         let accounts = self
-            .get_multiple_accounts_with_commitment(pubkeys, self.commitment())?
+            .get_multiple_accounts_with_config(
+                pubkeys,
+                RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    data_slice: Some(data_slice),
+                    commitment: Some(self.commitment()),
+                    min_context_slot: None,
+                },
+            )?
             .value;
         assert_eq!(accounts.len(), pubkeys.len());
         Ok(accounts)
@@ -90,116 +141,154 @@ impl GetMultipleAccounts for RpcClient {
 
 impl GetMinimumBalanceForRentExemption for RpcClient {
     fn get_minimum_balance_for_rent_exemption(&self, data_len: usize) -> ClientResult<u64> {
-        Ok(RpcClient::get_minimum_balance_for_rent_exemption(
-            self, data_len,
-        )?)
+        self.get_minimum_balance_for_rent_exemption_with_options(data_len, &CallOptions::default())
+    }
+
+    fn get_minimum_balance_for_rent_exemption_with_options(
+        &self,
+        data_len: usize,
+        options: &CallOptions,
+    ) -> ClientResult<u64> {
+        let call = self.call(options);
+        Ok(call.invoke(
+            call.inner()
+                .get_minimum_balance_for_rent_exemption(data_len),
+        )??)
     }
 }
 
 impl GetLatestBlockhash for RpcClient {
     fn get_latest_blockhash(&self) -> ClientResult<Hash> {
-        let (blockhash, _) = self.get_latest_blockhash_with_commitment(self.commitment())?;
+        self.get_latest_blockhash_with_options(&CallOptions::default())
+    }
+
+    fn get_latest_blockhash_with_options(&self, options: &CallOptions) -> ClientResult<Hash> {
+        let (blockhash, _) = self
+            .call(options)
+            .get_latest_blockhash_with_last_valid_block_height()?;
         Ok(blockhash)
     }
 }
 
-impl ProcessTransaction<Signature> for RpcClient {
-    fn process_transaction(&self, transaction: VersionedTransaction) -> ClientResult<Signature> {
-        let signature = transaction.signatures[0];
+impl GetLatestBlockhashWithExpiry for RpcClient {
+    fn get_latest_blockhash_with_last_valid_block_height(&self) -> ClientResult<(Hash, u64)> {
+        self.call(&CallOptions::default())
+            .get_latest_blockhash_with_last_valid_block_height()
+    }
+}
 
-        let result = self.send_transaction_with_config(
-            &transaction,
-            RpcSendTransactionConfig {
-                skip_preflight: false,
-                preflight_commitment: Some(CommitmentLevel::Processed),
-                encoding: None,
-                max_retries: None,
-                min_context_slot: None,
+impl GetTransaction for RpcClient {
+    fn get_transaction(
+        &self,
+        signature: &Signature,
+    ) -> ClientResult<Option<EncodedConfirmedTransactionWithStatusMeta>> {
+        self.call(&CallOptions::default())
+            .get_transaction(signature)
+    }
+}
+
+impl GetSignaturesForAddress for RpcClient {
+    fn get_signatures_for_address(
+        &self,
+        address: &Pubkey,
+        before: Option<Signature>,
+        until: Option<Signature>,
+        limit: Option<usize>,
+    ) -> ClientResult<Vec<RpcConfirmedTransactionStatusWithSignature>> {
+        let statuses = self.get_signatures_for_address_with_config(
+            address,
+            GetConfirmedSignaturesForAddress2Config {
+                before,
+                until,
+                limit,
+                // signatures are not indexed at the processed commitment
+                commitment: Some(CommitmentConfig::confirmed()),
             },
-        );
+        )?;
+        Ok(statuses)
+    }
+}
 
-        if let Err(RpcClientError {
-            kind:
-                RpcClientErrorKind::RpcError(RpcError::RpcResponseError {
-                    data: RpcResponseErrorData::SendTransactionPreflightFailure(tx_result),
-                    ..
-                }),
-            ..
-        }) = result
-        {
-            if tx_result.units_consumed.unwrap() == 0 {
-                return Err(tx_result.err.unwrap().into());
-            }
-        }
+impl GetSignatureStatuses for RpcClient {
+    fn get_signature_statuses(
+        &self,
+        signatures: &[Signature],
+    ) -> ClientResult<Vec<Option<TransactionStatus>>> {
+        Ok(RpcClient::get_signature_statuses(self, signatures)?.value)
+    }
+}
 
-        let result = self.send_and_confirm_transaction_with_spinner_and_config(
+impl GetBlockHeight for RpcClient {
+    fn get_block_height(&self) -> ClientResult<u64> {
+        Ok(RpcClient::get_block_height(self)?)
+    }
+}
+
+impl SendTransaction for RpcClient {
+    fn send_transaction(&self, transaction: VersionedTransaction) -> ClientResult<Signature> {
+        let signature = self.send_transaction_with_config(
             &transaction,
-            CommitmentConfig::confirmed(),
             RpcSendTransactionConfig {
                 skip_preflight: true,
                 preflight_commitment: None,
                 encoding: None,
-                max_retries: None,
+                max_retries: Some(0),
                 min_context_slot: None,
             },
-        );
+        )?;
+        Ok(signature)
+    }
+}
 
-        let error = match result {
-            // confirmed successfully
-            Ok(confirmed_signature) => {
-                assert_eq!(confirmed_signature, signature);
-                return Ok(signature);
-            }
-            Err(error) => error,
-        };
+impl RequestAirdrop for RpcClient {
+    fn request_airdrop(&self, pubkey: &Pubkey, lamports: u64) -> ClientResult<Signature> {
+        Ok(RpcClient::request_airdrop(self, pubkey, lamports)?)
+    }
+}
 
-        match &error.kind {
-            // confirmed but failed
-            RpcClientErrorKind::TransactionError(_) => Ok(signature),
-            _ => Err(error.into()),
-        }
+impl FundAccount for RpcClient {
+    fn fund_account(&mut self, pubkey: &Pubkey, lamports: u64) -> ClientResult<()> {
+        let signature = RequestAirdrop::request_airdrop(self, pubkey, lamports)?;
+        self.poll_for_signature_with_commitment(&signature, CommitmentConfig::confirmed())?;
+        Ok(())
     }
 }
 
+impl ProcessTransaction<Signature> for RpcClient {
+    fn process_transaction(&self, transaction: VersionedTransaction) -> ClientResult<Signature> {
+        self.process_transaction_with_options(transaction, &CallOptions::default())
+    }
+
+    fn process_transaction_with_options(
+        &self,
+        transaction: VersionedTransaction,
+        options: &CallOptions,
+    ) -> ClientResult<Signature> {
+        ProcessTransaction::<Signature>::process_transaction(&self.call(options), transaction)
+    }
+}
+
+impl ProcessTransactionBatch<Signature> for RpcClient {}
+
 impl ProcessTransaction<ExecutionOutput> for RpcClient {
     fn process_transaction(
         &self,
         transaction: VersionedTransaction,
     ) -> ClientResult<ExecutionOutput> {
-        const MAX_RETRIES: usize = 10;
-        const RETRY_INTERVAL: Duration = Duration::from_secs(1);
-
-        let signature =
-            ProcessTransaction::<Signature>::process_transaction(self, transaction.clone())?;
-
-        let confirmed = {
-            let mut num_retries = 0;
-
-            loop {
-                let result = self.get_transaction_with_config(
-                    &signature,
-                    RpcTransactionConfig {
-                        encoding: Some(UiTransactionEncoding::Base64),
-                        commitment: Some(CommitmentConfig::confirmed()),
-                        max_supported_transaction_version: Some(0),
-                    },
-                );
-
-                match result {
-                    Ok(confirmed) => break confirmed,
-                    Err(err) if num_retries >= MAX_RETRIES => return Err(err.into()),
-                    Err(_) => {
-                        num_retries += 1;
-                        thread::sleep(RETRY_INTERVAL);
-                    }
-                }
-            }
-        };
+        self.process_transaction_with_options(transaction, &CallOptions::default())
+    }
 
-        Ok(convert_processed(transaction, confirmed))
+    fn process_transaction_with_options(
+        &self,
+        transaction: VersionedTransaction,
+        options: &CallOptions,
+    ) -> ClientResult<ExecutionOutput> {
+        ProcessTransaction::<ExecutionOutput>::process_transaction(&self.call(options), transaction)
     }
 }
 
+impl ProcessTransactionBatch<ExecutionOutput> for RpcClient {}
+
 impl SimulateTransaction<ExecutionOutput> for RpcClient {
     fn simulate_transaction(
         &self,
@@ -208,6 +297,27 @@ impl SimulateTransaction<ExecutionOutput> for RpcClient {
         SimulateTransaction::<ExecutionEffect>::simulate_transaction(self, transaction)
             .map(Into::into)
     }
+
+    fn simulate_transaction_unverified(
+        &self,
+        transaction: VersionedTransaction,
+    ) -> ClientResult<ExecutionOutput> {
+        SimulateTransaction::<ExecutionEffect>::simulate_transaction_unverified(self, transaction)
+            .map(Into::into)
+    }
+
+    fn simulate_transaction_with_options(
+        &self,
+        transaction: VersionedTransaction,
+        options: &CallOptions,
+    ) -> ClientResult<ExecutionOutput> {
+        SimulateTransaction::<ExecutionEffect>::simulate_transaction_with_options(
+            self,
+            transaction,
+            options,
+        )
+        .map(Into::into)
+    }
 }
 
 impl SimulateTransaction<ExecutionEffect> for RpcClient {
@@ -215,94 +325,64 @@ impl SimulateTransaction<ExecutionEffect> for RpcClient {
         &self,
         transaction: VersionedTransaction,
     ) -> ClientResult<ExecutionEffect> {
-        let sanitized_transaction = self.sanitize_transaction(transaction.clone())?;
-
-        let addresses = sanitized_transaction
-            .message()
-            .account_keys()
-            .iter()
-            .map(ToString::to_string)
-            .collect();
-
-        let result = self
-            .simulate_transaction_with_config(
-                &transaction,
-                RpcSimulateTransactionConfig {
-                    sig_verify: true,
-                    replace_recent_blockhash: false,
-                    commitment: Some(CommitmentConfig::processed()),
-                    encoding: Some(UiTransactionEncoding::Base64),
-                    accounts: Some(RpcSimulateTransactionAccountsConfig {
-                        encoding: Some(UiAccountEncoding::Base64),
-                        addresses,
-                    }),
-                    min_context_slot: None,
-                    inner_instructions: false,
-                },
-            )?
-            .value;
-
-        if result.units_consumed.unwrap() == 0 {
-            return Err(result.err.unwrap().into());
-        }
+        self.simulate_transaction_with_options(transaction, &CallOptions::default())
+    }
 
-        let fee = self.get_fee_for_versioned_message(&transaction.message)?;
+    fn simulate_transaction_with_options(
+        &self,
+        transaction: VersionedTransaction,
+        options: &CallOptions,
+    ) -> ClientResult<ExecutionEffect> {
+        simulate_with_sig_verify(&self.call(options), transaction, true)
+    }
 
-        convert_simulated(self, transaction, sanitized_transaction, result, fee)
+    fn simulate_transaction_unverified(
+        &self,
+        transaction: VersionedTransaction,
+    ) -> ClientResult<ExecutionEffect> {
+        simulate_with_sig_verify(&self.call(&CallOptions::default()), transaction, false)
     }
 }
 
-fn convert_processed(
+fn simulate_with_sig_verify(
+    call: &RpcCall,
     transaction: VersionedTransaction,
-    confirmed: EncodedConfirmedTransactionWithStatusMeta,
-) -> ExecutionOutput {
-    let EncodedConfirmedTransactionWithStatusMeta {
-        transaction:
-            EncodedTransactionWithStatusMeta {
-                meta:
-                    Some(UiTransactionStatusMeta {
-                        err,
-                        fee,
-                        log_messages: OptionSerializer::Some(logs),
-                        return_data: ui_return_data_opt,
-                        compute_units_consumed: OptionSerializer::Some(compute_units_consumed),
-                        ..
-                    }),
-                ..
-            },
-        ..
-    } = confirmed
-    else {
-        panic!("unexpected transaction format: {:?}", confirmed);
-    };
+    sig_verify: bool,
+) -> ClientResult<ExecutionEffect> {
+    let sanitized_transaction = call.sanitize_transaction(transaction.clone())?;
 
-    let return_data = if let OptionSerializer::Some(ui_return_data) = ui_return_data_opt {
-        let UiTransactionReturnData {
-            program_id,
-            data: (ui_data, _),
-        } = ui_return_data;
+    let addresses = sanitized_transaction
+        .message()
+        .account_keys()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
 
-        let program_id = program_id.parse().expect("return data program id");
-        let data = BASE64_STANDARD.decode(ui_data).expect("return data data");
+    let result = call
+        .invoke(call.inner().simulate_transaction_with_config(
+            &transaction,
+            RpcSimulateTransactionConfig {
+                sig_verify,
+                replace_recent_blockhash: false,
+                commitment: Some(CommitmentConfig::processed()),
+                encoding: Some(UiTransactionEncoding::Base64),
+                accounts: Some(RpcSimulateTransactionAccountsConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    addresses,
+                }),
+                min_context_slot: None,
+                inner_instructions: true,
+            },
+        ))??
+        .value;
 
-        Some(TransactionReturnData { program_id, data })
-    } else {
-        None
-    };
+    if result.units_consumed.unwrap() == 0 {
+        return Err(result.err.unwrap().into());
+    }
 
-    let result = match err {
-        None => Ok(()),
-        Some(err) => Err(err),
-    };
+    let fee = call.get_fee_for_versioned_message(&transaction.message)?;
 
-    ExecutionOutput {
-        transaction,
-        result,
-        logs,
-        compute_units_consumed,
-        return_data,
-        fee,
-    }
+    convert_simulated(call, transaction, sanitized_transaction, result, fee)
 }
 
 fn convert_simulated<C: GetMultipleAccounts>(
@@ -318,9 +398,16 @@ fn convert_simulated<C: GetMultipleAccounts>(
         accounts: ui_accounts_opt,
         units_consumed,
         return_data: ui_return_data_opt,
-        inner_instructions: _,
+        inner_instructions: ui_inner_instructions_opt,
     } = result;
 
+    let inner_instructions = ui_inner_instructions_opt.and_then(|ui_inner_instructions| {
+        decode_ui_inner_instructions(
+            ui_inner_instructions,
+            sanitized_transaction.message().instructions().len(),
+        )
+    });
+
     let ui_accounts = ui_accounts_opt.unwrap();
 
     let account_keys: Vec<_> = sanitized_transaction
@@ -398,24 +485,289 @@ fn convert_simulated<C: GetMultipleAccounts>(
         compute_units_consumed: units_consumed.unwrap(),
         return_data,
         fee,
+        inner_instructions,
         post_accounts,
     })
 }
 
 trait RpcClientExt {
-    fn get_fee_for_versioned_message(&self, message: &VersionedMessage) -> RpcClientResult<u64>;
+    fn call(&self, options: &CallOptions) -> RpcCall;
 }
 
 impl RpcClientExt for RpcClient {
-    fn get_fee_for_versioned_message(&self, message: &VersionedMessage) -> RpcClientResult<u64> {
+    fn call(&self, options: &CallOptions) -> RpcCall {
+        RpcCall {
+            client: self,
+            deadline: options.timeout.map(|timeout| Instant::now() + timeout),
+        }
+    }
+}
+
+// the requests of a call go through the inner client, keeping the sender and the config of the
+// caller, and the deadline bounds all of them, retries included
+struct RpcCall<'a> {
+    client: &'a RpcClient,
+    deadline: Option<Instant>,
+}
+
+impl<'a> RpcCall<'a> {
+    fn inner(&self) -> &'a NonblockingRpcClient {
+        self.client.get_inner_client()
+    }
+
+    // as in the sync client, a call from within a runtime hands the worker over first
+    fn invoke<F: Future>(&self, request: F) -> ClientResult<F::Output> {
+        let runtime = self.client.runtime();
+        tokio::task::block_in_place(|| match self.deadline {
+            Some(deadline) => runtime
+                .block_on(tokio::time::timeout_at(deadline.into(), request))
+                .map_err(|_| ClientError::Timeout("waiting for the rpc call")),
+            None => Ok(runtime.block_on(request)),
+        })
+    }
+
+    fn sleep(&self, duration: Duration) -> ClientResult<()> {
+        if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() + duration >= deadline)
+        {
+            return Err(ClientError::Timeout("waiting for the rpc call"));
+        }
+        thread::sleep(duration);
+        Ok(())
+    }
+
+    fn get_fee_for_versioned_message(&self, message: &VersionedMessage) -> ClientResult<u64> {
         let serialized_encoded = serialize_and_encode(message, UiTransactionEncoding::Base64)?;
-        let result = self.send::<RpcResponse<Option<u64>>>(
+        let result = self.invoke(self.inner().send::<RpcResponse<Option<u64>>>(
             RpcRequest::GetFeeForMessage,
-            serde_json::json!([serialized_encoded, self.commitment()]),
+            serde_json::json!([serialized_encoded, self.client.commitment()]),
+        ))??;
+        result.value.ok_or_else(|| {
+            RpcClientError::from(RpcClientErrorKind::Custom("Invalid blockhash".to_string())).into()
+        })
+    }
+}
+
+impl Client for RpcCall<'_> {}
+
+impl GetAccount for RpcCall<'_> {
+    fn get_account(&self, pubkey: &Pubkey) -> ClientResult<Option<Account>> {
+        let response = self.invoke(
+            self.inner()
+                .get_account_with_commitment(pubkey, self.client.commitment()),
+        )??;
+        Ok(response.value)
+    }
+}
+
+impl GetProgramAccounts for RpcCall<'_> {
+    fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        let mut program_accounts =
+            self.invoke(self.inner().get_program_accounts_with_config(
+                program_id,
+                RpcProgramAccountsConfig {
+                    filters,
+                    account_config: RpcAccountInfoConfig {
+                        encoding: Some(UiAccountEncoding::Base64),
+                        data_slice: None,
+                        commitment: Some(self.client.commitment()),
+                        min_context_slot: None,
+                    },
+                    with_context: None,
+                },
+            ))??;
+
+        // returned accounts are not sorted if the underlying bank enables indexing
+        program_accounts.sort_by_key(|(key, _)| *key);
+
+        Ok(program_accounts)
+    }
+
+    fn get_program_account_keys(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+    ) -> ClientResult<Vec<Pubkey>> {
+        let data_slice = DataSlice {
+            offset: 0,
+            length: 0,
+        };
+        let program_accounts = self.get_program_accounts_sliced(program_id, filters, data_slice)?;
+        Ok(program_accounts.into_iter().map(|(key, _)| key).collect())
+    }
+
+    fn get_program_accounts_sliced(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+        data_slice: DataSlice,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        let mut program_accounts =
+            self.invoke(self.inner().get_program_accounts_with_config(
+                program_id,
+                RpcProgramAccountsConfig {
+                    filters,
+                    account_config: RpcAccountInfoConfig {
+                        encoding: Some(UiAccountEncoding::Base64),
+                        data_slice: Some(data_slice),
+                        commitment: Some(self.client.commitment()),
+                        min_context_slot: None,
+                    },
+                    with_context: None,
+                },
+            ))??;
+
+        // returned accounts are not sorted if the underlying bank enables indexing
+        program_accounts.sort_by_key(|(key, _)| *key);
+
+        Ok(program_accounts)
+    }
+}
+
+impl GetMultipleAccounts for RpcCall<'_> {
+    fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> ClientResult<Vec<Option<Account>>> {
+        let accounts = self
+            .invoke(
+                self.inner()
+                    .get_multiple_accounts_with_commitment(pubkeys, self.client.commitment()),
+            )??
+            .value;
+        assert_eq!(accounts.len(), pubkeys.len());
+        Ok(accounts)
+    }
+}
+
+impl GetLatestBlockhash for RpcCall<'_> {
+    fn get_latest_blockhash(&self) -> ClientResult<Hash> {
+        let (blockhash, _) = self.get_latest_blockhash_with_last_valid_block_height()?;
+        Ok(blockhash)
+    }
+}
+
+impl GetLatestBlockhashWithExpiry for RpcCall<'_> {
+    fn get_latest_blockhash_with_last_valid_block_height(&self) -> ClientResult<(Hash, u64)> {
+        let latest = self.invoke(
+            self.inner()
+                .get_latest_blockhash_with_commitment(self.client.commitment()),
+        )??;
+        Ok(latest)
+    }
+}
+
+impl GetTransaction for RpcCall<'_> {
+    fn get_transaction(
+        &self,
+        signature: &Signature,
+    ) -> ClientResult<Option<EncodedConfirmedTransactionWithStatusMeta>> {
+        let confirmed: Option<EncodedConfirmedTransactionWithStatusMeta> =
+            self.invoke(self.inner().send(
+                RpcRequest::GetTransaction,
+                serde_json::json!([
+                    signature.to_string(),
+                    RpcTransactionConfig {
+                        encoding: Some(UiTransactionEncoding::Base64),
+                        commitment: Some(CommitmentConfig::confirmed()),
+                        max_supported_transaction_version: Some(0),
+                    }
+                ]),
+            ))??;
+        Ok(confirmed)
+    }
+}
+
+impl ProcessTransaction<Signature> for RpcCall<'_> {
+    fn process_transaction(&self, transaction: VersionedTransaction) -> ClientResult<Signature> {
+        let signature = transaction.signatures[0];
+
+        let result = self.invoke(self.inner().send_transaction_with_config(
+            &transaction,
+            RpcSendTransactionConfig {
+                skip_preflight: false,
+                preflight_commitment: Some(CommitmentLevel::Processed),
+                encoding: None,
+                max_retries: None,
+                min_context_slot: None,
+            },
+        ))?;
+
+        if let Err(RpcClientError {
+            kind:
+                RpcClientErrorKind::RpcError(RpcError::RpcResponseError {
+                    data: RpcResponseErrorData::SendTransactionPreflightFailure(tx_result),
+                    ..
+                }),
+            ..
+        }) = result
+        {
+            if tx_result.units_consumed.unwrap() == 0 {
+                return Err(tx_result.err.unwrap().into());
+            }
+        }
+
+        let result = self.invoke(
+            self.inner()
+                .send_and_confirm_transaction_with_spinner_and_config(
+                    &transaction,
+                    CommitmentConfig::confirmed(),
+                    RpcSendTransactionConfig {
+                        skip_preflight: true,
+                        preflight_commitment: None,
+                        encoding: None,
+                        max_retries: None,
+                        min_context_slot: None,
+                    },
+                ),
         )?;
-        result
-            .value
-            .ok_or_else(|| RpcClientErrorKind::Custom("Invalid blockhash".to_string()).into())
+
+        let error = match result {
+            // confirmed successfully
+            Ok(confirmed_signature) => {
+                assert_eq!(confirmed_signature, signature);
+                return Ok(signature);
+            }
+            Err(error) => error,
+        };
+
+        match &error.kind {
+            // confirmed but failed
+            RpcClientErrorKind::TransactionError(_) => Ok(signature),
+            _ => Err(error.into()),
+        }
+    }
+}
+
+impl ProcessTransaction<ExecutionOutput> for RpcCall<'_> {
+    fn process_transaction(
+        &self,
+        transaction: VersionedTransaction,
+    ) -> ClientResult<ExecutionOutput> {
+        const MAX_RETRIES: usize = 10;
+        const RETRY_INTERVAL: Duration = Duration::from_secs(1);
+
+        let signature = ProcessTransaction::<Signature>::process_transaction(self, transaction)?;
+
+        let mut num_retries = 0;
+
+        loop {
+            let result = self.fetch_execution_output(&signature);
+
+            match result {
+                Ok(Some(output)) => break Ok(output),
+                Ok(None) if num_retries >= MAX_RETRIES => {
+                    break Err(ClientError::TransactionNotFound(signature))
+                }
+                Err(err) if num_retries >= MAX_RETRIES => break Err(err),
+                _ => {
+                    num_retries += 1;
+                    self.sleep(RETRY_INTERVAL)?;
+                }
+            }
+        }
     }
 }
 
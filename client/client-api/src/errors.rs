@@ -5,6 +5,9 @@ use thiserror::Error;
 use solana_rpc_client_api::client_error::ErrorKind as RpcClientErrorKind;
 use solana_rpc_client_api::request::RpcError;
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+
+use crate::execution::ExecutionEffect;
 
 pub use solana_banks_client::BanksClientError;
 pub use solana_rpc_client_api::client_error::Error as RpcClientError;
@@ -23,6 +26,12 @@ pub enum ClientError {
     AccountDidNotDeserialize(Pubkey),
     #[error("Failed to serialize the account {0}")]
     AccountDidNotSerialize(Pubkey),
+    #[error("A transaction {0} was not found")]
+    TransactionNotFound(Signature),
+    #[error("Failed to decode the transaction {0}")]
+    TransactionDidNotDecode(Signature),
+    #[error("An instruction at index {0} does not fit into a single transaction")]
+    InstructionDidNotFit(usize),
     #[error(transparent)]
     CompileError(#[from] CompileError),
     #[error(transparent)]
@@ -31,6 +40,10 @@ pub enum ClientError {
     AddressLookupError(#[from] AddressLookupError),
     #[error(transparent)]
     TransactionError(#[from] TransactionError),
+    #[error("A simulated transaction was rejected")]
+    SimulationRejected(Box<ExecutionEffect>),
+    #[error("Timed out {0}")]
+    Timeout(&'static str),
     #[error(transparent)]
     ClientSpecific(#[from] ClientSpecificError),
     #[error("domain specific error: {0}")]
@@ -2,6 +2,7 @@ use std::collections::{btree_map, BTreeMap};
 use thiserror::Error;
 
 use solana_sdk::account::Account;
+use solana_sdk::inner_instruction::InnerInstructionsList;
 use solana_sdk::instruction::InstructionError;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Signature;
@@ -20,6 +21,8 @@ pub struct ExecutionOutput {
     pub compute_units_consumed: u64,
     pub return_data: Option<TransactionReturnData>,
     pub fee: u64,
+    // `None` if the backend does not record them
+    pub inner_instructions: Option<InnerInstructionsList>,
 }
 
 impl ExecutionOutput {
@@ -45,6 +48,7 @@ pub struct ExecutionEffect {
     pub compute_units_consumed: u64,
     pub return_data: Option<TransactionReturnData>,
     pub fee: u64,
+    pub inner_instructions: Option<InnerInstructionsList>,
     pub post_accounts: PostAccounts,
 }
 
@@ -87,6 +91,7 @@ impl From<ExecutionEffect> for ExecutionOutput {
             compute_units_consumed,
             return_data,
             fee,
+            inner_instructions,
             ..
         } = execution;
 
@@ -97,6 +102,7 @@ impl From<ExecutionEffect> for ExecutionOutput {
             compute_units_consumed,
             return_data,
             fee,
+            inner_instructions,
         }
     }
 }
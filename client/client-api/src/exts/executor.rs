@@ -1,19 +1,33 @@
 use std::collections::BTreeSet;
+use std::mem;
+use std::thread;
+use std::time::{Duration, Instant};
 
+use solana_program_runtime::compute_budget_processor::MAX_COMPUTE_UNIT_LIMIT;
 use solana_sdk::address_lookup_table_account::AddressLookupTableAccount;
-use solana_sdk::instruction::Instruction;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::{Instruction, InstructionError};
 use solana_sdk::message::v0::Message;
 use solana_sdk::message::VersionedMessage;
+use solana_sdk::packet::PACKET_DATA_SIZE;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Signature;
 use solana_sdk::signer::SignerError;
 use solana_sdk::signers::Signers;
-use solana_sdk::transaction::VersionedTransaction;
+use solana_sdk::transaction::{TransactionError, VersionedTransaction};
 
-use crate::base::executor::{ProcessTransaction, SimulateTransaction};
-use crate::base::getter::GetLatestBlockhash;
+use crate::base::executor::{ProcessTransaction, SendTransaction, SimulateTransaction};
+use crate::base::getter::{
+    GetBlockHeight, GetLatestBlockhash, GetLatestBlockhashWithExpiry, GetSignatureStatuses,
+    GetTransaction,
+};
 use crate::client::Client;
-use crate::errors::ClientResult;
+use crate::errors::{ClientError, ClientResult};
+use crate::execution::{ExecutionEffect, ExecutionOutput};
+use crate::exts::getter::GetTransactionExt;
+use crate::exts::memo::MemoInstruction;
 
 pub trait CompileTransaction: Client + GetLatestBlockhash {
     fn compile_transaction<S>(
@@ -26,6 +40,21 @@ pub trait CompileTransaction: Client + GetLatestBlockhash {
     where
         S: Signers + ?Sized,
     {
+        let message = self.compile_message(instructions, payer, address_lookup_table_accounts)?;
+
+        let signers = PrimeSigners::new(signers)?;
+
+        let transaction = VersionedTransaction::try_new(message, &signers)?;
+
+        Ok(transaction)
+    }
+
+    fn compile_message(
+        &self,
+        instructions: &[Instruction],
+        payer: &Pubkey,
+        address_lookup_table_accounts: &[AddressLookupTableAccount],
+    ) -> ClientResult<VersionedMessage> {
         let recent_blockhash = self.get_latest_blockhash()?;
 
         let message = Message::try_compile(
@@ -35,11 +64,23 @@ pub trait CompileTransaction: Client + GetLatestBlockhash {
             recent_blockhash,
         )?;
 
-        let signers = PrimeSigners::new(signers)?;
+        Ok(VersionedMessage::V0(message))
+    }
+
+    fn compile_unsigned_transaction(
+        &self,
+        instructions: &[Instruction],
+        payer: &Pubkey,
+        address_lookup_table_accounts: &[AddressLookupTableAccount],
+    ) -> ClientResult<VersionedTransaction> {
+        let message = self.compile_message(instructions, payer, address_lookup_table_accounts)?;
 
-        let transaction = VersionedTransaction::try_new(VersionedMessage::V0(message), &signers)?;
+        let num_required_signatures = usize::from(message.header().num_required_signatures);
 
-        Ok(transaction)
+        Ok(VersionedTransaction {
+            signatures: vec![Signature::default(); num_required_signatures],
+            message,
+        })
     }
 }
 
@@ -62,6 +103,25 @@ pub trait CompilingProcessTransaction<T>:
             self.compile_transaction(instructions, payer, signers, address_lookup_table_accounts)?;
         self.process_transaction(transaction)
     }
+
+    fn compiling_process_transaction_with_memo<S>(
+        &self,
+        instructions: &[Instruction],
+        payer: &Pubkey,
+        signers: &S,
+        address_lookup_table_accounts: &[AddressLookupTableAccount],
+        memo: &str,
+    ) -> ClientResult<T>
+    where
+        S: Signers + ?Sized,
+    {
+        self.compiling_process_transaction(
+            &with_memo(self, instructions, memo),
+            payer,
+            signers,
+            address_lookup_table_accounts,
+        )
+    }
 }
 
 impl<T, C: ?Sized + Client + GetLatestBlockhash + ProcessTransaction<T>>
@@ -86,6 +146,25 @@ pub trait CompilingSimulateTransaction<T>:
             self.compile_transaction(instructions, payer, signers, address_lookup_table_accounts)?;
         self.simulate_transaction(transaction)
     }
+
+    fn compiling_simulate_transaction_with_memo<S>(
+        &self,
+        instructions: &[Instruction],
+        payer: &Pubkey,
+        signers: &S,
+        address_lookup_table_accounts: &[AddressLookupTableAccount],
+        memo: &str,
+    ) -> ClientResult<T>
+    where
+        S: Signers + ?Sized,
+    {
+        self.compiling_simulate_transaction(
+            &with_memo(self, instructions, memo),
+            payer,
+            signers,
+            address_lookup_table_accounts,
+        )
+    }
 }
 
 impl<T, C: ?Sized + Client + GetLatestBlockhash + SimulateTransaction<T>>
@@ -93,13 +172,540 @@ impl<T, C: ?Sized + Client + GetLatestBlockhash + SimulateTransaction<T>>
 {
 }
 
-struct PrimeSigners<'a, S: Signers + ?Sized> {
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComputeBudgetConfig {
+    pub unit_limit_margin: f64,
+    pub unit_price: Option<u64>,
+}
+
+impl ComputeBudgetConfig {
+    pub const DEFAULT_UNIT_LIMIT_MARGIN: f64 = 1.1;
+    pub const MIN_UNIT_LIMIT: u32 = 10_000;
+
+    pub fn new(unit_limit_margin: f64, unit_price: Option<u64>) -> Self {
+        Self {
+            unit_limit_margin,
+            unit_price,
+        }
+    }
+
+    pub fn with_unit_price(unit_price: u64) -> Self {
+        Self {
+            unit_price: Some(unit_price),
+            ..Default::default()
+        }
+    }
+
+    fn unit_limit(&self, units_consumed: u64) -> u32 {
+        let unit_limit = (units_consumed as f64 * self.unit_limit_margin).ceil() as u64;
+        unit_limit.clamp(
+            u64::from(Self::MIN_UNIT_LIMIT),
+            u64::from(MAX_COMPUTE_UNIT_LIMIT),
+        ) as u32
+    }
+
+    fn prepend_instructions(
+        &self,
+        unit_limit: u32,
+        instructions: &[Instruction],
+    ) -> Vec<Instruction> {
+        let mut budgeted = Vec::with_capacity(instructions.len() + 2);
+        budgeted.push(ComputeBudgetInstruction::set_compute_unit_limit(unit_limit));
+        if let Some(unit_price) = self.unit_price {
+            budgeted.push(ComputeBudgetInstruction::set_compute_unit_price(unit_price));
+        }
+        budgeted.extend_from_slice(instructions);
+        budgeted
+    }
+}
+
+impl Default for ComputeBudgetConfig {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_UNIT_LIMIT_MARGIN, None)
+    }
+}
+
+pub trait CompilingProcessTransactionWithBudget<T>:
+    Client + GetLatestBlockhash + SimulateTransaction<ExecutionOutput> + ProcessTransaction<T>
+{
+    fn compiling_process_transaction_with_budget<S>(
+        &self,
+        instructions: &[Instruction],
+        payer: &Pubkey,
+        signers: &S,
+        address_lookup_table_accounts: &[AddressLookupTableAccount],
+        budget: &ComputeBudgetConfig,
+    ) -> ClientResult<T>
+    where
+        S: Signers + ?Sized,
+    {
+        // simulate with the same instruction layout so the budget instructions are accounted for
+        let simulated: ExecutionOutput = self.compiling_simulate_transaction(
+            &budget.prepend_instructions(MAX_COMPUTE_UNIT_LIMIT, instructions),
+            payer,
+            signers,
+            address_lookup_table_accounts,
+        )?;
+
+        if let Err(err) = simulated.result {
+            return Err(err.into());
+        }
+
+        let unit_limit = budget.unit_limit(simulated.compute_units_consumed);
+
+        self.compiling_process_transaction(
+            &budget.prepend_instructions(unit_limit, instructions),
+            payer,
+            signers,
+            address_lookup_table_accounts,
+        )
+    }
+}
+
+impl<T, C> CompilingProcessTransactionWithBudget<T> for C where
+    C: ?Sized
+        + Client
+        + GetLatestBlockhash
+        + SimulateTransaction<ExecutionOutput>
+        + ProcessTransaction<T>
+{
+}
+
+pub trait SimulateThenProcessTransaction<T>:
+    Client + SimulateTransaction<ExecutionEffect> + ProcessTransaction<T>
+{
+    fn simulate_then_process<P>(
+        &self,
+        transaction: VersionedTransaction,
+        predicate: P,
+    ) -> ClientResult<T>
+    where
+        P: FnOnce(&ExecutionEffect) -> bool,
+    {
+        let effect: ExecutionEffect = self.simulate_transaction(transaction.clone())?;
+
+        if let Err(err) = &effect.result {
+            return Err(err.clone().into());
+        }
+
+        if !predicate(&effect) {
+            return Err(ClientError::SimulationRejected(Box::new(effect)));
+        }
+
+        self.process_transaction(transaction)
+    }
+
+    fn compiling_simulate_then_process<S, P>(
+        &self,
+        instructions: &[Instruction],
+        payer: &Pubkey,
+        signers: &S,
+        address_lookup_table_accounts: &[AddressLookupTableAccount],
+        predicate: P,
+    ) -> ClientResult<T>
+    where
+        Self: GetLatestBlockhash,
+        S: Signers + ?Sized,
+        P: FnOnce(&ExecutionEffect) -> bool,
+    {
+        let transaction =
+            self.compile_transaction(instructions, payer, signers, address_lookup_table_accounts)?;
+        self.simulate_then_process(transaction, predicate)
+    }
+}
+
+impl<T, C> SimulateThenProcessTransaction<T> for C where
+    C: ?Sized + Client + SimulateTransaction<ExecutionEffect> + ProcessTransaction<T>
+{
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResendConfig {
+    pub resend_interval: Duration,
+    pub poll_interval: Duration,
+    pub commitment: CommitmentConfig,
+    pub max_recompiles: usize,
+}
+
+impl ResendConfig {
+    pub const DEFAULT_RESEND_INTERVAL: Duration = Duration::from_secs(2);
+    pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+    pub fn with_max_recompiles(max_recompiles: usize) -> Self {
+        Self {
+            max_recompiles,
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for ResendConfig {
+    fn default() -> Self {
+        Self {
+            resend_interval: Self::DEFAULT_RESEND_INTERVAL,
+            poll_interval: Self::DEFAULT_POLL_INTERVAL,
+            commitment: CommitmentConfig::confirmed(),
+            max_recompiles: 0,
+        }
+    }
+}
+
+pub trait ResendTransaction:
+    Client + SendTransaction + GetSignatureStatuses + GetBlockHeight
+{
+    /// `last_valid_block_height` goes with the blockhash of `transaction`.
+    fn send_with_resend(
+        &self,
+        transaction: VersionedTransaction,
+        last_valid_block_height: u64,
+        config: &ResendConfig,
+    ) -> ClientResult<Signature> {
+        resend_until_expired(self, &transaction, last_valid_block_height, config)?
+            .ok_or_else(|| TransactionError::BlockhashNotFound.into())
+    }
+
+    fn process_with_resend<S>(
+        &self,
+        instructions: &[Instruction],
+        payer: &Pubkey,
+        signers: &S,
+        address_lookup_table_accounts: &[AddressLookupTableAccount],
+        config: &ResendConfig,
+    ) -> ClientResult<Signature>
+    where
+        Self: GetLatestBlockhashWithExpiry,
+        S: Signers + ?Sized,
+    {
+        for _ in 0..=config.max_recompiles {
+            let (recent_blockhash, last_valid_block_height) =
+                self.get_latest_blockhash_with_last_valid_block_height()?;
+            let message = Message::try_compile(
+                payer,
+                instructions,
+                address_lookup_table_accounts,
+                recent_blockhash,
+            )?;
+            let transaction = VersionedTransaction::try_new(
+                VersionedMessage::V0(message),
+                &PrimeSigners::new(signers)?,
+            )?;
+
+            if let Some(signature) =
+                resend_until_expired(self, &transaction, last_valid_block_height, config)?
+            {
+                return Ok(signature);
+            }
+        }
+
+        Err(TransactionError::BlockhashNotFound.into())
+    }
+}
+
+impl<C> ResendTransaction for C where
+    C: ?Sized + Client + SendTransaction + GetSignatureStatuses + GetBlockHeight
+{
+}
+
+pub trait IdempotentProcessTransaction: Client + GetSignatureStatuses {
+    fn is_transaction_landed(&self, signature: &Signature) -> ClientResult<bool> {
+        let status = self.get_signature_statuses(&[*signature])?.pop().flatten();
+        Ok(status.is_some())
+    }
+
+    fn process_transaction_once(&self, transaction: VersionedTransaction) -> ClientResult<Signature>
+    where
+        Self: ProcessTransaction<Signature>,
+    {
+        let signature = transaction.signatures[0];
+
+        if self.is_transaction_landed(&signature)? {
+            return Ok(signature);
+        }
+
+        self.process_transaction(transaction)
+    }
+
+    fn process_transaction_once_with_output(
+        &self,
+        transaction: VersionedTransaction,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetTransaction + ProcessTransaction<ExecutionOutput>,
+    {
+        let signature = transaction.signatures[0];
+
+        if self.is_transaction_landed(&signature)? {
+            return self.try_fetch_execution_output(&signature);
+        }
+
+        self.process_transaction(transaction)
+    }
+}
+
+impl<C: ?Sized + Client + GetSignatureStatuses> IdempotentProcessTransaction for C {}
+
+fn resend_until_expired<C>(
+    client: &C,
+    transaction: &VersionedTransaction,
+    last_valid_block_height: u64,
+    config: &ResendConfig,
+) -> ClientResult<Option<Signature>>
+where
+    C: ?Sized + SendTransaction + GetSignatureStatuses + GetBlockHeight,
+{
+    let signature = transaction.signatures[0];
+    let mut resend_at = Instant::now();
+
+    loop {
+        let status = client.get_signature_statuses(&[signature])?.pop().flatten();
+
+        match status {
+            Some(status) if status.satisfies_commitment(config.commitment) => {
+                return Ok(Some(signature));
+            }
+            // landed but not yet at the requested commitment
+            Some(_) => {}
+            None => {
+                if client.get_block_height()? > last_valid_block_height {
+                    return Ok(None);
+                }
+
+                if Instant::now() >= resend_at {
+                    client.send_transaction(transaction.clone())?;
+                    resend_at = Instant::now() + config.resend_interval;
+                    continue;
+                }
+            }
+        }
+
+        thread::sleep(config.poll_interval);
+    }
+}
+
+pub trait CompileTransactionBatch: Client + GetLatestBlockhash {
+    fn compile_transaction_batch<S>(
+        &self,
+        instructions: &[Instruction],
+        payer: &Pubkey,
+        signers: &S,
+        address_lookup_table_accounts: &[AddressLookupTableAccount],
+    ) -> ClientResult<Vec<VersionedTransaction>>
+    where
+        S: Signers + ?Sized,
+    {
+        let recent_blockhash = self.get_latest_blockhash()?;
+
+        let batches = pack_instructions(instructions, |candidate| {
+            Ok(try_compile_within_size(
+                candidate,
+                payer,
+                address_lookup_table_accounts,
+                recent_blockhash,
+            )
+            .is_some())
+        })?;
+
+        batches
+            .iter()
+            .map(|batch| {
+                let message = Message::try_compile(
+                    payer,
+                    batch,
+                    address_lookup_table_accounts,
+                    recent_blockhash,
+                )?;
+                sign_message(VersionedMessage::V0(message), signers)
+            })
+            .collect()
+    }
+
+    fn compile_transaction_batch_with_budget<S>(
+        &self,
+        instructions: &[Instruction],
+        payer: &Pubkey,
+        signers: &S,
+        address_lookup_table_accounts: &[AddressLookupTableAccount],
+        max_compute_units: u32,
+    ) -> ClientResult<Vec<VersionedTransaction>>
+    where
+        Self: SimulateTransaction<ExecutionOutput>,
+        S: Signers + ?Sized,
+    {
+        let recent_blockhash = self.get_latest_blockhash()?;
+
+        let with_budget = |unit_limit: u32, candidate: &[Instruction]| {
+            let mut budgeted = Vec::with_capacity(candidate.len() + 1);
+            budgeted.push(ComputeBudgetInstruction::set_compute_unit_limit(unit_limit));
+            budgeted.extend_from_slice(candidate);
+            budgeted
+        };
+
+        let within_size = |unit_limit: u32, candidate: &[Instruction]| {
+            try_compile_within_size(
+                &with_budget(unit_limit, candidate),
+                payer,
+                address_lookup_table_accounts,
+                recent_blockhash,
+            )
+        };
+
+        // each instruction is simulated once on its own under the maximum limit, as a run under
+        // `max_compute_units` would stop at it, and a batch takes the sum of its instructions
+        let units_of = |instruction: &Instruction| -> ClientResult<Option<u64>> {
+            let candidate = std::slice::from_ref(instruction);
+            let Some(message) = within_size(MAX_COMPUTE_UNIT_LIMIT, candidate) else {
+                return Ok(None);
+            };
+
+            let simulated: ExecutionOutput =
+                self.simulate_transaction(sign_message(message, signers)?)?;
+
+            // the instruction may depend on state created by the preceding ones, so any other
+            // failure is ignored and only the consumed units are taken into account
+            if let Err(TransactionError::InstructionError(
+                _,
+                InstructionError::ComputationalBudgetExceeded,
+            )) = simulated.result
+            {
+                return Ok(None);
+            }
+
+            Ok(Some(simulated.compute_units_consumed)
+                .filter(|&units| units <= u64::from(max_compute_units)))
+        };
+
+        let mut batches = Vec::new();
+        let mut current: Vec<Instruction> = Vec::new();
+        let mut current_units = 0;
+
+        for (index, instruction) in instructions.iter().enumerate() {
+            let Some(units) = units_of(instruction)? else {
+                return Err(ClientError::InstructionDidNotFit(index));
+            };
+
+            current.push(instruction.clone());
+            if current_units + units <= u64::from(max_compute_units)
+                && within_size(max_compute_units, &current).is_some()
+            {
+                current_units += units;
+                continue;
+            }
+
+            current.pop();
+            batches.push(mem::replace(&mut current, vec![instruction.clone()]));
+            current_units = units;
+        }
+
+        if !current.is_empty() {
+            batches.push(current);
+        }
+
+        batches
+            .iter()
+            .map(|batch| {
+                let message = Message::try_compile(
+                    payer,
+                    &with_budget(max_compute_units, batch),
+                    address_lookup_table_accounts,
+                    recent_blockhash,
+                )?;
+                sign_message(VersionedMessage::V0(message), signers)
+            })
+            .collect()
+    }
+}
+
+impl<C: ?Sized + Client + GetLatestBlockhash> CompileTransactionBatch for C {}
+
+fn with_memo<C>(client: &C, instructions: &[Instruction], memo: &str) -> Vec<Instruction>
+where
+    C: ?Sized + Client,
+{
+    let mut with_memo = Vec::with_capacity(instructions.len() + 1);
+    with_memo.extend_from_slice(instructions);
+    with_memo.push(client.build_memo(memo, &[]));
+    with_memo
+}
+
+fn pack_instructions<F>(
+    instructions: &[Instruction],
+    mut fits: F,
+) -> ClientResult<Vec<Vec<Instruction>>>
+where
+    F: FnMut(&[Instruction]) -> ClientResult<bool>,
+{
+    let mut batches = Vec::new();
+    let mut current = Vec::new();
+
+    for (index, instruction) in instructions.iter().enumerate() {
+        current.push(instruction.clone());
+        if fits(&current)? {
+            continue;
+        }
+
+        current.pop();
+        if current.is_empty() {
+            return Err(ClientError::InstructionDidNotFit(index));
+        }
+        batches.push(mem::take(&mut current));
+
+        current.push(instruction.clone());
+        if !fits(&current)? {
+            return Err(ClientError::InstructionDidNotFit(index));
+        }
+    }
+
+    if !current.is_empty() {
+        batches.push(current);
+    }
+
+    Ok(batches)
+}
+
+fn try_compile_within_size(
+    instructions: &[Instruction],
+    payer: &Pubkey,
+    address_lookup_table_accounts: &[AddressLookupTableAccount],
+    recent_blockhash: Hash,
+) -> Option<VersionedMessage> {
+    let message = Message::try_compile(
+        payer,
+        instructions,
+        address_lookup_table_accounts,
+        recent_blockhash,
+    )
+    .ok()?;
+    let message = VersionedMessage::V0(message);
+
+    let num_required_signatures = usize::from(message.header().num_required_signatures);
+    let placeholder = VersionedTransaction {
+        signatures: vec![Signature::default(); num_required_signatures],
+        message,
+    };
+
+    let size = bincode::serialized_size(&placeholder).ok()?;
+    (size <= PACKET_DATA_SIZE as u64).then_some(placeholder.message)
+}
+
+fn sign_message<S>(message: VersionedMessage, signers: &S) -> ClientResult<VersionedTransaction>
+where
+    S: Signers + ?Sized,
+{
+    let num_required_signatures = usize::from(message.header().num_required_signatures);
+    let required_signers = &message.static_account_keys()[..num_required_signatures];
+
+    let signers = PrimeSigners::new_for(signers, required_signers)?;
+
+    Ok(VersionedTransaction::try_new(message, &signers)?)
+}
+
+pub(crate) struct PrimeSigners<'a, S: Signers + ?Sized> {
     signers: &'a S,
     indexes: Vec<usize>,
 }
 
 impl<'a, S: Signers + ?Sized> PrimeSigners<'a, S> {
-    fn new(signers: &'a S) -> Result<Self, SignerError> {
+    pub(crate) fn new(signers: &'a S) -> Result<Self, SignerError> {
         let signer_keys = signers.try_pubkeys()?;
 
         let mut seen = BTreeSet::new();
@@ -113,6 +719,21 @@ impl<'a, S: Signers + ?Sized> PrimeSigners<'a, S> {
 
         Ok(Self { signers, indexes })
     }
+
+    pub(crate) fn new_for(signers: &'a S, required: &[Pubkey]) -> Result<Self, SignerError> {
+        let signer_keys = signers.try_pubkeys()?;
+
+        let mut seen = BTreeSet::new();
+        let mut indexes = Vec::new();
+        for (i, key) in signer_keys.into_iter().enumerate() {
+            if !required.contains(&key) || !seen.insert(key) {
+                continue;
+            }
+            indexes.push(i);
+        }
+
+        Ok(Self { signers, indexes })
+    }
 }
 
 impl<'a, S: Signers + ?Sized> Signers for PrimeSigners<'a, S> {
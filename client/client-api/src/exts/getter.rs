@@ -1,8 +1,18 @@
+use base64::prelude::{Engine, BASE64_STANDARD};
+
 use solana_sdk::account::Account;
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction_context::TransactionReturnData;
+use solana_transaction_status::{
+    EncodedConfirmedTransactionWithStatusMeta, EncodedTransactionWithStatusMeta,
+    UiTransactionReturnData, UiTransactionStatusMeta,
+};
 
-use crate::base::getter::{GetAccount, GetMultipleAccounts};
+use crate::base::getter::{GetAccount, GetMultipleAccounts, GetTransaction};
 use crate::errors::{ClientError, ClientResult};
+use crate::execution::ExecutionOutput;
+use crate::internals::inner_instructions::decode_ui_inner_instructions;
 
 pub trait GetAccountExt: GetAccount {
     fn try_get_account(&self, pubkey: &Pubkey) -> ClientResult<Account> {
@@ -61,3 +71,99 @@ pub trait GetMultipleAccountsExt: GetMultipleAccounts {
 }
 
 impl<C: ?Sized + GetMultipleAccounts> GetMultipleAccountsExt for C {}
+
+pub trait GetTransactionExt: GetTransaction {
+    fn fetch_execution_output(
+        &self,
+        signature: &Signature,
+    ) -> ClientResult<Option<ExecutionOutput>> {
+        let Some(confirmed) = self.get_transaction(signature)? else {
+            return Ok(None);
+        };
+
+        convert_confirmed(signature, confirmed).map(Some)
+    }
+
+    fn try_fetch_execution_output(&self, signature: &Signature) -> ClientResult<ExecutionOutput> {
+        match self.fetch_execution_output(signature)? {
+            Some(output) => Ok(output),
+            None => Err(ClientError::TransactionNotFound(*signature)),
+        }
+    }
+}
+
+impl<C: ?Sized + GetTransaction> GetTransactionExt for C {}
+
+fn convert_confirmed(
+    signature: &Signature,
+    confirmed: EncodedConfirmedTransactionWithStatusMeta,
+) -> ClientResult<ExecutionOutput> {
+    let EncodedConfirmedTransactionWithStatusMeta {
+        transaction:
+            EncodedTransactionWithStatusMeta {
+                transaction: encoded_transaction,
+                meta: Some(meta),
+                ..
+            },
+        ..
+    } = confirmed
+    else {
+        return Err(ClientError::TransactionDidNotDecode(*signature));
+    };
+
+    let transaction = encoded_transaction
+        .decode()
+        .ok_or(ClientError::TransactionDidNotDecode(*signature))?;
+
+    let UiTransactionStatusMeta {
+        err,
+        fee,
+        log_messages,
+        inner_instructions: ui_inner_instructions_opt,
+        return_data: ui_return_data_opt,
+        compute_units_consumed,
+        ..
+    } = meta;
+
+    let inner_instructions =
+        Option::from(ui_inner_instructions_opt).and_then(|ui_inner_instructions| {
+            decode_ui_inner_instructions(
+                ui_inner_instructions,
+                transaction.message.instructions().len(),
+            )
+        });
+
+    let return_data = match Option::from(ui_return_data_opt) {
+        Some(ui_return_data) => {
+            let UiTransactionReturnData {
+                program_id,
+                data: (ui_data, _),
+            } = ui_return_data;
+
+            let program_id = program_id
+                .parse()
+                .map_err(|_| ClientError::TransactionDidNotDecode(*signature))?;
+            let data = BASE64_STANDARD
+                .decode(ui_data)
+                .map_err(|_| ClientError::TransactionDidNotDecode(*signature))?;
+
+            Some(TransactionReturnData { program_id, data })
+        }
+        None => None,
+    };
+
+    let result = match err {
+        None => Ok(()),
+        Some(err) => Err(err),
+    };
+
+    Ok(ExecutionOutput {
+        transaction,
+        result,
+        logs: Option::from(log_messages).unwrap_or_default(),
+        compute_units_consumed: Option::from(compute_units_consumed).unwrap_or_default(),
+        return_data,
+        fee,
+        inner_instructions,
+    })
+}
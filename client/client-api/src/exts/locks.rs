@@ -0,0 +1,118 @@
+use std::collections::BTreeSet;
+
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::VersionedTransaction;
+
+use crate::base::getter::GetMultipleAccounts;
+use crate::client::Client;
+use crate::errors::ClientResult;
+use crate::internals::sanitize::SanitizeTransaction;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccountLocks {
+    pub writable: BTreeSet<Pubkey>,
+    pub readonly: BTreeSet<Pubkey>,
+}
+
+impl AccountLocks {
+    pub fn conflicting_accounts(&self, other: &Self) -> BTreeSet<Pubkey> {
+        // a write lock conflicts with both read and write locks on the same account
+        self.writable
+            .iter()
+            .filter(|pubkey| other.writable.contains(pubkey) || other.readonly.contains(pubkey))
+            .chain(
+                other
+                    .writable
+                    .iter()
+                    .filter(|pubkey| self.readonly.contains(pubkey)),
+            )
+            .copied()
+            .collect()
+    }
+
+    pub fn conflicts_with(&self, other: &Self) -> bool {
+        !self.conflicting_accounts(other).is_empty()
+    }
+
+    pub fn merge(&mut self, other: &Self) {
+        self.writable.extend(&other.writable);
+        self.readonly.extend(&other.readonly);
+        self.readonly
+            .retain(|pubkey| !self.writable.contains(pubkey));
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WriteConflict {
+    pub first: usize,
+    pub second: usize,
+    pub accounts: BTreeSet<Pubkey>,
+}
+
+pub trait AccountLocksExt: Client + GetMultipleAccounts {
+    fn get_account_locks(&self, transaction: &VersionedTransaction) -> ClientResult<AccountLocks> {
+        let sanitized_transaction = self.sanitize_transaction(transaction.clone())?;
+        let message = sanitized_transaction.message();
+
+        let mut locks = AccountLocks::default();
+        for (index, pubkey) in message.account_keys().iter().enumerate() {
+            if message.is_writable(index) {
+                locks.writable.insert(*pubkey);
+            } else {
+                locks.readonly.insert(*pubkey);
+            }
+        }
+
+        Ok(locks)
+    }
+
+    fn find_write_conflicts(
+        &self,
+        transactions: &[VersionedTransaction],
+    ) -> ClientResult<Vec<WriteConflict>> {
+        let locks = transactions
+            .iter()
+            .map(|transaction| self.get_account_locks(transaction))
+            .collect::<ClientResult<Vec<_>>>()?;
+
+        let mut conflicts = Vec::new();
+        for (first, first_locks) in locks.iter().enumerate() {
+            for (second, second_locks) in locks.iter().enumerate().skip(first + 1) {
+                let accounts = first_locks.conflicting_accounts(second_locks);
+                if !accounts.is_empty() {
+                    conflicts.push(WriteConflict {
+                        first,
+                        second,
+                        accounts,
+                    });
+                }
+            }
+        }
+
+        Ok(conflicts)
+    }
+
+    fn group_non_conflicting(
+        &self,
+        transactions: &[VersionedTransaction],
+    ) -> ClientResult<Vec<Vec<usize>>> {
+        let mut groups: Vec<(AccountLocks, Vec<usize>)> = Vec::new();
+
+        for (index, transaction) in transactions.iter().enumerate() {
+            let locks = self.get_account_locks(transaction)?;
+
+            // keep the relative order of conflicting transactions by only joining the last group
+            match groups.last_mut() {
+                Some((group_locks, indexes)) if !group_locks.conflicts_with(&locks) => {
+                    group_locks.merge(&locks);
+                    indexes.push(index);
+                }
+                _ => groups.push((locks, vec![index])),
+            }
+        }
+
+        Ok(groups.into_iter().map(|(_, indexes)| indexes).collect())
+    }
+}
+
+impl<C: ?Sized + Client + GetMultipleAccounts> AccountLocksExt for C {}
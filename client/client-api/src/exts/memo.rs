@@ -0,0 +1,12 @@
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::client::Client;
+
+pub trait MemoInstruction: Client {
+    fn build_memo(&self, memo: &str, signers: &[Pubkey]) -> Instruction {
+        spl_memo::build_memo(memo.as_bytes(), &signers.iter().collect::<Vec<_>>())
+    }
+}
+
+impl<C: ?Sized + Client> MemoInstruction for C {}
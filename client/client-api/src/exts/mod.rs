@@ -1,2 +1,5 @@
 pub mod executor;
 pub mod getter;
+pub mod locks;
+pub mod memo;
+pub mod signer;
@@ -0,0 +1,337 @@
+use std::env;
+use std::fmt;
+use std::future::Future;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::{mpsc, Arc};
+use tokio::runtime::{Handle, RuntimeFlavor};
+use tokio::task;
+
+use solana_sdk::address_lookup_table_account::AddressLookupTableAccount;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::message::VersionedMessage;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::signer::keypair::read_keypair_file;
+use solana_sdk::signer::{Signer, SignerError};
+use solana_sdk::signers::Signers;
+use solana_sdk::transaction::{TransactionError, VersionedTransaction};
+
+use crate::base::executor::ProcessTransaction;
+use crate::base::getter::GetLatestBlockhash;
+use crate::client::Client;
+use crate::errors::{ClientError, ClientResult};
+use crate::exts::executor::{CompileTransaction, PrimeSigners};
+use crate::internals::runtime::RUNTIME;
+
+pub trait PartialSign {
+    fn required_signers(&self) -> &[Pubkey];
+
+    fn missing_signers(&self) -> Vec<Pubkey>;
+
+    fn is_fully_signed(&self) -> bool {
+        self.missing_signers().is_empty()
+    }
+
+    fn add_signature(&mut self, pubkey: &Pubkey, signature: Signature) -> Result<(), SignerError>;
+
+    fn try_partial_sign<S>(&mut self, signers: &S) -> Result<(), SignerError>
+    where
+        S: Signers + ?Sized;
+
+    fn verify_signatures(&self) -> Result<(), TransactionError>;
+}
+
+impl PartialSign for VersionedTransaction {
+    fn required_signers(&self) -> &[Pubkey] {
+        let num_required_signatures = usize::from(self.message.header().num_required_signatures);
+        &self.message.static_account_keys()[..num_required_signatures]
+    }
+
+    fn missing_signers(&self) -> Vec<Pubkey> {
+        self.required_signers()
+            .iter()
+            .zip(&self.signatures)
+            .filter(|(_, signature)| **signature == Signature::default())
+            .map(|(pubkey, _)| *pubkey)
+            .collect()
+    }
+
+    fn add_signature(&mut self, pubkey: &Pubkey, signature: Signature) -> Result<(), SignerError> {
+        let index = self
+            .required_signers()
+            .iter()
+            .position(|signer| signer == pubkey)
+            .ok_or(SignerError::KeypairPubkeyMismatch)?;
+
+        if !signature.verify(pubkey.as_ref(), &self.message.serialize()) {
+            return Err(TransactionError::SignatureFailure.into());
+        }
+
+        self.signatures[index] = signature;
+
+        Ok(())
+    }
+
+    fn try_partial_sign<S>(&mut self, signers: &S) -> Result<(), SignerError>
+    where
+        S: Signers + ?Sized,
+    {
+        let signers = PrimeSigners::new(signers)?;
+
+        let positions = signers
+            .try_pubkeys()?
+            .iter()
+            .map(|pubkey| {
+                self.required_signers()
+                    .iter()
+                    .position(|signer| signer == pubkey)
+                    .ok_or(SignerError::KeypairPubkeyMismatch)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let signatures = signers.try_sign_message(&self.message.serialize())?;
+
+        for (index, signature) in positions.into_iter().zip(signatures) {
+            self.signatures[index] = signature;
+        }
+
+        Ok(())
+    }
+
+    fn verify_signatures(&self) -> Result<(), TransactionError> {
+        if self
+            .verify_with_results()
+            .into_iter()
+            .all(|verified| verified)
+        {
+            Ok(())
+        } else {
+            Err(TransactionError::SignatureFailure)
+        }
+    }
+}
+
+pub trait ProcessPresignedTransaction<T>: Client + ProcessTransaction<T> {
+    fn process_presigned_transaction(&self, transaction: VersionedTransaction) -> ClientResult<T> {
+        if !transaction.is_fully_signed() {
+            return Err(ClientError::SigningError(SignerError::NotEnoughSigners));
+        }
+
+        transaction.verify_signatures()?;
+
+        self.process_transaction(transaction)
+    }
+}
+
+impl<T, C: ?Sized + Client + ProcessTransaction<T>> ProcessPresignedTransaction<T> for C {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OfflineMessage {
+    message: VersionedMessage,
+}
+
+impl OfflineMessage {
+    pub fn new(message: VersionedMessage) -> Self {
+        Self { message }
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SignerError> {
+        let message = bincode::deserialize(bytes)
+            .map_err(|e| SignerError::InvalidInput(format!("invalid message: {e}")))?;
+        Ok(Self { message })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.message.serialize()
+    }
+
+    pub fn message(&self) -> &VersionedMessage {
+        &self.message
+    }
+
+    pub fn into_message(self) -> VersionedMessage {
+        self.message
+    }
+
+    pub fn required_signers(&self) -> &[Pubkey] {
+        let num_required_signatures = usize::from(self.message.header().num_required_signatures);
+        &self.message.static_account_keys()[..num_required_signatures]
+    }
+
+    pub fn into_unsigned_transaction(self) -> VersionedTransaction {
+        let num_required_signatures = usize::from(self.message.header().num_required_signatures);
+        VersionedTransaction {
+            signatures: vec![Signature::default(); num_required_signatures],
+            message: self.message,
+        }
+    }
+
+    pub fn assemble(
+        self,
+        signatures: &[(Pubkey, Signature)],
+    ) -> Result<VersionedTransaction, SignerError> {
+        let mut transaction = self.into_unsigned_transaction();
+        for (pubkey, signature) in signatures {
+            transaction.add_signature(pubkey, *signature)?;
+        }
+        Ok(transaction)
+    }
+}
+
+impl From<VersionedMessage> for OfflineMessage {
+    fn from(message: VersionedMessage) -> Self {
+        Self::new(message)
+    }
+}
+
+pub trait CompileOfflineMessage: Client + GetLatestBlockhash {
+    fn compile_offline_message(
+        &self,
+        instructions: &[Instruction],
+        payer: &Pubkey,
+        address_lookup_table_accounts: &[AddressLookupTableAccount],
+    ) -> ClientResult<OfflineMessage> {
+        let message = self.compile_message(instructions, payer, address_lookup_table_accounts)?;
+        Ok(OfflineMessage::new(message))
+    }
+}
+
+impl<C: ?Sized + Client + GetLatestBlockhash> CompileOfflineMessage for C {}
+
+pub trait FeePayer {
+    fn payer(&self) -> &dyn Signer;
+
+    fn payer_pubkey(&self) -> Pubkey {
+        self.payer().pubkey()
+    }
+}
+
+impl<T: Signer> FeePayer for T {
+    fn payer(&self) -> &dyn Signer {
+        self
+    }
+}
+
+pub struct SignerContext {
+    payer: Box<dyn Signer>,
+}
+
+impl SignerContext {
+    pub fn new(payer: impl Into<Box<dyn Signer>>) -> Self {
+        Self {
+            payer: payer.into(),
+        }
+    }
+
+    pub fn from_keypair_file(path: impl AsRef<Path>) -> Result<Self, SignerError> {
+        let keypair = read_keypair_file(path.as_ref()).map_err(|e| {
+            SignerError::Custom(format!(
+                "failed to read keypair {}: {e}",
+                path.as_ref().display()
+            ))
+        })?;
+        Ok(Self::new(keypair))
+    }
+
+    pub fn from_env(key: &str) -> Result<Self, SignerError> {
+        let path = env::var(key)
+            .map_err(|e| SignerError::Custom(format!("failed to read env {key}: {e}")))?;
+        Self::from_keypair_file(path)
+    }
+}
+
+impl FeePayer for SignerContext {
+    fn payer(&self) -> &dyn Signer {
+        &*self.payer
+    }
+}
+
+pub type RemoteSignFuture = Pin<Box<dyn Future<Output = Result<Signature, SignerError>> + Send>>;
+
+type RemoteSignFn = dyn Fn(Vec<u8>) -> RemoteSignFuture + Send + Sync;
+
+pub struct RemoteSigner {
+    pubkey: Pubkey,
+    sign: Arc<RemoteSignFn>,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl RemoteSigner {
+    pub fn new<F, Fut>(pubkey: Pubkey, sign: F) -> Self
+    where
+        F: Fn(Vec<u8>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Signature, SignerError>> + Send + 'static,
+    {
+        Self::with_runtime(pubkey, sign, RUNTIME.clone())
+    }
+
+    pub fn with_runtime<F, Fut>(
+        pubkey: Pubkey,
+        sign: F,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self
+    where
+        F: Fn(Vec<u8>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Signature, SignerError>> + Send + 'static,
+    {
+        Self {
+            pubkey,
+            sign: Arc::new(move |message| -> RemoteSignFuture { Box::pin(sign(message)) }),
+            runtime,
+        }
+    }
+
+    fn block_on(&self, future: RemoteSignFuture) -> Result<Signature, SignerError> {
+        match Handle::try_current() {
+            // blocking a worker panics, so it is handed over first, which also keeps the signing
+            // future from waiting on the blocked worker if it runs on the same runtime
+            Ok(current) if current.runtime_flavor() == RuntimeFlavor::MultiThread => {
+                task::block_in_place(|| self.runtime.block_on(future))
+            }
+            // the only worker of a current-thread runtime cannot be handed over, so the future
+            // has to run on the workers of another runtime
+            Ok(_) if self.runtime.handle().runtime_flavor() == RuntimeFlavor::MultiThread => {
+                let (sender, receiver) = mpsc::sync_channel(1);
+                self.runtime.spawn(async move {
+                    let _ = sender.send(future.await);
+                });
+                receiver
+                    .recv()
+                    .map_err(|_| SignerError::Connection("remote signer was dropped".to_string()))?
+            }
+            Ok(_) => Err(SignerError::Custom(
+                "remote signer cannot block within a current-thread runtime".to_string(),
+            )),
+            Err(_) => self.runtime.block_on(future),
+        }
+    }
+}
+
+impl fmt::Debug for RemoteSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RemoteSigner")
+            .field("pubkey", &self.pubkey)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Signer for RemoteSigner {
+    fn try_pubkey(&self) -> Result<Pubkey, SignerError> {
+        Ok(self.pubkey)
+    }
+
+    fn try_sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        let signature = self.block_on((self.sign)(message.to_vec()))?;
+
+        if !signature.verify(self.pubkey.as_ref(), message) {
+            return Err(SignerError::KeypairPubkeyMismatch);
+        }
+
+        Ok(signature)
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
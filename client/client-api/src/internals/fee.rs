@@ -1,5 +1,6 @@
 use solana_program_runtime::compute_budget_processor::process_compute_budget_instructions;
 use solana_program_runtime::prioritization_fee::{PrioritizationFeeDetails, PrioritizationFeeType};
+use solana_sdk::fee::FeeStructure;
 use solana_sdk::transaction::SanitizedTransaction;
 
 use crate::client::Client;
@@ -22,3 +23,25 @@ pub trait CalculatePrioritizationFee: Client {
 }
 
 impl<C: ?Sized + Client> CalculatePrioritizationFee for C {}
+
+pub trait CalculateFee: Client {
+    // as the bank does under the default fee structure
+    fn calculate_fee(
+        &self,
+        sanitized_transaction: &SanitizedTransaction,
+        lamports_per_signature: u64,
+    ) -> u64 {
+        let message = sanitized_transaction.message();
+        let fee_structure = FeeStructure {
+            lamports_per_signature,
+            ..FeeStructure::default()
+        };
+        let budget_limits =
+            process_compute_budget_instructions(message.program_instructions_iter())
+                .unwrap_or_default()
+                .into();
+        fee_structure.calculate_fee(message, lamports_per_signature, &budget_limits, false)
+    }
+}
+
+impl<C: ?Sized + Client> CalculateFee for C {}
@@ -0,0 +1,36 @@
+use solana_sdk::bs58;
+use solana_sdk::inner_instruction::{InnerInstruction, InnerInstructionsList};
+use solana_sdk::instruction::CompiledInstruction;
+use solana_transaction_status::{UiInnerInstructions, UiInstruction};
+
+// `None` if any inner instruction is not compiled, e.g. parsed by the rpc node
+pub fn decode_ui_inner_instructions(
+    ui_inner_instructions: Vec<UiInnerInstructions>,
+    num_instructions: usize,
+) -> Option<InnerInstructionsList> {
+    let mut inner_instructions_list = vec![Vec::new(); num_instructions];
+
+    for UiInnerInstructions {
+        index,
+        instructions,
+    } in ui_inner_instructions
+    {
+        let inner_instructions = inner_instructions_list.get_mut(usize::from(index))?;
+        for ui_instruction in instructions {
+            let UiInstruction::Compiled(compiled) = ui_instruction else {
+                return None;
+            };
+
+            inner_instructions.push(InnerInstruction {
+                instruction: CompiledInstruction {
+                    program_id_index: compiled.program_id_index,
+                    accounts: compiled.accounts,
+                    data: bs58::decode(compiled.data).into_vec().ok()?,
+                },
+                stack_height: u8::try_from(compiled.stack_height.unwrap_or_default()).ok()?,
+            });
+        }
+    }
+
+    Some(inner_instructions_list)
+}
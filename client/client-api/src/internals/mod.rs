@@ -1,2 +1,4 @@
 pub mod fee;
+pub mod inner_instructions;
+pub mod runtime;
 pub mod sanitize;
@@ -0,0 +1 @@
+pub use dexter_solana_banks_client_blocking::RUNTIME;
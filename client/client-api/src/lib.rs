@@ -2,6 +2,7 @@ pub mod base;
 pub mod errors;
 pub mod execution;
 pub mod exts;
+pub mod planning;
 
 mod base_impls;
 mod client;
@@ -0,0 +1,218 @@
+use solana_sdk::account::Account;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::{TransactionError, VersionedTransaction};
+
+use crate::base::executor::{ProcessTransaction, SimulateTransaction};
+use crate::base::getter::{
+    GetAccount, GetLatestBlockhash, GetLatestBlockhashWithExpiry,
+    GetMinimumBalanceForRentExemption, GetMultipleAccounts, GetProgramAccounts,
+    ProgramAccountsFilter,
+};
+use crate::base::options::CallOptions;
+use crate::client::Client;
+use crate::errors::ClientResult;
+use crate::execution::ExecutionOutput;
+use crate::internals::sanitize::SanitizeTransaction;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedAccount {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionPlan {
+    pub transaction: VersionedTransaction,
+    pub instructions: Vec<Instruction>,
+    pub accounts: Vec<PlannedAccount>,
+    pub required_signers: Vec<Pubkey>,
+    pub result: Result<(), TransactionError>,
+    pub logs: Vec<String>,
+    pub compute_units_consumed: u64,
+    pub estimated_fee: u64,
+}
+
+impl TransactionPlan {
+    pub fn is_success(&self) -> bool {
+        self.result.is_ok()
+    }
+
+    pub fn writable_accounts(&self) -> impl Iterator<Item = &Pubkey> {
+        self.accounts
+            .iter()
+            .filter(|account| account.is_writable)
+            .map(|account| &account.pubkey)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PlanningClient<C> {
+    inner: C,
+}
+
+impl<C> PlanningClient<C> {
+    pub fn new(inner: C) -> Self {
+        Self { inner }
+    }
+
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+impl<C: Client> Client for PlanningClient<C> {}
+
+impl<C: GetAccount> GetAccount for PlanningClient<C> {
+    fn get_account(&self, pubkey: &Pubkey) -> ClientResult<Option<Account>> {
+        self.inner.get_account(pubkey)
+    }
+
+    fn get_account_with_options(
+        &self,
+        pubkey: &Pubkey,
+        options: &CallOptions,
+    ) -> ClientResult<Option<Account>> {
+        self.inner.get_account_with_options(pubkey, options)
+    }
+}
+
+impl<C: GetProgramAccounts> GetProgramAccounts for PlanningClient<C> {
+    fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        self.inner.get_program_accounts(program_id, filters)
+    }
+
+    fn get_program_accounts_with_options(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+        options: &CallOptions,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        self.inner
+            .get_program_accounts_with_options(program_id, filters, options)
+    }
+}
+
+impl<C: GetMultipleAccounts> GetMultipleAccounts for PlanningClient<C> {
+    fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> ClientResult<Vec<Option<Account>>> {
+        self.inner.get_multiple_accounts(pubkeys)
+    }
+
+    fn get_multiple_accounts_with_options(
+        &self,
+        pubkeys: &[Pubkey],
+        options: &CallOptions,
+    ) -> ClientResult<Vec<Option<Account>>> {
+        self.inner
+            .get_multiple_accounts_with_options(pubkeys, options)
+    }
+}
+
+impl<C: GetMinimumBalanceForRentExemption> GetMinimumBalanceForRentExemption for PlanningClient<C> {
+    fn get_minimum_balance_for_rent_exemption(&self, data_len: usize) -> ClientResult<u64> {
+        self.inner.get_minimum_balance_for_rent_exemption(data_len)
+    }
+
+    fn get_minimum_balance_for_rent_exemption_with_options(
+        &self,
+        data_len: usize,
+        options: &CallOptions,
+    ) -> ClientResult<u64> {
+        self.inner
+            .get_minimum_balance_for_rent_exemption_with_options(data_len, options)
+    }
+}
+
+impl<C: GetLatestBlockhash> GetLatestBlockhash for PlanningClient<C> {
+    fn get_latest_blockhash(&self) -> ClientResult<Hash> {
+        self.inner.get_latest_blockhash()
+    }
+
+    fn get_latest_blockhash_with_options(&self, options: &CallOptions) -> ClientResult<Hash> {
+        self.inner.get_latest_blockhash_with_options(options)
+    }
+}
+
+impl<C: GetLatestBlockhashWithExpiry> GetLatestBlockhashWithExpiry for PlanningClient<C> {
+    fn get_latest_blockhash_with_last_valid_block_height(&self) -> ClientResult<(Hash, u64)> {
+        self.inner
+            .get_latest_blockhash_with_last_valid_block_height()
+    }
+}
+
+impl<C> ProcessTransaction<TransactionPlan> for PlanningClient<C>
+where
+    C: Client + GetMultipleAccounts + SimulateTransaction<ExecutionOutput>,
+{
+    fn process_transaction(
+        &self,
+        transaction: VersionedTransaction,
+    ) -> ClientResult<TransactionPlan> {
+        let sanitized_transaction = self.inner.sanitize_transaction(transaction.clone())?;
+        let message = sanitized_transaction.message();
+
+        let instructions = message
+            .decompile_instructions()
+            .into_iter()
+            .map(|instruction| Instruction {
+                program_id: *instruction.program_id,
+                accounts: instruction
+                    .accounts
+                    .iter()
+                    .map(|meta| AccountMeta {
+                        pubkey: *meta.pubkey,
+                        is_signer: meta.is_signer,
+                        is_writable: meta.is_writable,
+                    })
+                    .collect(),
+                data: instruction.data.to_vec(),
+            })
+            .collect();
+
+        let accounts: Vec<_> = message
+            .account_keys()
+            .iter()
+            .enumerate()
+            .map(|(index, pubkey)| PlannedAccount {
+                pubkey: *pubkey,
+                is_signer: message.is_signer(index),
+                is_writable: message.is_writable(index),
+            })
+            .collect();
+
+        let required_signers = accounts
+            .iter()
+            .filter(|account| account.is_signer)
+            .map(|account| account.pubkey)
+            .collect();
+
+        let ExecutionOutput {
+            result,
+            logs,
+            compute_units_consumed,
+            fee,
+            ..
+        } = self.inner.simulate_transaction(transaction.clone())?;
+
+        Ok(TransactionPlan {
+            transaction,
+            instructions,
+            accounts,
+            required_signers,
+            result,
+            logs,
+            compute_units_consumed,
+            estimated_fee: fee,
+        })
+    }
+}
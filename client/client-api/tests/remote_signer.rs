@@ -0,0 +1,138 @@
+use std::sync::Arc;
+
+use solana_sdk::hash::Hash;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::{Signer, SignerError};
+use solana_sdk::system_instruction;
+use tokio::runtime::{Builder, Runtime};
+
+use dexter_client_api::base::getter::GetLatestBlockhash;
+use dexter_client_api::errors::{ClientError, ClientResult};
+use dexter_client_api::exts::executor::CompileTransaction;
+use dexter_client_api::exts::signer::{PartialSign, RemoteSigner};
+use dexter_client_api::Client;
+
+// compiling only needs a blockhash
+struct Offline;
+
+impl Client for Offline {}
+
+impl GetLatestBlockhash for Offline {
+    fn get_latest_blockhash(&self) -> ClientResult<Hash> {
+        Ok(Hash::new_unique())
+    }
+}
+
+fn runtime(worker_threads: usize) -> Arc<Runtime> {
+    let runtime = Builder::new_multi_thread()
+        .worker_threads(worker_threads)
+        .enable_all()
+        .build()
+        .unwrap();
+    Arc::new(runtime)
+}
+
+fn remote_signer(keypair: Keypair, runtime: Arc<Runtime>) -> RemoteSigner {
+    let pubkey = keypair.pubkey();
+    let keypair = Arc::new(keypair);
+    RemoteSigner::with_runtime(
+        pubkey,
+        move |message| {
+            let keypair = keypair.clone();
+            async move {
+                tokio::task::yield_now().await;
+                Ok(keypair.sign_message(&message))
+            }
+        },
+        runtime,
+    )
+}
+
+#[test]
+fn test_compile_transaction() {
+    let payer = remote_signer(Keypair::new(), runtime(1));
+    let recipient = Keypair::new();
+
+    let instruction = system_instruction::transfer(&payer.pubkey(), &recipient.pubkey(), 1);
+    let transaction = Offline
+        .compile_transaction(
+            &[instruction],
+            &payer.pubkey(),
+            &[&payer as &dyn Signer],
+            &[],
+        )
+        .unwrap();
+
+    assert!(transaction.is_fully_signed());
+    assert_eq!(transaction.verify_signatures(), Ok(()));
+}
+
+#[test]
+fn test_compile_transaction_with_local_signer() {
+    let payer = Keypair::new();
+    let authority = remote_signer(Keypair::new(), runtime(1));
+
+    let instruction = system_instruction::transfer(&authority.pubkey(), &payer.pubkey(), 1);
+    let transaction = Offline
+        .compile_transaction(
+            &[instruction],
+            &payer.pubkey(),
+            &[&payer as &dyn Signer, &authority],
+            &[],
+        )
+        .unwrap();
+
+    assert!(transaction.is_fully_signed());
+    assert_eq!(transaction.verify_signatures(), Ok(()));
+}
+
+#[test]
+fn test_compile_transaction_with_mismatched_signature() {
+    let pubkey = Keypair::new().pubkey();
+    let other = Arc::new(Keypair::new());
+    let payer = RemoteSigner::new(pubkey, move |message| {
+        let other = other.clone();
+        async move { Ok(other.sign_message(&message)) }
+    });
+
+    let instruction = system_instruction::transfer(&pubkey, &Keypair::new().pubkey(), 1);
+    let result =
+        Offline.compile_transaction(&[instruction], &pubkey, &[&payer as &dyn Signer], &[]);
+
+    assert!(matches!(
+        result,
+        Err(ClientError::SigningError(
+            SignerError::KeypairPubkeyMismatch
+        ))
+    ));
+}
+
+#[test]
+fn test_sign_within_signer_runtime() {
+    // a single worker deadlocks unless it is handed over while blocking
+    let runtime = runtime(1);
+    let keypair = Keypair::new();
+    let pubkey = keypair.pubkey();
+    let signer = remote_signer(keypair, runtime.clone());
+
+    let signature = runtime
+        .block_on(runtime.spawn(async move { signer.try_sign_message(b"message") }))
+        .unwrap()
+        .unwrap();
+
+    assert!(signature.verify(pubkey.as_ref(), b"message"));
+}
+
+#[test]
+fn test_sign_within_current_thread_runtime() {
+    let keypair = Keypair::new();
+    let pubkey = keypair.pubkey();
+    let signer = remote_signer(keypair, runtime(1));
+
+    let current_thread = Builder::new_current_thread().build().unwrap();
+    let signature = current_thread
+        .block_on(async { signer.try_sign_message(b"message") })
+        .unwrap();
+
+    assert!(signature.verify(pubkey.as_ref(), b"message"));
+}
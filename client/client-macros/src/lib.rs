@@ -0,0 +1,451 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use serde_json::Value;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{
+    parse_macro_input, parse_quote, Data, DeriveInput, Error, Fields, Ident, ItemTrait, LitStr,
+    Meta, Path, Token, TraitItem, Type,
+};
+
+/// Generates `{Name}Getter`, `{Name}Instruction` and `{Name}Processor` traits from an Anchor IDL,
+/// over the `declare_program!` module named after the program or given by `program = path`.
+/// Default items of the annotated trait are kept. Pass e.g. `crate = dexter_client::anchor` when
+/// `dexter_client_anchor` is only reachable through a re-export.
+///
+/// ```ignore
+/// declare_program!(counter);
+///
+/// #[dexter_program_client(idl = "idls/counter.json")]
+/// pub trait Counter {}
+///
+/// let state = client.try_get_counter_state(&state_key)?;
+/// client.process_increment(&payer, accounts, args, &[])?;
+/// ```
+#[proc_macro_attribute]
+pub fn dexter_program_client(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr as Args);
+    let item = parse_macro_input!(item as ItemTrait);
+
+    match expand(args, item) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+struct Args {
+    idl: LitStr,
+    program: Option<Path>,
+    krate: Option<Path>,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut idl = None;
+        let mut program = None;
+        let mut krate = None;
+
+        let args = Punctuated::<Arg, Token![,]>::parse_terminated(input)?;
+        for arg in args {
+            match arg {
+                Arg::Idl(lit) => idl = Some(lit),
+                Arg::Program(path) => program = Some(path),
+                Arg::Crate(path) => krate = Some(path),
+            }
+        }
+
+        let idl = idl.ok_or_else(|| input.error("missing `idl = \"path/to/idl.json\"`"))?;
+
+        Ok(Self {
+            idl,
+            program,
+            krate,
+        })
+    }
+}
+
+enum Arg {
+    Idl(LitStr),
+    Program(Path),
+    Crate(Path),
+}
+
+impl Parse for Arg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![crate]) {
+            return parse_crate(input).map(Self::Crate);
+        }
+
+        let name: Ident = input.parse()?;
+        input.parse::<Token![=]>()?;
+
+        match name.to_string().as_str() {
+            "idl" => Ok(Self::Idl(input.parse()?)),
+            "program" => Ok(Self::Program(input.parse()?)),
+            _ => Err(Error::new(
+                name.span(),
+                "expected `idl`, `program` or `crate`",
+            )),
+        }
+    }
+}
+
+fn parse_crate(input: ParseStream) -> syn::Result<Path> {
+    input.parse::<Token![crate]>()?;
+    input.parse::<Token![=]>()?;
+    input.parse()
+}
+
+// `dexter_client_anchor`, unless given as re-exported, e.g. `crate = dexter_client::anchor`
+fn crate_path(krate: Option<Path>) -> Path {
+    krate.unwrap_or_else(|| parse_quote!(::dexter_client_anchor))
+}
+
+fn expand(args: Args, item: ItemTrait) -> syn::Result<TokenStream2> {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let idl_path = PathBuf::from(manifest_dir).join(args.idl.value());
+
+    let idl: Value = fs::read_to_string(&idl_path)
+        .map_err(|err| Error::new(args.idl.span(), format!("failed to read the idl: {err}")))
+        .and_then(|json| {
+            serde_json::from_str(&json)
+                .map_err(|err| Error::new(args.idl.span(), format!("invalid idl: {err}")))
+        })?;
+
+    let program = match args.program {
+        Some(program) => program,
+        None => {
+            let name = idl["metadata"]["name"]
+                .as_str()
+                .ok_or_else(|| Error::new(args.idl.span(), "the idl has no program name"))?;
+            Ident::new(name, Span::call_site()).into()
+        }
+    };
+
+    let names = |key: &str| -> Vec<String> {
+        idl[key]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry["name"].as_str().map(ToOwned::to_owned))
+            .collect()
+    };
+
+    // the trait is implemented for every client, so it cannot require anything of them
+    if !item.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &item.generics,
+            "generic traits are not supported",
+        ));
+    }
+    if !item.supertraits.is_empty() {
+        return Err(Error::new_spanned(
+            &item.supertraits,
+            "supertraits are not supported",
+        ));
+    }
+    for trait_item in &item.items {
+        let has_default = match trait_item {
+            TraitItem::Fn(item) => item.default.is_some(),
+            TraitItem::Const(item) => item.default.is_some(),
+            _ => false,
+        };
+        if !has_default {
+            return Err(Error::new_spanned(
+                trait_item,
+                "only methods and constants with a default are supported",
+            ));
+        }
+    }
+
+    let krate = crate_path(args.krate);
+    let api = quote!(#krate::__private::dexter_client_api);
+    let sdk = quote!(#krate::__private::solana_sdk);
+
+    let attrs = &item.attrs;
+    let items = &item.items;
+    let vis = &item.vis;
+    let name = &item.ident;
+    let getter = format_ident!("{}Getter", name);
+    let instruction = format_ident!("{}Instruction", name);
+    let processor = format_ident!("{}Processor", name);
+
+    let getter_methods = names("accounts").into_iter().map(|account| {
+        let ty = Ident::new(&account, Span::call_site());
+        let snake = to_snake_case(&account);
+        let get = format_ident!("get_{}", snake);
+        let try_get = format_ident!("try_get_{}", snake);
+        let get_all = format_ident!("get_{}_accounts", snake);
+
+        quote! {
+            fn #get(
+                &self,
+                pubkey: &#sdk::pubkey::Pubkey,
+            ) -> #api::errors::ClientResult<
+                ::core::option::Option<#krate::AnchorAccount<#program::accounts::#ty>>,
+            >
+            where
+                Self: #api::base::getter::GetAccount,
+            {
+                #krate::AnchorGetter::get_anchor_account(self, pubkey)
+            }
+
+            fn #try_get(
+                &self,
+                pubkey: &#sdk::pubkey::Pubkey,
+            ) -> #api::errors::ClientResult<
+                #krate::AnchorAccount<#program::accounts::#ty>,
+            >
+            where
+                Self: #api::base::getter::GetAccount,
+            {
+                #krate::AnchorGetter::try_get_anchor_account(self, pubkey)
+            }
+
+            fn #get_all(
+                &self,
+                filters: ::core::option::Option<
+                    ::std::vec::Vec<#api::base::getter::ProgramAccountsFilter>,
+                >,
+            ) -> #api::errors::ClientResult<
+                ::std::vec::Vec<#krate::AnchorAccount<#program::accounts::#ty>>,
+            >
+            where
+                Self: #api::base::getter::GetProgramAccounts,
+            {
+                #krate::AnchorGetter::get_anchor_program_accounts_filtered(
+                    self, filters,
+                )
+            }
+        }
+    });
+
+    let instructions = names("instructions");
+
+    let instruction_methods = instructions.iter().map(|ix| {
+        let ty = Ident::new(&to_pascal_case(ix), Span::call_site());
+        let build = format_ident!("build_{}", ix);
+
+        quote! {
+            fn #build(
+                &self,
+                accounts: #program::client::accounts::#ty,
+                args: #program::client::args::#ty,
+            ) -> #sdk::instruction::Instruction {
+                #krate::AnchorInstruction::build_anchor_instruction(
+                    self,
+                    #program::ID,
+                    accounts,
+                    args,
+                )
+            }
+        }
+    });
+
+    let processor_methods = instructions.iter().map(|ix| {
+        let ty = Ident::new(&to_pascal_case(ix), Span::call_site());
+        let process = format_ident!("process_{}", ix);
+
+        quote! {
+            fn #process(
+                &self,
+                payer: &impl #api::exts::signer::FeePayer,
+                accounts: #program::client::accounts::#ty,
+                args: #program::client::args::#ty,
+                signers: &[&dyn #sdk::signer::Signer],
+            ) -> #api::errors::ClientResult<#api::execution::ExecutionOutput>
+            where
+                Self: #api::base::getter::GetLatestBlockhash
+                    + #api::base::executor::ProcessTransaction<
+                        #api::execution::ExecutionOutput,
+                    >,
+            {
+                #krate::AnchorProcessor::process_anchor_instruction(
+                    self,
+                    payer,
+                    #program::ID,
+                    accounts,
+                    args,
+                    signers,
+                )
+            }
+        }
+    });
+
+    let idl_path = idl_path.to_string_lossy();
+
+    Ok(quote! {
+        // recompiles on changes of the idl
+        const _: &[u8] = include_bytes!(#idl_path);
+
+        #vis trait #getter: #api::Client {
+            #(#getter_methods)*
+        }
+
+        impl<C: ?Sized + #api::Client> #getter for C {}
+
+        #vis trait #instruction: #api::Client {
+            #(#instruction_methods)*
+        }
+
+        impl<C: ?Sized + #api::Client> #instruction for C {}
+
+        #vis trait #processor: #api::Client {
+            #(#processor_methods)*
+        }
+
+        impl<C: ?Sized + #api::Client> #processor for C {}
+
+        #(#attrs)*
+        #vis trait #name: #getter + #instruction + #processor {
+            #(#items)*
+        }
+
+        impl<C: ?Sized + #api::Client> #name for C {}
+    })
+}
+
+/// Derives `FIELD_OFFSETS` for `filter_on!`, up to the first field of a variable size. Zero-copy
+/// accounts are rejected. Takes `#[field_offsets(crate = path)]` like `dexter_program_client`.
+#[proc_macro_derive(FieldOffsets, attributes(field_offsets))]
+pub fn derive_field_offsets(item: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(item as DeriveInput);
+
+    match expand_field_offsets(item) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand_field_offsets(item: DeriveInput) -> syn::Result<TokenStream2> {
+    if !item.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &item.generics,
+            "generic accounts are not supported",
+        ));
+    }
+
+    if is_zero_copy(&item) {
+        return Err(Error::new_spanned(
+            &item.ident,
+            "zero-copy accounts are not supported, as their fields are laid out as in memory",
+        ));
+    }
+
+    let fields = match &item.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new_spanned(&item.ident, "expected named fields")),
+        },
+        _ => return Err(Error::new_spanned(&item.ident, "expected a struct")),
+    };
+
+    let mut krate = None;
+    for attr in &item.attrs {
+        if attr.path().is_ident("field_offsets") {
+            krate = Some(attr.parse_args_with(parse_crate)?);
+        }
+    }
+    let krate = crate_path(krate);
+
+    let vis = &item.vis;
+    let name = &item.ident;
+    let offsets_name = format_ident!("{}FieldOffsets", name);
+
+    let mut names = Vec::new();
+    let mut offsets = Vec::new();
+    let mut offset = quote!(8usize);
+    for field in fields {
+        names.push(field.ident.clone().unwrap());
+        offsets.push(offset.clone());
+
+        if is_variable_size(&field.ty) {
+            break;
+        }
+
+        let ty = &field.ty;
+        offset = quote!(#offset + <#ty as #krate::__private::anchor_lang::Space>::INIT_SPACE);
+    }
+
+    Ok(quote! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #vis struct #offsets_name {
+            #(pub #names: usize,)*
+        }
+
+        impl #name {
+            pub const FIELD_OFFSETS: #offsets_name = #offsets_name {
+                #(#names: #offsets,)*
+            };
+        }
+    })
+}
+
+// `#[account(zero_copy)]` leaves a `repr` behind once expanded before the derive
+fn is_zero_copy(item: &DeriveInput) -> bool {
+    item.attrs.iter().any(|attr| match &attr.meta {
+        Meta::List(list) if list.path.is_ident("account") => {
+            list.tokens.to_string().contains("zero_copy")
+        }
+        Meta::List(list) if list.path.is_ident("repr") => list
+            .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+            .is_ok_and(|reprs| {
+                reprs
+                    .iter()
+                    .any(|repr| repr.path().is_ident("C") || repr.path().is_ident("packed"))
+            }),
+        meta => meta.path().is_ident("zero_copy"),
+    })
+}
+
+fn is_variable_size(ty: &Type) -> bool {
+    const VARIABLE_SIZE_TYPES: &[&str] = &[
+        "Vec", "VecDeque", "String", "Option", "HashMap", "BTreeMap", "HashSet", "BTreeSet",
+    ];
+
+    match ty {
+        Type::Path(path) => path.path.segments.last().is_some_and(|segment| {
+            VARIABLE_SIZE_TYPES.contains(&segment.ident.to_string().as_str())
+        }),
+        _ => false,
+    }
+}
+
+fn to_pascal_case(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::with_capacity(name.len() + 4);
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            // word boundaries of both `UserState` and `NFTConfig`
+            if prev.is_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_uppercase() && next_is_lower)
+            {
+                snake.push('_');
+            }
+        }
+        snake.extend(c.to_lowercase());
+    }
+
+    snake
+}
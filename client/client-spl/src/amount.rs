@@ -0,0 +1,71 @@
+use thiserror::Error;
+
+use dexter_client_api::errors::ClientError;
+
+// for ui amounts with more fractional digits than the mint decimals
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Rounding {
+    #[default]
+    Down,
+    Up,
+    // half up
+    Nearest,
+    Exact,
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum AmountError {
+    #[error("Invalid ui amount {0:?}")]
+    Invalid(String),
+    #[error("The ui amount overflows a raw amount")]
+    Overflow,
+    #[error("The ui amount {0:?} is not representable with {1} decimals")]
+    Inexact(String, u8),
+}
+
+impl From<AmountError> for ClientError {
+    fn from(error: AmountError) -> Self {
+        ClientError::DomainSpecific(Box::new(error))
+    }
+}
+
+// parses the decimal string without going through floats
+pub fn ui_amount_to_amount(
+    ui_amount: &str,
+    decimals: u8,
+    rounding: Rounding,
+) -> Result<u64, AmountError> {
+    let trimmed = ui_amount.trim();
+    let (integer, fraction) = trimmed.split_once('.').unwrap_or((trimmed, ""));
+
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if (integer.is_empty() && fraction.is_empty()) || !is_digits(integer) || !is_digits(fraction) {
+        return Err(AmountError::Invalid(ui_amount.to_string()));
+    }
+
+    let precision = decimals as usize;
+    let (kept, dropped) = fraction.split_at(fraction.len().min(precision));
+
+    let digits = format!("{integer}{kept:0<precision$}");
+    let amount = match digits.trim_start_matches('0') {
+        "" => 0,
+        digits => digits.parse::<u64>().map_err(|_| AmountError::Overflow)?,
+    };
+
+    let has_remainder = dropped.bytes().any(|b| b != b'0');
+    let round_up = match rounding {
+        Rounding::Down => false,
+        Rounding::Up => has_remainder,
+        Rounding::Nearest => dropped.bytes().next().is_some_and(|b| b >= b'5'),
+        Rounding::Exact if has_remainder => {
+            return Err(AmountError::Inexact(ui_amount.to_string(), decimals));
+        }
+        Rounding::Exact => false,
+    };
+
+    if round_up {
+        amount.checked_add(1).ok_or(AmountError::Overflow)
+    } else {
+        Ok(amount)
+    }
+}
@@ -1,38 +1,47 @@
-use solana_sdk::compute_budget::ComputeBudgetInstruction;
-use solana_sdk::instruction::Instruction;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signer::Signer;
 
 use anchor_spl::associated_token::{get_associated_token_address, spl_associated_token_account};
 use anchor_spl::token::{spl_token, TokenAccount};
 
-use dexter_client_anchor::AnchorAccount;
-use dexter_client_api::base::executor::ProcessTransaction;
-use dexter_client_api::base::getter::{GetAccount, GetLatestBlockhash};
+use dexter_client_anchor::{AnchorAccount, AnchorGetter};
+use dexter_client_api::base::executor::{ProcessTransaction, SimulateTransaction};
+use dexter_client_api::base::getter::{GetAccount, GetLatestBlockhash, GetMultipleAccounts};
 use dexter_client_api::base::setter::{HasRent, SetAccount};
-use dexter_client_api::errors::ClientResult;
+use dexter_client_api::errors::{ClientError, ClientResult};
 use dexter_client_api::execution::ExecutionOutput;
-use dexter_client_api::exts::executor::CompilingProcessTransaction;
+use dexter_client_api::exts::executor::{
+    CompilingProcessTransactionWithBudget, ComputeBudgetConfig,
+};
+use dexter_client_api::exts::signer::FeePayer;
 use dexter_client_api::Client;
 
-use crate::token::{TokenGetter, TokenSetter};
+use crate::token::{TokenGetter, TokenInstruction, TokenSetter};
 
-const COMPUTE_BUDGET_UNITS: u32 = 50_000;
 const COMPUTE_BUDGET_PRICE: u64 = 1_000_000;
 
-fn with_compute_budget(instruction: Instruction) -> [Instruction; 3] {
-    [
-        ComputeBudgetInstruction::set_compute_unit_limit(COMPUTE_BUDGET_UNITS),
-        ComputeBudgetInstruction::set_compute_unit_price(COMPUTE_BUDGET_PRICE),
-        instruction,
-    ]
-}
+const MAX_MULTIPLE_ACCOUNTS: usize = 100;
 
 pub trait AssociatedTokenGetter: Client {
     fn get_associated_token_address(&self, owner: &Pubkey, mint: &Pubkey) -> Pubkey {
         get_associated_token_address(owner, mint)
     }
 
+    fn get_associated_token_address_with_owner_off_curve(
+        &self,
+        owner: &Pubkey,
+        mint: &Pubkey,
+        allow_owner_off_curve: bool,
+    ) -> ClientResult<Pubkey> {
+        if !allow_owner_off_curve && !owner.is_on_curve() {
+            return Err(ClientError::DomainSpecific(
+                format!("owner {owner} is off curve").into(),
+            ));
+        }
+
+        Ok(self.get_associated_token_address(owner, mint))
+    }
+
     fn get_associated_token_account(
         &self,
         owner: &Pubkey,
@@ -41,7 +50,23 @@ pub trait AssociatedTokenGetter: Client {
     where
         Self: GetAccount,
     {
-        self.get_token_account(&self.get_associated_token_address(owner, mint))
+        self.get_associated_token_account_with_owner_off_curve(owner, mint, true)
+    }
+
+    fn get_associated_token_account_with_owner_off_curve(
+        &self,
+        owner: &Pubkey,
+        mint: &Pubkey,
+        allow_owner_off_curve: bool,
+    ) -> ClientResult<Option<AnchorAccount<TokenAccount>>>
+    where
+        Self: GetAccount,
+    {
+        self.get_token_account(&self.get_associated_token_address_with_owner_off_curve(
+            owner,
+            mint,
+            allow_owner_off_curve,
+        )?)
     }
 
     fn try_get_associated_token_account(
@@ -54,6 +79,28 @@ pub trait AssociatedTokenGetter: Client {
     {
         self.try_get_token_account(&self.get_associated_token_address(owner, mint))
     }
+
+    /// Zero where the associated token account is missing.
+    fn get_balances_for_owner(&self, owner: &Pubkey, mints: &[Pubkey]) -> ClientResult<Vec<u64>>
+    where
+        Self: GetMultipleAccounts,
+    {
+        let token_accounts: Vec<Pubkey> = mints
+            .iter()
+            .map(|mint| self.get_associated_token_address(owner, mint))
+            .collect();
+
+        let mut balances = Vec::with_capacity(token_accounts.len());
+        for chunk in token_accounts.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            balances.extend(
+                self.get_anchor_multiple_accounts::<TokenAccount>(chunk)?
+                    .into_iter()
+                    .map(|token_account| token_account.map_or(0, |ta| ta.amount)),
+            );
+        }
+
+        Ok(balances)
+    }
 }
 
 impl<C: ?Sized + Client> AssociatedTokenGetter for C {}
@@ -61,23 +108,78 @@ impl<C: ?Sized + Client> AssociatedTokenGetter for C {}
 pub trait AssociatedTokenProcessor: Client {
     fn process_create_associated_token_account(
         &self,
-        payer: &impl Signer,
+        payer: &impl FeePayer,
         owner: Pubkey,
         mint: Pubkey,
     ) -> ClientResult<ExecutionOutput>
     where
-        Self: GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+        Self: GetLatestBlockhash
+            + SimulateTransaction<ExecutionOutput>
+            + ProcessTransaction<ExecutionOutput>,
     {
-        let instructions = with_compute_budget(
+        let instructions = [
             spl_associated_token_account::instruction::create_associated_token_account(
-                &payer.pubkey(),
+                &payer.payer_pubkey(),
                 &owner,
                 &mint,
                 &spl_token::id(),
             ),
-        );
-        let signers: Vec<&dyn Signer> = vec![payer];
-        self.compiling_process_transaction(&instructions, &payer.pubkey(), &signers, &[])
+        ];
+        let budget = ComputeBudgetConfig::with_unit_price(COMPUTE_BUDGET_PRICE);
+        let signers: Vec<&dyn Signer> = vec![payer.payer()];
+        self.compiling_process_transaction_with_budget(
+            &instructions,
+            &payer.payer_pubkey(),
+            &signers,
+            &[],
+            &budget,
+        )
+    }
+
+    /// Creates the associated token account if missing.
+    fn process_airdrop_token(
+        &self,
+        payer: &impl FeePayer,
+        mint: Pubkey,
+        mint_authority: &impl Signer,
+        recipient_owner: Pubkey,
+        amount: u64,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetAccount
+            + GetLatestBlockhash
+            + SimulateTransaction<ExecutionOutput>
+            + ProcessTransaction<ExecutionOutput>,
+    {
+        let token_account = self.get_associated_token_address(&recipient_owner, &mint);
+
+        let mut instructions = Vec::with_capacity(2);
+        if self.get_account(&token_account)?.is_none() {
+            instructions.push(
+                spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+                    &payer.payer_pubkey(),
+                    &recipient_owner,
+                    &mint,
+                    &spl_token::id(),
+                ),
+            );
+        }
+        instructions.push(self.build_mint_to(
+            &token_account,
+            &mint,
+            &mint_authority.pubkey(),
+            amount,
+        ));
+
+        let budget = ComputeBudgetConfig::with_unit_price(COMPUTE_BUDGET_PRICE);
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), mint_authority];
+        self.compiling_process_transaction_with_budget(
+            &instructions,
+            &payer.payer_pubkey(),
+            &signers,
+            &[],
+            &budget,
+        )
     }
 }
 
@@ -1,34 +1,36 @@
-use solana_sdk::compute_budget::ComputeBudgetInstruction;
-use solana_sdk::instruction::Instruction;
+use solana_sdk::account::ReadableAccount;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signer::Signer;
 
+use anchor_lang::Key;
 use anchor_spl::associated_token::{
     get_associated_token_address_with_program_id, spl_associated_token_account,
 };
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022;
 use anchor_spl::token_interface::TokenAccount;
 
-use dexter_client_anchor::AnchorAccount;
-use dexter_client_api::base::executor::ProcessTransaction;
-use dexter_client_api::base::getter::{GetAccount, GetLatestBlockhash};
+use dexter_client_anchor::{AnchorAccount, AnchorGetter};
+use dexter_client_api::base::executor::{ProcessTransaction, SimulateTransaction};
+use dexter_client_api::base::getter::{GetAccount, GetLatestBlockhash, GetMultipleAccounts};
 use dexter_client_api::base::setter::{HasRent, SetAccount};
-use dexter_client_api::errors::ClientResult;
+use dexter_client_api::errors::{ClientError, ClientResult};
 use dexter_client_api::execution::ExecutionOutput;
-use dexter_client_api::exts::executor::CompilingProcessTransaction;
+use dexter_client_api::exts::executor::{
+    CompilingProcessTransactionWithBudget, ComputeBudgetConfig,
+};
+use dexter_client_api::exts::signer::FeePayer;
 use dexter_client_api::Client;
 
-use crate::token_interface::{TokenInterfaceGetter, TokenInterfaceSetter};
+use crate::token_interface::{
+    TokenInterfaceGetter, TokenInterfaceInstruction, TokenInterfaceSetter,
+};
 
-const COMPUTE_BUDGET_UNITS: u32 = 50_000;
 const COMPUTE_BUDGET_PRICE: u64 = 1_000_000;
 
-fn with_compute_budget(instruction: Instruction) -> [Instruction; 3] {
-    [
-        ComputeBudgetInstruction::set_compute_unit_limit(COMPUTE_BUDGET_UNITS),
-        ComputeBudgetInstruction::set_compute_unit_price(COMPUTE_BUDGET_PRICE),
-        instruction,
-    ]
-}
+// the maximum number of accounts per getMultipleAccounts call, an even number keeping the
+// candidates of a mint together
+const MAX_MULTIPLE_ACCOUNTS: usize = 100;
 
 pub trait AssociatedTokenInterfaceGetter: Client {
     fn get_associated_token_address(
@@ -40,6 +42,22 @@ pub trait AssociatedTokenInterfaceGetter: Client {
         get_associated_token_address_with_program_id(owner, mint, token_program_id)
     }
 
+    fn get_associated_token_address_with_owner_off_curve(
+        &self,
+        token_program_id: &Pubkey,
+        owner: &Pubkey,
+        mint: &Pubkey,
+        allow_owner_off_curve: bool,
+    ) -> ClientResult<Pubkey> {
+        if !allow_owner_off_curve && !owner.is_on_curve() {
+            return Err(ClientError::DomainSpecific(
+                format!("owner {owner} is off curve").into(),
+            ));
+        }
+
+        Ok(self.get_associated_token_address(token_program_id, owner, mint))
+    }
+
     fn get_associated_token_account(
         &self,
         owner: &Pubkey,
@@ -48,11 +66,24 @@ pub trait AssociatedTokenInterfaceGetter: Client {
     where
         Self: GetAccount,
     {
-        self.get_token_account(&self.get_associated_token_address(
+        self.get_associated_token_account_with_owner_off_curve(owner, mint, true)
+    }
+
+    fn get_associated_token_account_with_owner_off_curve(
+        &self,
+        owner: &Pubkey,
+        mint: &Pubkey,
+        allow_owner_off_curve: bool,
+    ) -> ClientResult<Option<AnchorAccount<TokenAccount>>>
+    where
+        Self: GetAccount,
+    {
+        self.get_token_account(&self.get_associated_token_address_with_owner_off_curve(
             &self.try_get_token_program_id(mint)?,
             owner,
             mint,
-        ))
+            allow_owner_off_curve,
+        )?)
     }
 
     fn try_get_associated_token_account(
@@ -69,6 +100,31 @@ pub trait AssociatedTokenInterfaceGetter: Client {
             mint,
         ))
     }
+
+    fn get_balances_for_owner(&self, owner: &Pubkey, mints: &[Pubkey]) -> ClientResult<Vec<u64>>
+    where
+        Self: GetMultipleAccounts,
+    {
+        let token_accounts: Vec<Pubkey> = mints
+            .iter()
+            .flat_map(|mint| {
+                [spl_token::id(), spl_token_2022::id()]
+                    .map(|program_id| self.get_associated_token_address(&program_id, owner, mint))
+            })
+            .collect();
+
+        let mut candidates = Vec::with_capacity(token_accounts.len());
+        for chunk in token_accounts.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            candidates.extend(self.get_anchor_multiple_accounts::<TokenAccount>(chunk)?);
+        }
+
+        let balances = candidates
+            .chunks(2)
+            .map(|candidates| candidates.iter().flatten().next().map_or(0, |ta| ta.amount))
+            .collect();
+
+        Ok(balances)
+    }
 }
 
 impl<C: ?Sized + Client> AssociatedTokenInterfaceGetter for C {}
@@ -76,23 +132,146 @@ impl<C: ?Sized + Client> AssociatedTokenInterfaceGetter for C {}
 pub trait AssociatedTokenInterfaceProcessor: Client {
     fn process_create_associated_token_account(
         &self,
-        payer: &impl Signer,
+        payer: &impl FeePayer,
         owner: Pubkey,
         mint: Pubkey,
     ) -> ClientResult<ExecutionOutput>
     where
-        Self: GetAccount + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+        Self: GetAccount
+            + GetLatestBlockhash
+            + SimulateTransaction<ExecutionOutput>
+            + ProcessTransaction<ExecutionOutput>,
     {
-        let instructions = with_compute_budget(
+        let instructions = [
             spl_associated_token_account::instruction::create_associated_token_account(
-                &payer.pubkey(),
+                &payer.payer_pubkey(),
                 &owner,
                 &mint,
                 &self.try_get_token_program_id(&mint)?,
             ),
+        ];
+        let budget = ComputeBudgetConfig::with_unit_price(COMPUTE_BUDGET_PRICE);
+        let signers: Vec<&dyn Signer> = vec![payer.payer()];
+        self.compiling_process_transaction_with_budget(
+            &instructions,
+            &payer.payer_pubkey(),
+            &signers,
+            &[],
+            &budget,
+        )
+    }
+
+    fn process_airdrop_token(
+        &self,
+        payer: &impl FeePayer,
+        mint: Pubkey,
+        mint_authority: &impl Signer,
+        recipient_owner: Pubkey,
+        amount: u64,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetAccount
+            + GetLatestBlockhash
+            + SimulateTransaction<ExecutionOutput>
+            + ProcessTransaction<ExecutionOutput>,
+    {
+        let token_program_id = self.try_get_token_program_id(&mint)?;
+        let token_account =
+            self.get_associated_token_address(&token_program_id, &recipient_owner, &mint);
+
+        let mut instructions = Vec::with_capacity(2);
+        if self.get_account(&token_account)?.is_none() {
+            instructions.push(
+                spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+                    &payer.payer_pubkey(),
+                    &recipient_owner,
+                    &mint,
+                    &token_program_id,
+                ),
+            );
+        }
+        instructions.push(self.build_mint_to(
+            token_program_id,
+            token_account,
+            mint,
+            mint_authority.pubkey(),
+            amount,
+        ));
+
+        let budget = ComputeBudgetConfig::with_unit_price(COMPUTE_BUDGET_PRICE);
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), mint_authority];
+        self.compiling_process_transaction_with_budget(
+            &instructions,
+            &payer.payer_pubkey(),
+            &signers,
+            &[],
+            &budget,
+        )
+    }
+
+    /// Creates the recipient's account first if missing.
+    fn process_transfer_checked_to_owner(
+        &self,
+        payer: &impl FeePayer,
+        source: Pubkey,
+        recipient_owner: Pubkey,
+        authority: &impl Signer,
+        amount: u64,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetAccount
+            + GetLatestBlockhash
+            + SimulateTransaction<ExecutionOutput>
+            + ProcessTransaction<ExecutionOutput>,
+    {
+        let source_account = self.try_get_token_account(&source)?;
+        let mint_account = self.try_get_mint(&source_account.mint)?;
+        let mint = mint_account.key();
+        let token_program_id = *ReadableAccount::owner(&mint_account);
+        let destination =
+            self.get_associated_token_address(&token_program_id, &recipient_owner, &mint);
+
+        let mut instructions = Vec::with_capacity(2);
+        if self.get_account(&destination)?.is_none() {
+            instructions.push(
+                spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+                    &payer.payer_pubkey(),
+                    &recipient_owner,
+                    &mint,
+                    &token_program_id,
+                ),
+            );
+        }
+
+        let mut transfer_instruction = self.build_transfer_checked(
+            token_program_id,
+            source,
+            mint,
+            destination,
+            authority.pubkey(),
+            &[],
+            amount,
+            mint_account.decimals,
         );
-        let signers: Vec<&dyn Signer> = vec![payer];
-        self.compiling_process_transaction(&instructions, &payer.pubkey(), &signers, &[])
+        self.add_transfer_hook_account_metas(
+            &mut transfer_instruction,
+            source,
+            mint,
+            destination,
+            authority.pubkey(),
+            amount,
+        )?;
+        instructions.push(transfer_instruction);
+
+        let budget = ComputeBudgetConfig::with_unit_price(COMPUTE_BUDGET_PRICE);
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), authority];
+        self.compiling_process_transaction_with_budget(
+            &instructions,
+            &payer.payer_pubkey(),
+            &signers,
+            &[],
+            &budget,
+        )
     }
 }
 
@@ -1,4 +1,12 @@
+pub mod amount;
 pub mod associated_token;
 pub mod associated_token_interface;
+pub mod memo;
+#[cfg(feature = "metadata")]
+pub mod metadata;
+pub mod reclaim;
+#[cfg(feature = "stake-pool")]
+pub mod stake_pool;
 pub mod token;
+pub mod token_extension;
 pub mod token_interface;
@@ -0,0 +1,112 @@
+use solana_sdk::signer::Signer;
+
+use dexter_client_api::base::executor::{ProcessTransaction, SimulateTransaction};
+use dexter_client_api::base::getter::GetLatestBlockhash;
+use dexter_client_api::errors::ClientResult;
+use dexter_client_api::execution::ExecutionOutput;
+use dexter_client_api::exts::executor::CompilingProcessTransaction;
+use dexter_client_api::exts::signer::FeePayer;
+use dexter_client_api::Client;
+
+pub use dexter_client_sys::memo::MemoInstruction;
+
+const MEMO_LOG_PREFIX: &str = "Program log: Memo (len ";
+
+pub trait MemoProcessor: Client {
+    /// Every signer is also required to sign the memo.
+    fn process_memo(
+        &self,
+        payer: &impl FeePayer,
+        memo: &str,
+        signers: &[&dyn Signer],
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetLatestBlockhash
+            + SimulateTransaction<ExecutionOutput>
+            + ProcessTransaction<ExecutionOutput>,
+    {
+        let signer_pubkeys: Vec<_> = signers.iter().map(|signer| signer.pubkey()).collect();
+        let instructions = [self.build_memo(memo, &signer_pubkeys)];
+
+        let mut all_signers: Vec<&dyn Signer> = vec![payer.payer()];
+        all_signers.extend_from_slice(signers);
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &all_signers, &[])
+    }
+}
+
+impl<C: ?Sized + Client> MemoProcessor for C {}
+
+/// In execution order.
+pub fn get_memos(output: &ExecutionOutput) -> Vec<String> {
+    let memo_program_ids = [spl_memo::id().to_string(), spl_memo::v1::id().to_string()];
+
+    let mut invocations: Vec<&str> = Vec::new();
+    let mut memos = Vec::new();
+    for log in &output.logs {
+        let mut words = log.split_whitespace();
+        if let (Some("Program"), Some(program_id), Some(status), depth) =
+            (words.next(), words.next(), words.next(), words.next())
+        {
+            match status {
+                "invoke" if depth.is_some_and(|depth| depth.starts_with('[')) => {
+                    invocations.push(program_id)
+                }
+                "success" if depth.is_none() => {
+                    invocations.pop();
+                }
+                "failed:" => {
+                    invocations.pop();
+                }
+                _ => {}
+            }
+        }
+
+        let in_memo_program = invocations
+            .last()
+            .is_some_and(|program_id| memo_program_ids.iter().any(|id| id == program_id));
+        if !in_memo_program {
+            continue;
+        }
+
+        if let Some(memo) = log
+            .strip_prefix(MEMO_LOG_PREFIX)
+            .and_then(|rest| rest.split_once("): "))
+            .and_then(|(_, quoted)| unescape_debug_str(quoted))
+        {
+            memos.push(memo);
+        }
+    }
+
+    memos
+}
+
+// reverses the `{:?}` formatting the memo program applies to its log
+fn unescape_debug_str(quoted: &str) -> Option<String> {
+    let inner = quoted.strip_prefix('"')?.strip_suffix('"')?;
+
+    let mut unescaped = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+
+        match chars.next()? {
+            'n' => unescaped.push('\n'),
+            'r' => unescaped.push('\r'),
+            't' => unescaped.push('\t'),
+            '0' => unescaped.push('\0'),
+            '\\' => unescaped.push('\\'),
+            '"' => unescaped.push('"'),
+            '\'' => unescaped.push('\''),
+            'u' => {
+                let code: String = chars.by_ref().skip(1).take_while(|c| *c != '}').collect();
+                unescaped.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+            }
+            _ => return None,
+        }
+    }
+
+    Some(unescaped)
+}
@@ -0,0 +1,98 @@
+use solana_sdk::account::Account;
+use solana_sdk::pubkey::Pubkey;
+
+use anchor_lang::AnchorSerialize;
+use anchor_spl::metadata::mpl_token_metadata;
+use anchor_spl::metadata::mpl_token_metadata::accounts::Metadata;
+use anchor_spl::metadata::mpl_token_metadata::types::Key;
+use anchor_spl::metadata::MetadataAccount;
+
+use dexter_client_anchor::{AnchorAccount, AnchorGetter};
+use dexter_client_api::base::getter::GetAccount;
+use dexter_client_api::base::setter::{HasRent, SetAccount};
+use dexter_client_api::errors::ClientResult;
+use dexter_client_api::Client;
+
+// metadata accounts are allocated with the maximum size regardless of their contents
+const METADATA_LEN: usize = 679;
+
+pub trait MetadataGetter: Client {
+    fn get_metadata_address(&self, mint: &Pubkey) -> Pubkey {
+        Metadata::find_pda(mint).0
+    }
+
+    fn get_metadata(&self, mint: &Pubkey) -> ClientResult<Option<AnchorAccount<MetadataAccount>>>
+    where
+        Self: GetAccount,
+    {
+        self.get_anchor_account(&self.get_metadata_address(mint))
+    }
+
+    fn try_get_metadata(&self, mint: &Pubkey) -> ClientResult<AnchorAccount<MetadataAccount>>
+    where
+        Self: GetAccount,
+    {
+        self.try_get_anchor_account(&self.get_metadata_address(mint))
+    }
+}
+
+impl<C: ?Sized + Client> MetadataGetter for C {}
+
+pub trait MetadataSetter: Client {
+    fn set_metadata(
+        &mut self,
+        mint: Pubkey,
+        update_authority: Pubkey,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> AnchorAccount<MetadataAccount>
+    where
+        Self: SetAccount + HasRent,
+    {
+        let metadata = Metadata {
+            key: Key::MetadataV1,
+            update_authority,
+            mint,
+            name,
+            symbol,
+            uri,
+            seller_fee_basis_points: 0,
+            creators: None,
+            primary_sale_happened: false,
+            is_mutable: true,
+            edition_nonce: None,
+            token_standard: None,
+            collection: None,
+            uses: None,
+            collection_details: None,
+            programmable_config: None,
+        };
+
+        self.set_metadata_account(&metadata)
+    }
+
+    fn set_metadata_account(&mut self, metadata: &Metadata) -> AnchorAccount<MetadataAccount>
+    where
+        Self: SetAccount + HasRent,
+    {
+        let metadata_pk = self.get_metadata_address(&metadata.mint);
+
+        let mut data = metadata.try_to_vec().unwrap();
+        data.resize(data.len().max(METADATA_LEN), 0);
+
+        let account = Account {
+            lamports: self.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: mpl_token_metadata::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        };
+
+        self.set_account(metadata_pk, account.clone());
+
+        AnchorAccount::try_from_account(metadata_pk, account).unwrap()
+    }
+}
+
+impl<C: ?Sized + Client> MetadataSetter for C {}
@@ -0,0 +1,195 @@
+use solana_sdk::account::ReadableAccount;
+use solana_sdk::address_lookup_table::instruction::{close_lookup_table, deactivate_lookup_table};
+use solana_sdk::address_lookup_table::state::LookupTableStatus;
+use solana_sdk::bpf_loader_upgradeable;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::nonce;
+use solana_sdk::program_option::COption;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_instruction;
+use solana_sdk::system_program;
+
+use anchor_lang::Key;
+
+use dexter_client_api::base::getter::{
+    DataSlice, GetAccount, GetMultipleAccounts, GetProgramAccounts, Memcmp, ProgramAccountsFilter,
+};
+use dexter_client_api::errors::ClientResult;
+use dexter_client_api::Client;
+use dexter_client_sys::address_lookup_table::AddressLookupTableGetter;
+use dexter_client_sys::sysvar::SysvarGetter;
+
+use crate::token_interface::{TokenInterfaceGetter, TokenInterfaceInstruction};
+
+const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+// version tag of the nonce state followed by the authority of an initialized one
+const NONCE_INITIALIZED_OFFSET: usize = 4;
+const NONCE_INITIALIZED_TAG: [u8; 4] = [1, 0, 0, 0];
+
+// buffer tag of the upgradeable loader state followed by `Some` of the authority
+const BUFFER_WITH_AUTHORITY_TAG: [u8; 5] = [1, 0, 0, 0, 1];
+
+const LAMPORTS_ONLY: DataSlice = DataSlice {
+    offset: 0,
+    length: 0,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReclaimableKind {
+    TokenAccount,
+    LookupTable,
+    NonceAccount,
+    ProgramBuffer,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReclaimableAccount {
+    pub pubkey: Pubkey,
+    pub kind: ReclaimableKind,
+    pub lamports: u64,
+    // `None` for a lookup table cooling down after its deactivation
+    pub instruction: Option<Instruction>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RentAudit {
+    pub wallet: Pubkey,
+    pub accounts: Vec<ReclaimableAccount>,
+}
+
+impl RentAudit {
+    pub fn total_lamports(&self) -> u64 {
+        self.accounts.iter().map(|account| account.lamports).sum()
+    }
+
+    pub fn instructions(&self) -> Vec<Instruction> {
+        self.accounts
+            .iter()
+            .filter_map(|account| account.instruction.clone())
+            .collect()
+    }
+}
+
+pub trait RentAuditGetter: Client {
+    /// Covers empty token accounts, lookup tables, nonce accounts and program buffers.
+    fn audit_reclaimable_rent(&self, wallet: &Pubkey) -> ClientResult<RentAudit>
+    where
+        Self: GetAccount + GetProgramAccounts + GetMultipleAccounts,
+    {
+        let mut accounts = Vec::new();
+
+        for owned in self.get_token_accounts_for_owner(wallet)? {
+            let token_account = &owned.token_account;
+
+            // wrapped SOL is unwrapped on close, so only its rent-exempt reserve is reclaimed
+            let is_empty = token_account.amount == 0 || token_account.is_native();
+            let close_authority = token_account.close_authority.unwrap_or(token_account.owner);
+            if !is_empty || close_authority != *wallet || token_account.is_frozen() {
+                continue;
+            }
+
+            let lamports = match token_account.is_native {
+                COption::Some(rent_exempt_reserve) => rent_exempt_reserve,
+                COption::None => token_account.lamports(),
+            };
+
+            accounts.push(ReclaimableAccount {
+                pubkey: token_account.key(),
+                kind: ReclaimableKind::TokenAccount,
+                lamports,
+                instruction: Some(self.build_close_account(
+                    owned.token_program_id,
+                    token_account.key(),
+                    *wallet,
+                    *wallet,
+                )),
+            });
+        }
+
+        let lookup_tables = self.get_address_lookup_tables_for_authority(wallet)?;
+        if !lookup_tables.is_empty() {
+            let current_slot = self.try_get_sysvar_clock()?.slot;
+            let slot_hashes = self.try_get_sysvar_slothashes()?;
+
+            let pubkeys: Vec<Pubkey> = lookup_tables.iter().map(|(pubkey, _)| *pubkey).collect();
+            let mut lamports = Vec::with_capacity(pubkeys.len());
+            for chunk in pubkeys.chunks(MAX_MULTIPLE_ACCOUNTS) {
+                let chunk_accounts = self.get_multiple_accounts_sliced(chunk, LAMPORTS_ONLY)?;
+                lamports.extend(
+                    chunk_accounts
+                        .into_iter()
+                        .map(|account| account.map_or(0, |account| account.lamports)),
+                );
+            }
+
+            for ((pubkey, lookup_table), lamports) in lookup_tables.into_iter().zip(lamports) {
+                let instruction = match lookup_table.meta.status(current_slot, &slot_hashes) {
+                    LookupTableStatus::Activated => Some(deactivate_lookup_table(pubkey, *wallet)),
+                    LookupTableStatus::Deactivating { .. } => None,
+                    LookupTableStatus::Deactivated => {
+                        Some(close_lookup_table(pubkey, *wallet, *wallet))
+                    }
+                };
+
+                accounts.push(ReclaimableAccount {
+                    pubkey,
+                    kind: ReclaimableKind::LookupTable,
+                    lamports,
+                    instruction,
+                });
+            }
+        }
+
+        let mut nonce_filter = NONCE_INITIALIZED_TAG.to_vec();
+        nonce_filter.extend_from_slice(wallet.as_ref());
+        let filters = vec![
+            ProgramAccountsFilter::DataSize(nonce::State::size() as u64),
+            ProgramAccountsFilter::Memcmp(Memcmp::new_base58_encoded(
+                NONCE_INITIALIZED_OFFSET,
+                &nonce_filter,
+            )),
+        ];
+        for (pubkey, account) in
+            self.get_program_accounts_sliced(&system_program::id(), Some(filters), LAMPORTS_ONLY)?
+        {
+            accounts.push(ReclaimableAccount {
+                pubkey,
+                kind: ReclaimableKind::NonceAccount,
+                lamports: account.lamports,
+                instruction: Some(system_instruction::withdraw_nonce_account(
+                    &pubkey,
+                    wallet,
+                    wallet,
+                    account.lamports,
+                )),
+            });
+        }
+
+        let mut buffer_filter = BUFFER_WITH_AUTHORITY_TAG.to_vec();
+        buffer_filter.extend_from_slice(wallet.as_ref());
+        let filters = vec![ProgramAccountsFilter::Memcmp(Memcmp::new_base58_encoded(
+            0,
+            &buffer_filter,
+        ))];
+        for (pubkey, account) in self.get_program_accounts_sliced(
+            &bpf_loader_upgradeable::id(),
+            Some(filters),
+            LAMPORTS_ONLY,
+        )? {
+            accounts.push(ReclaimableAccount {
+                pubkey,
+                kind: ReclaimableKind::ProgramBuffer,
+                lamports: account.lamports,
+                instruction: Some(bpf_loader_upgradeable::close(&pubkey, wallet, wallet)),
+            });
+        }
+
+        Ok(RentAudit {
+            wallet: *wallet,
+            accounts,
+        })
+    }
+}
+
+impl<C: ?Sized + Client> RentAuditGetter for C {}
@@ -0,0 +1,189 @@
+use solana_sdk::account::ReadableAccount;
+use solana_sdk::borsh1::try_from_slice_unchecked;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+
+use spl_stake_pool::state::{StakePool, ValidatorList};
+
+use dexter_client_api::base::getter::GetAccount;
+use dexter_client_api::errors::{ClientError, ClientResult};
+use dexter_client_api::Client;
+
+pub trait StakePoolGetter: Client {
+    fn get_stake_pool_withdraw_authority(
+        &self,
+        stake_pool_program_id: &Pubkey,
+        stake_pool: &Pubkey,
+    ) -> Pubkey {
+        spl_stake_pool::find_withdraw_authority_program_address(stake_pool_program_id, stake_pool).0
+    }
+
+    /// Pools may be deployed under programs other than the canonical one.
+    fn try_get_stake_pool_program_id(&self, stake_pool: &Pubkey) -> ClientResult<Pubkey>
+    where
+        Self: GetAccount,
+    {
+        let account = self
+            .get_account(stake_pool)?
+            .ok_or(ClientError::AccountNotFound(*stake_pool))?;
+        Ok(*account.owner())
+    }
+
+    fn get_stake_pool(&self, stake_pool: &Pubkey) -> ClientResult<Option<StakePool>>
+    where
+        Self: GetAccount,
+    {
+        let Some(account) = self.get_account(stake_pool)? else {
+            return Ok(None);
+        };
+
+        try_from_slice_unchecked::<StakePool>(&account.data)
+            .ok()
+            .filter(StakePool::is_valid)
+            .map(Some)
+            .ok_or(ClientError::AccountDidNotDeserialize(*stake_pool))
+    }
+
+    fn try_get_stake_pool(&self, stake_pool: &Pubkey) -> ClientResult<StakePool>
+    where
+        Self: GetAccount,
+    {
+        self.get_stake_pool(stake_pool)?
+            .ok_or(ClientError::AccountNotFound(*stake_pool))
+    }
+
+    fn get_validator_list(&self, validator_list: &Pubkey) -> ClientResult<Option<ValidatorList>>
+    where
+        Self: GetAccount,
+    {
+        let Some(account) = self.get_account(validator_list)? else {
+            return Ok(None);
+        };
+
+        // the list is allocated for the maximum number of validators, so trailing bytes are expected
+        try_from_slice_unchecked::<ValidatorList>(&account.data)
+            .ok()
+            .filter(ValidatorList::is_valid)
+            .map(Some)
+            .ok_or(ClientError::AccountDidNotDeserialize(*validator_list))
+    }
+
+    fn try_get_validator_list(&self, validator_list: &Pubkey) -> ClientResult<ValidatorList>
+    where
+        Self: GetAccount,
+    {
+        self.get_validator_list(validator_list)?
+            .ok_or(ClientError::AccountNotFound(*validator_list))
+    }
+
+    /// Lamports backing one pool token, as of the last epoch update of the pool.
+    fn get_stake_pool_exchange_rate(&self, stake_pool: &Pubkey) -> ClientResult<f64>
+    where
+        Self: GetAccount,
+    {
+        let stake_pool = self.try_get_stake_pool(stake_pool)?;
+        if stake_pool.pool_token_supply == 0 {
+            return Ok(1.0);
+        }
+
+        Ok(stake_pool.total_lamports as f64 / stake_pool.pool_token_supply as f64)
+    }
+}
+
+impl<C: ?Sized + Client> StakePoolGetter for C {}
+
+pub trait StakePoolInstruction: Client {
+    /// The sol deposit authority of the pool, if any, signs as well.
+    fn build_deposit_sol(
+        &self,
+        stake_pool_program_id: Pubkey,
+        stake_pool_address: Pubkey,
+        stake_pool: &StakePool,
+        lamports_from: Pubkey,
+        pool_tokens_to: Pubkey,
+        lamports: u64,
+    ) -> Instruction {
+        let withdraw_authority =
+            self.get_stake_pool_withdraw_authority(&stake_pool_program_id, &stake_pool_address);
+
+        match stake_pool.sol_deposit_authority {
+            Some(sol_deposit_authority) => spl_stake_pool::instruction::deposit_sol_with_authority(
+                &stake_pool_program_id,
+                &stake_pool_address,
+                &sol_deposit_authority,
+                &withdraw_authority,
+                &stake_pool.reserve_stake,
+                &lamports_from,
+                &pool_tokens_to,
+                &stake_pool.manager_fee_account,
+                &pool_tokens_to,
+                &stake_pool.pool_mint,
+                &stake_pool.token_program_id,
+                lamports,
+            ),
+            None => spl_stake_pool::instruction::deposit_sol(
+                &stake_pool_program_id,
+                &stake_pool_address,
+                &withdraw_authority,
+                &stake_pool.reserve_stake,
+                &lamports_from,
+                &pool_tokens_to,
+                &stake_pool.manager_fee_account,
+                &pool_tokens_to,
+                &stake_pool.pool_mint,
+                &stake_pool.token_program_id,
+                lamports,
+            ),
+        }
+    }
+
+    /// The sol withdraw authority of the pool, if any, signs as well.
+    #[allow(clippy::too_many_arguments)]
+    fn build_withdraw_sol(
+        &self,
+        stake_pool_program_id: Pubkey,
+        stake_pool_address: Pubkey,
+        stake_pool: &StakePool,
+        user_transfer_authority: Pubkey,
+        pool_tokens_from: Pubkey,
+        lamports_to: Pubkey,
+        pool_tokens: u64,
+    ) -> Instruction {
+        let withdraw_authority =
+            self.get_stake_pool_withdraw_authority(&stake_pool_program_id, &stake_pool_address);
+
+        match stake_pool.sol_withdraw_authority {
+            Some(sol_withdraw_authority) => {
+                spl_stake_pool::instruction::withdraw_sol_with_authority(
+                    &stake_pool_program_id,
+                    &stake_pool_address,
+                    &sol_withdraw_authority,
+                    &withdraw_authority,
+                    &user_transfer_authority,
+                    &pool_tokens_from,
+                    &stake_pool.reserve_stake,
+                    &lamports_to,
+                    &stake_pool.manager_fee_account,
+                    &stake_pool.pool_mint,
+                    &stake_pool.token_program_id,
+                    pool_tokens,
+                )
+            }
+            None => spl_stake_pool::instruction::withdraw_sol(
+                &stake_pool_program_id,
+                &stake_pool_address,
+                &withdraw_authority,
+                &user_transfer_authority,
+                &pool_tokens_from,
+                &stake_pool.reserve_stake,
+                &lamports_to,
+                &stake_pool.manager_fee_account,
+                &stake_pool.pool_mint,
+                &stake_pool.token_program_id,
+                pool_tokens,
+            ),
+        }
+    }
+}
+
+impl<C: ?Sized + Client> StakePoolInstruction for C {}
@@ -1,9 +1,12 @@
 use solana_sdk::instruction::Instruction;
+use solana_sdk::program_pack::Pack;
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 use solana_sdk::system_instruction;
 
 use anchor_lang::AccountDeserialize;
+use anchor_spl::token::spl_token::instruction::AuthorityType;
 use anchor_spl::token::{spl_token, Mint, TokenAccount};
 
 use dexter_client_anchor::{AnchorAccount, AnchorGetter};
@@ -13,12 +16,39 @@ use dexter_client_api::base::getter::{
     ProgramAccountsFilter,
 };
 use dexter_client_api::base::setter::{HasRent, SetAccount};
-use dexter_client_api::errors::ClientResult;
+use dexter_client_api::errors::{ClientError, ClientResult};
 use dexter_client_api::execution::ExecutionOutput;
 use dexter_client_api::exts::executor::CompilingProcessTransaction;
+use dexter_client_api::exts::signer::FeePayer;
 use dexter_client_api::Client;
 use dexter_client_sys::pack::PackingSetter;
 
+use crate::amount::{self, Rounding};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TokenAccountOptions {
+    pub delegate: Option<(Pubkey, u64)>,
+    pub frozen: bool,
+    pub close_authority: Option<Pubkey>,
+}
+
+impl TokenAccountOptions {
+    pub fn with_delegate(mut self, delegate: Pubkey, delegated_amount: u64) -> Self {
+        self.delegate = Some((delegate, delegated_amount));
+        self
+    }
+
+    pub fn frozen(mut self) -> Self {
+        self.frozen = true;
+        self
+    }
+
+    pub fn with_close_authority(mut self, close_authority: Pubkey) -> Self {
+        self.close_authority = Some(close_authority);
+        self
+    }
+}
+
 pub trait TokenGetter: Client {
     fn get_mint(&self, mint: &Pubkey) -> ClientResult<Option<AnchorAccount<Mint>>>
     where
@@ -109,6 +139,59 @@ pub trait TokenGetter: Client {
     {
         self.try_get_mint(mint).map(|tm| tm.supply)
     }
+
+    fn amount_to_ui_amount(&self, mint: &Pubkey, amount: u64) -> ClientResult<f64>
+    where
+        Self: GetAccount,
+    {
+        let decimals = self.try_get_mint(mint)?.decimals;
+        Ok(spl_token::amount_to_ui_amount(amount, decimals))
+    }
+
+    /// Exact counterpart of [`TokenGetter::amount_to_ui_amount`].
+    fn amount_to_ui_amount_string(&self, mint: &Pubkey, amount: u64) -> ClientResult<String>
+    where
+        Self: GetAccount,
+    {
+        let decimals = self.try_get_mint(mint)?.decimals;
+        Ok(spl_token::amount_to_ui_amount_string_trimmed(
+            amount, decimals,
+        ))
+    }
+
+    fn ui_amount_to_amount(
+        &self,
+        mint: &Pubkey,
+        ui_amount: &str,
+        rounding: Rounding,
+    ) -> ClientResult<u64>
+    where
+        Self: GetAccount,
+    {
+        let decimals = self.try_get_mint(mint)?.decimals;
+        Ok(amount::ui_amount_to_amount(ui_amount, decimals, rounding)?)
+    }
+
+    fn get_multisig(&self, multisig: &Pubkey) -> ClientResult<Option<spl_token::state::Multisig>>
+    where
+        Self: GetAccount,
+    {
+        let Some(account) = self.get_account(multisig)? else {
+            return Ok(None);
+        };
+
+        spl_token::state::Multisig::unpack(&account.data)
+            .map(Some)
+            .map_err(|_| ClientError::AccountDidNotDeserialize(*multisig))
+    }
+
+    fn try_get_multisig(&self, multisig: &Pubkey) -> ClientResult<spl_token::state::Multisig>
+    where
+        Self: GetAccount,
+    {
+        self.get_multisig(multisig)?
+            .ok_or(ClientError::AccountNotFound(*multisig))
+    }
 }
 
 impl<C: ?Sized + Client> TokenGetter for C {}
@@ -177,6 +260,180 @@ pub trait TokenInstruction: Client {
         .unwrap()
     }
 
+    fn build_burn(
+        &self,
+        token_account: &Pubkey,
+        mint: &Pubkey,
+        authority: &Pubkey,
+        amount: u64,
+    ) -> Instruction {
+        spl_token::instruction::burn(
+            &spl_token::id(),
+            token_account,
+            mint,
+            authority,
+            &[],
+            amount,
+        )
+        .unwrap()
+    }
+
+    fn build_initialize_multisig(
+        &self,
+        multisig: &Pubkey,
+        signers: &[Pubkey],
+        m: u8,
+    ) -> Instruction {
+        spl_token::instruction::initialize_multisig(
+            &spl_token::id(),
+            multisig,
+            &signers.iter().collect::<Vec<_>>(),
+            m,
+        )
+        .unwrap()
+    }
+
+    fn build_mint_to_multisig(
+        &self,
+        token_account: &Pubkey,
+        mint: &Pubkey,
+        multisig_authority: &Pubkey,
+        signers: &[Pubkey],
+        amount: u64,
+    ) -> Instruction {
+        spl_token::instruction::mint_to(
+            &spl_token::id(),
+            mint,
+            token_account,
+            multisig_authority,
+            &signers.iter().collect::<Vec<_>>(),
+            amount,
+        )
+        .unwrap()
+    }
+
+    fn build_transfer_multisig(
+        &self,
+        source: &Pubkey,
+        destination: &Pubkey,
+        multisig_authority: &Pubkey,
+        signers: &[Pubkey],
+        amount: u64,
+    ) -> Instruction {
+        spl_token::instruction::transfer(
+            &spl_token::id(),
+            source,
+            destination,
+            multisig_authority,
+            &signers.iter().collect::<Vec<_>>(),
+            amount,
+        )
+        .unwrap()
+    }
+
+    fn build_approve(
+        &self,
+        source: &Pubkey,
+        delegate: &Pubkey,
+        owner: &Pubkey,
+        amount: u64,
+    ) -> Instruction {
+        spl_token::instruction::approve(&spl_token::id(), source, delegate, owner, &[], amount)
+            .unwrap()
+    }
+
+    fn build_approve_checked(
+        &self,
+        source: &Pubkey,
+        mint: &Pubkey,
+        delegate: &Pubkey,
+        owner: &Pubkey,
+        amount: u64,
+        decimals: u8,
+    ) -> Instruction {
+        spl_token::instruction::approve_checked(
+            &spl_token::id(),
+            source,
+            mint,
+            delegate,
+            owner,
+            &[],
+            amount,
+            decimals,
+        )
+        .unwrap()
+    }
+
+    fn build_revoke(&self, source: &Pubkey, owner: &Pubkey) -> Instruction {
+        spl_token::instruction::revoke(&spl_token::id(), source, owner, &[]).unwrap()
+    }
+
+    fn build_freeze_account(
+        &self,
+        token_account: &Pubkey,
+        mint: &Pubkey,
+        freeze_authority: &Pubkey,
+    ) -> Instruction {
+        spl_token::instruction::freeze_account(
+            &spl_token::id(),
+            token_account,
+            mint,
+            freeze_authority,
+            &[],
+        )
+        .unwrap()
+    }
+
+    fn build_thaw_account(
+        &self,
+        token_account: &Pubkey,
+        mint: &Pubkey,
+        freeze_authority: &Pubkey,
+    ) -> Instruction {
+        spl_token::instruction::thaw_account(
+            &spl_token::id(),
+            token_account,
+            mint,
+            freeze_authority,
+            &[],
+        )
+        .unwrap()
+    }
+
+    fn build_set_authority(
+        &self,
+        owned: &Pubkey,
+        new_authority: Option<&Pubkey>,
+        authority_type: AuthorityType,
+        current_authority: &Pubkey,
+    ) -> Instruction {
+        spl_token::instruction::set_authority(
+            &spl_token::id(),
+            owned,
+            new_authority,
+            authority_type,
+            current_authority,
+            &[],
+        )
+        .unwrap()
+    }
+
+    fn build_close_account(
+        &self,
+        token_account: &Pubkey,
+        destination: &Pubkey,
+        authority: &Pubkey,
+    ) -> Instruction {
+        spl_token::instruction::close_account(
+            &spl_token::id(),
+            token_account,
+            destination,
+            authority,
+            &[],
+        )
+        .unwrap()
+    }
+
     fn build_create_and_initialize_mint(
         &self,
         payer: &Pubkey,
@@ -231,6 +488,32 @@ pub trait TokenInstruction: Client {
 
         Ok(instructions)
     }
+
+    fn build_create_and_initialize_multisig(
+        &self,
+        payer: &Pubkey,
+        multisig: &Pubkey,
+        signers: &[Pubkey],
+        m: u8,
+    ) -> ClientResult<[Instruction; 2]>
+    where
+        Self: GetMinimumBalanceForRentExemption,
+    {
+        let multisig_len = spl_token::state::Multisig::LEN;
+
+        let instructions = [
+            system_instruction::create_account(
+                payer,
+                multisig,
+                self.get_minimum_balance_for_rent_exemption(multisig_len)?,
+                multisig_len as u64,
+                &spl_token::id(),
+            ),
+            self.build_initialize_multisig(multisig, signers, m),
+        ];
+
+        Ok(instructions)
+    }
 }
 
 impl<C: ?Sized + Client> TokenInstruction for C {}
@@ -238,7 +521,7 @@ impl<C: ?Sized + Client> TokenInstruction for C {}
 pub trait TokenProcessor: Client {
     fn process_create_and_initialize_mint(
         &self,
-        payer: &impl Signer,
+        payer: &impl FeePayer,
         mint: &impl Signer,
         mint_authority: &Pubkey,
         freeze_authority: Option<&Pubkey>,
@@ -250,19 +533,19 @@ pub trait TokenProcessor: Client {
             + ProcessTransaction<ExecutionOutput>,
     {
         let instructions = self.build_create_and_initialize_mint(
-            &payer.pubkey(),
+            &payer.payer_pubkey(),
             &mint.pubkey(),
             mint_authority,
             freeze_authority,
             decimals,
         )?;
-        let signers: Vec<&dyn Signer> = vec![payer, mint];
-        self.compiling_process_transaction(&instructions, &payer.pubkey(), &signers, &[])
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), mint];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
     }
 
     fn process_create_and_initialize_token_account(
         &self,
-        payer: &impl Signer,
+        payer: &impl FeePayer,
         token_account: &impl Signer,
         mint: &Pubkey,
         owner: &Pubkey,
@@ -273,18 +556,18 @@ pub trait TokenProcessor: Client {
             + ProcessTransaction<ExecutionOutput>,
     {
         let instructions = self.build_create_and_initialize_token_account(
-            &payer.pubkey(),
+            &payer.payer_pubkey(),
             &token_account.pubkey(),
             mint,
             owner,
         )?;
-        let signers: Vec<&dyn Signer> = vec![payer, token_account];
-        self.compiling_process_transaction(&instructions, &payer.pubkey(), &signers, &[])
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), token_account];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
     }
 
     fn process_mint_to(
         &self,
-        payer: &impl Signer,
+        payer: &impl FeePayer,
         token_account: &Pubkey,
         mint: &Pubkey,
         mint_authority: &impl Signer,
@@ -295,13 +578,13 @@ pub trait TokenProcessor: Client {
     {
         let instructions =
             [self.build_mint_to(token_account, mint, &mint_authority.pubkey(), amount)];
-        let signers: Vec<&dyn Signer> = vec![payer, mint_authority];
-        self.compiling_process_transaction(&instructions, &payer.pubkey(), &signers, &[])
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), mint_authority];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
     }
 
     fn process_transfer_token(
         &self,
-        payer: &impl Signer,
+        payer: &impl FeePayer,
         source: &Pubkey,
         destination: &Pubkey,
         source_authority: &impl Signer,
@@ -312,13 +595,13 @@ pub trait TokenProcessor: Client {
     {
         let instructions =
             [self.build_transfer(source, destination, &source_authority.pubkey(), amount)];
-        let signers: Vec<&dyn Signer> = vec![payer, source_authority];
-        self.compiling_process_transaction(&instructions, &payer.pubkey(), &signers, &[])
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), source_authority];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
     }
 
     fn process_wrap_native(
         &self,
-        payer: &impl Signer,
+        payer: &impl FeePayer,
         source: &impl Signer,
         destination: &Pubkey,
         lamports: u64,
@@ -330,8 +613,313 @@ pub trait TokenProcessor: Client {
             system_instruction::transfer(&source.pubkey(), destination, lamports),
             spl_token::instruction::sync_native(&spl_token::id(), destination).unwrap(),
         ];
-        let signers: Vec<&dyn Signer> = vec![payer, source];
-        self.compiling_process_transaction(&instructions, &payer.pubkey(), &signers, &[])
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), source];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_unwrap_native(
+        &self,
+        payer: &impl FeePayer,
+        token_account: &Pubkey,
+        recipient: &Pubkey,
+        owner: &impl Signer,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let instructions = [self.build_close_account(token_account, recipient, &owner.pubkey())];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), owner];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    /// Runs the instructions on a temporary wSOL account funded with `lamports`, closed at the end.
+    fn process_with_ephemeral_wrapped_native<F>(
+        &self,
+        payer: &impl FeePayer,
+        owner: &impl Signer,
+        lamports: u64,
+        build_instructions: F,
+        signers: &[&dyn Signer],
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetMinimumBalanceForRentExemption
+            + GetLatestBlockhash
+            + ProcessTransaction<ExecutionOutput>,
+        F: FnOnce(&Pubkey) -> Vec<Instruction>,
+    {
+        let wrapped = Keypair::new();
+
+        let mut instructions = vec![
+            system_instruction::create_account(
+                &owner.pubkey(),
+                &wrapped.pubkey(),
+                self.get_minimum_balance_for_rent_exemption(TokenAccount::LEN)? + lamports,
+                TokenAccount::LEN as u64,
+                &spl_token::id(),
+            ),
+            self.build_initialize_account(
+                &wrapped.pubkey(),
+                &spl_token::native_mint::id(),
+                &owner.pubkey(),
+            ),
+        ];
+        instructions.extend(build_instructions(&wrapped.pubkey()));
+        instructions.push(self.build_close_account(
+            &wrapped.pubkey(),
+            &owner.pubkey(),
+            &owner.pubkey(),
+        ));
+
+        let mut all_signers: Vec<&dyn Signer> = vec![payer.payer(), owner, &wrapped];
+        all_signers.extend_from_slice(signers);
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &all_signers, &[])
+    }
+
+    fn process_create_and_initialize_multisig(
+        &self,
+        payer: &impl FeePayer,
+        multisig: &impl Signer,
+        signers: &[Pubkey],
+        m: u8,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetMinimumBalanceForRentExemption
+            + GetLatestBlockhash
+            + ProcessTransaction<ExecutionOutput>,
+    {
+        let instructions = self.build_create_and_initialize_multisig(
+            &payer.payer_pubkey(),
+            &multisig.pubkey(),
+            signers,
+            m,
+        )?;
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), multisig];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_mint_to_multisig(
+        &self,
+        payer: &impl FeePayer,
+        token_account: &Pubkey,
+        mint: &Pubkey,
+        multisig_authority: &Pubkey,
+        signers: &[&dyn Signer],
+        amount: u64,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let signer_pubkeys: Vec<_> = signers.iter().map(|signer| signer.pubkey()).collect();
+        let instructions = [self.build_mint_to_multisig(
+            token_account,
+            mint,
+            multisig_authority,
+            &signer_pubkeys,
+            amount,
+        )];
+
+        let mut all_signers = vec![payer.payer()];
+        all_signers.extend_from_slice(signers);
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &all_signers, &[])
+    }
+
+    fn process_transfer_token_multisig(
+        &self,
+        payer: &impl FeePayer,
+        source: &Pubkey,
+        destination: &Pubkey,
+        multisig_authority: &Pubkey,
+        signers: &[&dyn Signer],
+        amount: u64,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let signer_pubkeys: Vec<_> = signers.iter().map(|signer| signer.pubkey()).collect();
+        let instructions = [self.build_transfer_multisig(
+            source,
+            destination,
+            multisig_authority,
+            &signer_pubkeys,
+            amount,
+        )];
+
+        let mut all_signers = vec![payer.payer()];
+        all_signers.extend_from_slice(signers);
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &all_signers, &[])
+    }
+
+    fn process_approve(
+        &self,
+        payer: &impl FeePayer,
+        source: &Pubkey,
+        delegate: &Pubkey,
+        owner: &impl Signer,
+        amount: u64,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let instructions = [self.build_approve(source, delegate, &owner.pubkey(), amount)];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), owner];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_approve_checked(
+        &self,
+        payer: &impl FeePayer,
+        source: &Pubkey,
+        delegate: &Pubkey,
+        owner: &impl Signer,
+        amount: u64,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetAccount + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let mint = self.try_get_token_account(source)?.mint;
+        let decimals = self.try_get_mint(&mint)?.decimals;
+
+        let instructions = [self.build_approve_checked(
+            source,
+            &mint,
+            delegate,
+            &owner.pubkey(),
+            amount,
+            decimals,
+        )];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), owner];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_revoke(
+        &self,
+        payer: &impl FeePayer,
+        source: &Pubkey,
+        owner: &impl Signer,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let instructions = [self.build_revoke(source, &owner.pubkey())];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), owner];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_freeze_account(
+        &self,
+        payer: &impl FeePayer,
+        token_account: &Pubkey,
+        mint: &Pubkey,
+        freeze_authority: &impl Signer,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let instructions =
+            [self.build_freeze_account(token_account, mint, &freeze_authority.pubkey())];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), freeze_authority];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_thaw_account(
+        &self,
+        payer: &impl FeePayer,
+        token_account: &Pubkey,
+        mint: &Pubkey,
+        freeze_authority: &impl Signer,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let instructions =
+            [self.build_thaw_account(token_account, mint, &freeze_authority.pubkey())];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), freeze_authority];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_set_authority(
+        &self,
+        payer: &impl FeePayer,
+        owned: &Pubkey,
+        new_authority: Option<&Pubkey>,
+        authority_type: AuthorityType,
+        current_authority: &impl Signer,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let instructions = [self.build_set_authority(
+            owned,
+            new_authority,
+            authority_type,
+            &current_authority.pubkey(),
+        )];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), current_authority];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_burn(
+        &self,
+        payer: &impl FeePayer,
+        token_account: &Pubkey,
+        mint: &Pubkey,
+        authority: &impl Signer,
+        amount: u64,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let instructions = [self.build_burn(token_account, mint, &authority.pubkey(), amount)];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), authority];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_close_account(
+        &self,
+        payer: &impl FeePayer,
+        token_account: &Pubkey,
+        destination: &Pubkey,
+        authority: &impl Signer,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let instructions =
+            [self.build_close_account(token_account, destination, &authority.pubkey())];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), authority];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_burn_all_and_close_account(
+        &self,
+        payer: &impl FeePayer,
+        token_account: &Pubkey,
+        destination: &Pubkey,
+        authority: &impl Signer,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetAccount + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let token_account_account = self.try_get_token_account(token_account)?;
+
+        let mut instructions = Vec::with_capacity(2);
+        // native accounts hold lamports that are reclaimed by closing the account
+        if token_account_account.amount > 0 && !token_account_account.is_native() {
+            instructions.push(self.build_burn(
+                token_account,
+                &token_account_account.mint,
+                &authority.pubkey(),
+                token_account_account.amount,
+            ));
+        }
+        instructions.push(self.build_close_account(
+            token_account,
+            destination,
+            &authority.pubkey(),
+        ));
+
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), authority];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
     }
 }
 
@@ -376,6 +964,77 @@ pub trait TokenSetter: Client {
     ) -> AnchorAccount<TokenAccount>
     where
         Self: SetAccount + HasRent,
+    {
+        self.set_token_account_with_state(token_account_pk, mint, owner, amount, |_| {})
+    }
+
+    fn set_token_account_with_delegate(
+        &mut self,
+        token_account_pk: Pubkey,
+        mint: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+        delegate: Pubkey,
+        delegated_amount: u64,
+    ) -> AnchorAccount<TokenAccount>
+    where
+        Self: SetAccount + HasRent,
+    {
+        self.set_token_account_with_state(token_account_pk, mint, owner, amount, |state| {
+            state.delegate = Some(delegate).into();
+            state.delegated_amount = delegated_amount;
+        })
+    }
+
+    fn set_frozen_token_account(
+        &mut self,
+        token_account_pk: Pubkey,
+        mint: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+    ) -> AnchorAccount<TokenAccount>
+    where
+        Self: SetAccount + HasRent,
+    {
+        self.set_token_account_with_state(token_account_pk, mint, owner, amount, |state| {
+            state.state = spl_token::state::AccountState::Frozen;
+        })
+    }
+
+    fn set_token_account_with_options(
+        &mut self,
+        token_account_pk: Pubkey,
+        mint: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+        options: TokenAccountOptions,
+    ) -> AnchorAccount<TokenAccount>
+    where
+        Self: SetAccount + HasRent,
+    {
+        self.set_token_account_with_state(token_account_pk, mint, owner, amount, |state| {
+            if let Some((delegate, delegated_amount)) = options.delegate {
+                state.delegate = Some(delegate).into();
+                state.delegated_amount = delegated_amount;
+            }
+            if options.frozen {
+                state.state = spl_token::state::AccountState::Frozen;
+            }
+            state.close_authority = options.close_authority.into();
+        })
+    }
+
+    fn set_token_account_with_state<F>(
+        &mut self,
+        token_account_pk: Pubkey,
+        mint: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+        modify: F,
+    ) -> AnchorAccount<TokenAccount>
+    where
+        Self: SetAccount + HasRent,
+        F: FnOnce(&mut spl_token::state::Account),
     {
         let rent_exempt = self.minimum_balance_for_rent_exemption(TokenAccount::LEN);
 
@@ -385,7 +1044,7 @@ pub trait TokenSetter: Client {
             (rent_exempt, None)
         };
 
-        let token_account = spl_token::state::Account {
+        let mut token_account = spl_token::state::Account {
             mint,
             owner,
             amount,
@@ -395,6 +1054,7 @@ pub trait TokenSetter: Client {
             delegated_amount: 0,
             close_authority: None.into(),
         };
+        modify(&mut token_account);
 
         let account =
             self.packing_set_account(token_account_pk, lamports, spl_token::id(), &token_account);
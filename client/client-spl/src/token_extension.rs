@@ -0,0 +1,127 @@
+use solana_sdk::program_error::ProgramError;
+use solana_sdk::program_pack::Pack;
+use solana_sdk::pubkey::Pubkey;
+
+use anchor_spl::token_2022::spl_token_2022::extension::cpi_guard::CpiGuard;
+use anchor_spl::token_2022::spl_token_2022::extension::default_account_state::DefaultAccountState;
+use anchor_spl::token_2022::spl_token_2022::extension::group_member_pointer::GroupMemberPointer;
+use anchor_spl::token_2022::spl_token_2022::extension::group_pointer::GroupPointer;
+use anchor_spl::token_2022::spl_token_2022::extension::immutable_owner::ImmutableOwner;
+use anchor_spl::token_2022::spl_token_2022::extension::interest_bearing_mint::InterestBearingConfig;
+use anchor_spl::token_2022::spl_token_2022::extension::memo_transfer::MemoTransfer;
+use anchor_spl::token_2022::spl_token_2022::extension::metadata_pointer::MetadataPointer;
+use anchor_spl::token_2022::spl_token_2022::extension::mint_close_authority::MintCloseAuthority;
+use anchor_spl::token_2022::spl_token_2022::extension::non_transferable::{
+    NonTransferable, NonTransferableAccount,
+};
+use anchor_spl::token_2022::spl_token_2022::extension::permanent_delegate::PermanentDelegate;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::{
+    TransferFeeAmount, TransferFeeConfig,
+};
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_hook::{
+    TransferHook, TransferHookAccount,
+};
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    BaseState, BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+};
+use anchor_spl::token_2022_extensions::spl_token_metadata_interface::state::TokenMetadata;
+
+use dexter_client_api::errors::{ClientError, ClientResult};
+
+/// Extensions without a dedicated variant are reported by their type only.
+#[derive(Debug, Clone)]
+pub enum TokenExtension {
+    TransferFeeConfig(TransferFeeConfig),
+    TransferFeeAmount(TransferFeeAmount),
+    MintCloseAuthority(MintCloseAuthority),
+    DefaultAccountState(DefaultAccountState),
+    ImmutableOwner(ImmutableOwner),
+    MemoTransfer(MemoTransfer),
+    NonTransferable(NonTransferable),
+    NonTransferableAccount(NonTransferableAccount),
+    InterestBearingConfig(InterestBearingConfig),
+    CpiGuard(CpiGuard),
+    PermanentDelegate(PermanentDelegate),
+    TransferHook(TransferHook),
+    TransferHookAccount(TransferHookAccount),
+    MetadataPointer(MetadataPointer),
+    TokenMetadata(TokenMetadata),
+    GroupPointer(GroupPointer),
+    GroupMemberPointer(GroupMemberPointer),
+    Other(ExtensionType),
+}
+
+#[derive(Debug, Clone)]
+pub struct WithExtensions<S> {
+    pub base: S,
+    pub extensions: Vec<TokenExtension>,
+}
+
+pub(crate) fn decode_with_extensions<S: BaseState + Pack>(
+    pubkey: &Pubkey,
+    data: &[u8],
+) -> ClientResult<WithExtensions<S>> {
+    let decode = || -> Result<WithExtensions<S>, ProgramError> {
+        let state = StateWithExtensions::<S>::unpack(data)?;
+        let extensions = state
+            .get_extension_types()?
+            .into_iter()
+            .map(|extension_type| decode_extension(&state, extension_type))
+            .collect::<Result<_, _>>()?;
+
+        Ok(WithExtensions {
+            base: state.base,
+            extensions,
+        })
+    };
+
+    decode().map_err(|_| ClientError::AccountDidNotDeserialize(*pubkey))
+}
+
+fn decode_extension<S: BaseState + Pack>(
+    state: &StateWithExtensions<S>,
+    extension_type: ExtensionType,
+) -> Result<TokenExtension, ProgramError> {
+    let extension = match extension_type {
+        ExtensionType::TransferFeeConfig => {
+            TokenExtension::TransferFeeConfig(*state.get_extension()?)
+        }
+        ExtensionType::TransferFeeAmount => {
+            TokenExtension::TransferFeeAmount(*state.get_extension()?)
+        }
+        ExtensionType::MintCloseAuthority => {
+            TokenExtension::MintCloseAuthority(*state.get_extension()?)
+        }
+        ExtensionType::DefaultAccountState => {
+            TokenExtension::DefaultAccountState(*state.get_extension()?)
+        }
+        ExtensionType::ImmutableOwner => TokenExtension::ImmutableOwner(*state.get_extension()?),
+        ExtensionType::MemoTransfer => TokenExtension::MemoTransfer(*state.get_extension()?),
+        ExtensionType::NonTransferable => TokenExtension::NonTransferable(*state.get_extension()?),
+        ExtensionType::NonTransferableAccount => {
+            TokenExtension::NonTransferableAccount(*state.get_extension()?)
+        }
+        ExtensionType::InterestBearingConfig => {
+            TokenExtension::InterestBearingConfig(*state.get_extension()?)
+        }
+        ExtensionType::CpiGuard => TokenExtension::CpiGuard(*state.get_extension()?),
+        ExtensionType::PermanentDelegate => {
+            TokenExtension::PermanentDelegate(*state.get_extension()?)
+        }
+        ExtensionType::TransferHook => TokenExtension::TransferHook(*state.get_extension()?),
+        ExtensionType::TransferHookAccount => {
+            TokenExtension::TransferHookAccount(*state.get_extension()?)
+        }
+        ExtensionType::MetadataPointer => TokenExtension::MetadataPointer(*state.get_extension()?),
+        ExtensionType::TokenMetadata => {
+            TokenExtension::TokenMetadata(state.get_variable_len_extension()?)
+        }
+        ExtensionType::GroupPointer => TokenExtension::GroupPointer(*state.get_extension()?),
+        ExtensionType::GroupMemberPointer => {
+            TokenExtension::GroupMemberPointer(*state.get_extension()?)
+        }
+        extension_type => TokenExtension::Other(extension_type),
+    };
+
+    Ok(extension)
+}
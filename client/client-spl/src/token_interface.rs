@@ -1,29 +1,244 @@
-use solana_sdk::account::ReadableAccount;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use solana_rpc_client::rpc_client::RpcClient;
+use solana_sdk::account::{Account, ReadableAccount};
 use solana_sdk::instruction::Instruction;
 use solana_sdk::program_pack::Pack;
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 use solana_sdk::system_instruction;
 
 use anchor_lang::Key;
 use anchor_spl::token::spl_token;
 use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_2022::spl_token_2022::error::TokenError;
+use anchor_spl::token_2022::spl_token_2022::extension::confidential_transfer_fee::ConfidentialTransferFeeAmount;
+use anchor_spl::token_2022::spl_token_2022::extension::immutable_owner::ImmutableOwner;
+use anchor_spl::token_2022::spl_token_2022::extension::metadata_pointer::MetadataPointer;
+use anchor_spl::token_2022::spl_token_2022::extension::mint_close_authority::MintCloseAuthority;
+use anchor_spl::token_2022::spl_token_2022::extension::non_transferable::{
+    NonTransferable, NonTransferableAccount,
+};
+use anchor_spl::token_2022::spl_token_2022::extension::permanent_delegate::PermanentDelegate;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::{
+    TransferFeeAmount, TransferFeeConfig,
+};
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_hook::TransferHookAccount;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    AccountType, BaseState, BaseStateWithExtensions, BaseStateWithExtensionsMut, Extension,
+    ExtensionType, StateWithExtensions, StateWithExtensionsMut,
+};
+use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_2022::spl_token_2022::offchain::AccountFetchError;
+use anchor_spl::token_2022_extensions::spl_token_metadata_interface;
+use anchor_spl::token_2022_extensions::spl_token_metadata_interface::state::{
+    Field, TokenMetadata,
+};
 use anchor_spl::token_interface::{Mint, TokenAccount};
+use bytemuck::Pod;
+use futures::future;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 use dexter_client_anchor::{AnchorAccount, AnchorGetter};
 use dexter_client_api::base::executor::ProcessTransaction;
 use dexter_client_api::base::getter::{
     GetAccount, GetLatestBlockhash, GetMinimumBalanceForRentExemption, GetMultipleAccounts,
+    GetProgramAccounts, Memcmp, ProgramAccountsFilter,
 };
 use dexter_client_api::base::setter::{HasRent, SetAccount};
-use dexter_client_api::errors::ClientResult;
+use dexter_client_api::errors::{ClientError, ClientResult};
 use dexter_client_api::execution::ExecutionOutput;
-use dexter_client_api::exts::executor::CompilingProcessTransaction;
+use dexter_client_api::exts::executor::{CompileTransactionBatch, CompilingProcessTransaction};
+use dexter_client_api::exts::getter::GetAccountExt;
+use dexter_client_api::exts::signer::FeePayer;
 use dexter_client_api::Client;
 use dexter_client_sys::pack::PackingSetter;
+use dexter_client_sys::sysvar::SysvarGetter;
+
+use crate::amount::{self, Rounding};
+use crate::token::TokenAccountOptions;
+use crate::token_extension::{decode_with_extensions, WithExtensions};
+
+fn mint_len(extension_types: &[ExtensionType]) -> usize {
+    ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(extension_types)
+        .unwrap()
+}
+
+fn token_account_len(extension_types: &[ExtensionType]) -> usize {
+    ExtensionType::try_calculate_account_len::<spl_token_2022::state::Account>(extension_types)
+        .unwrap()
+}
+
+fn unpack_extension<S, E>(pubkey: &Pubkey, data: &[u8]) -> ClientResult<Option<E>>
+where
+    S: BaseState + Pack,
+    E: Extension + Pod,
+{
+    let state = StateWithExtensions::<S>::unpack(data)
+        .map_err(|_| ClientError::AccountDidNotDeserialize(*pubkey))?;
+    Ok(state.get_extension::<E>().ok().copied())
+}
+
+fn unpack_token_metadata(
+    mint: &Pubkey,
+    state: &StateWithExtensions<spl_token_2022::state::Mint>,
+) -> ClientResult<Option<TokenMetadata>> {
+    let extension_types = state
+        .get_extension_types()
+        .map_err(|_| ClientError::AccountDidNotDeserialize(*mint))?;
+    if !extension_types.contains(&ExtensionType::TokenMetadata) {
+        return Ok(None);
+    }
+
+    let token_metadata = state
+        .get_variable_len_extension::<TokenMetadata>()
+        .map_err(|_| ClientError::AccountDidNotDeserialize(*mint))?;
+    Ok(Some(token_metadata))
+}
+
+fn token_metadata_rent_shortfall<C, F>(client: &C, mint: &Pubkey, modify: F) -> ClientResult<u64>
+where
+    C: ?Sized + GetAccount + GetMinimumBalanceForRentExemption,
+    F: FnOnce(&mut TokenMetadata),
+{
+    let account = client.try_get_account(mint)?;
+    let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&account.data)
+        .map_err(|_| ClientError::AccountDidNotDeserialize(*mint))?;
+
+    let mut token_metadata = unpack_token_metadata(mint, &state)?.ok_or(
+        ExtensionError::NotFound(*mint, ExtensionType::TokenMetadata),
+    )?;
+    modify(&mut token_metadata);
+
+    let new_len = state
+        .try_get_new_account_len_for_variable_len_extension(&token_metadata)
+        .map_err(|_| ClientError::AccountDidNotSerialize(*mint))?;
+    let rent_exempt = client.get_minimum_balance_for_rent_exemption(new_len)?;
+
+    Ok(rent_exempt.saturating_sub(account.lamports))
+}
+
+// extended accounts are variable sized, but tagged right after the base state
+fn token_account_filters(
+    filters: Vec<ProgramAccountsFilter>,
+) -> (Vec<ProgramAccountsFilter>, Vec<ProgramAccountsFilter>) {
+    let mut base_filters = vec![ProgramAccountsFilter::DataSize(
+        spl_token_2022::state::Account::LEN as u64,
+    )];
+    base_filters.extend(filters.iter().cloned());
+
+    let mut extended_filters = filters;
+    extended_filters.push(ProgramAccountsFilter::Memcmp(Memcmp::new_raw_bytes(
+        spl_token_2022::state::Account::LEN,
+        vec![AccountType::Account.into()],
+    )));
+
+    (base_filters, extended_filters)
+}
+
+fn scan_token_accounts<C>(
+    client: &C,
+    token_program_id: &Pubkey,
+    filters: Vec<ProgramAccountsFilter>,
+) -> ClientResult<Vec<AnchorAccount<TokenAccount>>>
+where
+    C: ?Sized + GetProgramAccounts,
+{
+    let (base_filters, extended_filters) = token_account_filters(filters);
+
+    let mut accounts = client.get_program_accounts(token_program_id, Some(base_filters))?;
+    if *token_program_id == spl_token_2022::id() {
+        accounts.extend(client.get_program_accounts(token_program_id, Some(extended_filters))?);
+    }
+
+    accounts
+        .into_iter()
+        .map(|(pubkey, account)| {
+            AnchorAccount::<TokenAccount>::try_from_account(pubkey, account)
+                .map_err(|_| ClientError::AccountDidNotDeserialize(pubkey))
+        })
+        .collect()
+}
 
-const MINT_LEN: usize = spl_token_2022::state::Mint::LEN;
-const TOKEN_ACCOUNT_LEN: usize = spl_token_2022::state::Account::LEN;
+fn owner_filter(owner: &Pubkey) -> ProgramAccountsFilter {
+    ProgramAccountsFilter::Memcmp(Memcmp::new_base58_encoded(32, owner.as_ref()))
+}
+
+fn mint_filter(mint: &Pubkey) -> ProgramAccountsFilter {
+    ProgramAccountsFilter::Memcmp(Memcmp::new_base58_encoded(0, mint.as_ref()))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ExtensionError {
+    #[error("The account {0} has no {1:?} extension")]
+    NotFound(Pubkey, ExtensionType),
+}
+
+impl From<ExtensionError> for ClientError {
+    fn from(error: ExtensionError) -> Self {
+        ClientError::DomainSpecific(Box::new(error))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MintExtensionOptions {
+    pub permanent_delegate: Option<Pubkey>,
+    pub non_transferable: bool,
+}
+
+impl MintExtensionOptions {
+    pub fn with_permanent_delegate(mut self, permanent_delegate: Pubkey) -> Self {
+        self.permanent_delegate = Some(permanent_delegate);
+        self
+    }
+
+    pub fn non_transferable(mut self) -> Self {
+        self.non_transferable = true;
+        self
+    }
+
+    pub fn extension_types(&self) -> Vec<ExtensionType> {
+        let mut extension_types = Vec::new();
+        if self.permanent_delegate.is_some() {
+            extension_types.push(ExtensionType::PermanentDelegate);
+        }
+        if self.non_transferable {
+            extension_types.push(ExtensionType::NonTransferable);
+        }
+        extension_types
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OwnedTokenAccount {
+    pub token_program_id: Pubkey,
+    pub token_account: AnchorAccount<TokenAccount>,
+    pub mint: Option<AnchorAccount<Mint>>,
+    // `None` if the mint was not found
+    pub ui_amount: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenHolder {
+    pub owner: Pubkey,
+    pub token_account: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MintSnapshot {
+    pub mint: Pubkey,
+    pub token_program_id: Pubkey,
+    pub supply: u64,
+    pub decimals: u8,
+    // token accounts with a nonzero balance
+    pub holder_count: usize,
+    // largest first
+    pub top_holders: Vec<TokenHolder>,
+}
 
 pub trait TokenInterfaceGetter: Client {
     fn get_token_program_id(&self, mint: &Pubkey) -> ClientResult<Option<Pubkey>>
@@ -92,6 +307,131 @@ pub trait TokenInterfaceGetter: Client {
             .map(|ta| ta.amount)
     }
 
+    fn get_token_accounts_for_owner(&self, owner: &Pubkey) -> ClientResult<Vec<OwnedTokenAccount>>
+    where
+        Self: GetProgramAccounts + GetMultipleAccounts,
+    {
+        let mut token_accounts =
+            scan_token_accounts(self, &spl_token::id(), vec![owner_filter(owner)])?;
+        token_accounts.extend(scan_token_accounts(
+            self,
+            &spl_token_2022::id(),
+            vec![owner_filter(owner)],
+        )?);
+
+        let mut mints: Vec<Pubkey> = token_accounts.iter().map(|ta| ta.mint).collect();
+        mints.sort();
+        mints.dedup();
+        let mint_accounts: HashMap<Pubkey, AnchorAccount<Mint>> = self
+            .get_mints(&mints)?
+            .into_iter()
+            .flatten()
+            .map(|mint| (mint.key(), mint))
+            .collect();
+
+        let owned_token_accounts = token_accounts
+            .into_iter()
+            .map(|token_account| {
+                let mint = mint_accounts.get(&token_account.mint).cloned();
+                let ui_amount = mint.as_ref().map(|mint| {
+                    spl_token_2022::amount_to_ui_amount(token_account.amount, mint.decimals)
+                });
+
+                OwnedTokenAccount {
+                    token_program_id: *ReadableAccount::owner(&token_account),
+                    token_account,
+                    mint,
+                    ui_amount,
+                }
+            })
+            .collect();
+
+        Ok(owned_token_accounts)
+    }
+
+    fn get_token_accounts_for_mint(
+        &self,
+        mint: &Pubkey,
+    ) -> ClientResult<Vec<AnchorAccount<TokenAccount>>>
+    where
+        Self: GetProgramAccounts,
+    {
+        scan_token_accounts(
+            self,
+            &self.try_get_token_program_id(mint)?,
+            vec![mint_filter(mint)],
+        )
+    }
+
+    fn get_token_accounts_for_owner_and_mint(
+        &self,
+        owner: &Pubkey,
+        mint: &Pubkey,
+    ) -> ClientResult<Vec<AnchorAccount<TokenAccount>>>
+    where
+        Self: GetProgramAccounts,
+    {
+        scan_token_accounts(
+            self,
+            &self.try_get_token_program_id(mint)?,
+            vec![mint_filter(mint), owner_filter(owner)],
+        )
+    }
+
+    /// Largest first.
+    fn get_largest_token_holders(&self, mint: &Pubkey, n: usize) -> ClientResult<Vec<TokenHolder>>
+    where
+        Self: GetProgramAccounts,
+    {
+        let mut holders = self
+            .get_token_accounts_for_mint(mint)?
+            .into_iter()
+            .map(|token_account| TokenHolder {
+                owner: token_account.owner,
+                token_account: token_account.key(),
+                amount: token_account.amount,
+            })
+            .collect::<Vec<_>>();
+
+        holders.sort_by(|a, b| b.amount.cmp(&a.amount));
+        holders.truncate(n);
+
+        Ok(holders)
+    }
+
+    /// Scans every token account of the mint.
+    fn snapshot_mint(&self, mint: &Pubkey, top_n: usize) -> ClientResult<MintSnapshot>
+    where
+        Self: GetProgramAccounts,
+    {
+        let mint_account = self.try_get_mint(mint)?;
+
+        let mut holders = self
+            .get_token_accounts_for_mint(mint)?
+            .into_iter()
+            .filter(|token_account| token_account.amount > 0)
+            .map(|token_account| TokenHolder {
+                owner: token_account.owner,
+                token_account: token_account.key(),
+                amount: token_account.amount,
+            })
+            .collect::<Vec<_>>();
+
+        let holder_count = holders.len();
+
+        holders.sort_by(|a, b| b.amount.cmp(&a.amount));
+        holders.truncate(top_n);
+
+        Ok(MintSnapshot {
+            mint: *mint,
+            token_program_id: *ReadableAccount::owner(&mint_account),
+            supply: mint_account.supply,
+            decimals: mint_account.decimals,
+            holder_count,
+            top_holders: holders,
+        })
+    }
+
     fn get_mints(&self, mints: &[Pubkey]) -> ClientResult<Vec<Option<AnchorAccount<Mint>>>>
     where
         Self: GetMultipleAccounts,
@@ -119,10 +459,290 @@ pub trait TokenInterfaceGetter: Client {
     {
         self.try_get_mint(mint).map(|tm| tm.supply)
     }
+
+    fn amount_to_ui_amount(&self, mint: &Pubkey, amount: u64) -> ClientResult<f64>
+    where
+        Self: GetAccount,
+    {
+        let decimals = self.try_get_mint(mint)?.decimals;
+        Ok(spl_token_2022::amount_to_ui_amount(amount, decimals))
+    }
+
+    fn amount_to_ui_amount_string(&self, mint: &Pubkey, amount: u64) -> ClientResult<String>
+    where
+        Self: GetAccount,
+    {
+        let decimals = self.try_get_mint(mint)?.decimals;
+        Ok(spl_token_2022::amount_to_ui_amount_string_trimmed(
+            amount, decimals,
+        ))
+    }
+
+    fn ui_amount_to_amount(
+        &self,
+        mint: &Pubkey,
+        ui_amount: &str,
+        rounding: Rounding,
+    ) -> ClientResult<u64>
+    where
+        Self: GetAccount,
+    {
+        let decimals = self.try_get_mint(mint)?.decimals;
+        Ok(amount::ui_amount_to_amount(ui_amount, decimals, rounding)?)
+    }
+
+    fn get_multisig(
+        &self,
+        multisig: &Pubkey,
+    ) -> ClientResult<Option<spl_token_2022::state::Multisig>>
+    where
+        Self: GetAccount,
+    {
+        let Some(account) = self.get_account(multisig)? else {
+            return Ok(None);
+        };
+
+        spl_token_2022::state::Multisig::unpack(&account.data)
+            .map(Some)
+            .map_err(|_| ClientError::AccountDidNotDeserialize(*multisig))
+    }
+
+    fn try_get_multisig(&self, multisig: &Pubkey) -> ClientResult<spl_token_2022::state::Multisig>
+    where
+        Self: GetAccount,
+    {
+        self.get_multisig(multisig)?
+            .ok_or(ClientError::AccountNotFound(*multisig))
+    }
+
+    fn get_mint_with_extensions(
+        &self,
+        mint: &Pubkey,
+    ) -> ClientResult<Option<WithExtensions<spl_token_2022::state::Mint>>>
+    where
+        Self: GetAccount,
+    {
+        let Some(account) = self.get_account(mint)? else {
+            return Ok(None);
+        };
+
+        decode_with_extensions(mint, &account.data).map(Some)
+    }
+
+    fn try_get_mint_with_extensions(
+        &self,
+        mint: &Pubkey,
+    ) -> ClientResult<WithExtensions<spl_token_2022::state::Mint>>
+    where
+        Self: GetAccount,
+    {
+        self.get_mint_with_extensions(mint)?
+            .ok_or(ClientError::AccountNotFound(*mint))
+    }
+
+    fn get_token_account_with_extensions(
+        &self,
+        token_account: &Pubkey,
+    ) -> ClientResult<Option<WithExtensions<spl_token_2022::state::Account>>>
+    where
+        Self: GetAccount,
+    {
+        let Some(account) = self.get_account(token_account)? else {
+            return Ok(None);
+        };
+
+        decode_with_extensions(token_account, &account.data).map(Some)
+    }
+
+    fn try_get_token_account_with_extensions(
+        &self,
+        token_account: &Pubkey,
+    ) -> ClientResult<WithExtensions<spl_token_2022::state::Account>>
+    where
+        Self: GetAccount,
+    {
+        self.get_token_account_with_extensions(token_account)?
+            .ok_or(ClientError::AccountNotFound(*token_account))
+    }
+
+    fn get_mint_extension_types(&self, mint: &Pubkey) -> ClientResult<Vec<ExtensionType>>
+    where
+        Self: GetAccount,
+    {
+        let account = self.try_get_account(mint)?;
+        StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&account.data)
+            .and_then(|state| state.get_extension_types())
+            .map_err(|_| ClientError::AccountDidNotDeserialize(*mint))
+    }
+
+    fn get_required_token_account_extension_types(
+        &self,
+        mint: &Pubkey,
+    ) -> ClientResult<Vec<ExtensionType>>
+    where
+        Self: GetAccount,
+    {
+        let mint_extension_types = self.get_mint_extension_types(mint)?;
+        Ok(ExtensionType::get_required_init_account_extensions(
+            &mint_extension_types,
+        ))
+    }
+
+    fn get_token_account_len(&self, mint: &Pubkey) -> ClientResult<usize>
+    where
+        Self: GetAccount,
+    {
+        self.get_required_token_account_extension_types(mint)
+            .map(|extension_types| token_account_len(&extension_types))
+    }
+
+    fn get_transfer_fee_config(&self, mint: &Pubkey) -> ClientResult<Option<TransferFeeConfig>>
+    where
+        Self: GetAccount,
+    {
+        let account = self.try_get_account(mint)?;
+        unpack_extension::<spl_token_2022::state::Mint, _>(mint, &account.data)
+    }
+
+    fn get_mint_withheld_amount(&self, mint: &Pubkey) -> ClientResult<u64>
+    where
+        Self: GetAccount,
+    {
+        let withheld_amount = self
+            .get_transfer_fee_config(mint)?
+            .map(|config| config.withheld_amount.into())
+            .unwrap_or_default();
+        Ok(withheld_amount)
+    }
+
+    fn get_withheld_amount(&self, token_account: &Pubkey) -> ClientResult<u64>
+    where
+        Self: GetAccount,
+    {
+        let account = self.try_get_account(token_account)?;
+        let withheld_amount =
+            unpack_extension::<spl_token_2022::state::Account, TransferFeeAmount>(
+                token_account,
+                &account.data,
+            )?
+            .map(|amount| amount.withheld_amount.into())
+            .unwrap_or_default();
+        Ok(withheld_amount)
+    }
+
+    fn get_transfer_fee(&self, mint: &Pubkey, amount: u64) -> ClientResult<u64>
+    where
+        Self: GetAccount,
+    {
+        let Some(config) = self.get_transfer_fee_config(mint)? else {
+            return Ok(0);
+        };
+        let epoch = self.try_get_sysvar_clock()?.epoch;
+        config
+            .calculate_epoch_fee(epoch, amount)
+            .ok_or_else(|| ClientError::DomainSpecific(Box::new(TokenError::Overflow)))
+    }
+
+    fn get_permanent_delegate(&self, mint: &Pubkey) -> ClientResult<Option<Pubkey>>
+    where
+        Self: GetAccount,
+    {
+        let account = self.try_get_account(mint)?;
+        let permanent_delegate =
+            unpack_extension::<spl_token_2022::state::Mint, PermanentDelegate>(
+                mint,
+                &account.data,
+            )?
+            .and_then(|extension| Option::<Pubkey>::from(extension.delegate));
+        Ok(permanent_delegate)
+    }
+
+    fn is_non_transferable(&self, mint: &Pubkey) -> ClientResult<bool>
+    where
+        Self: GetAccount,
+    {
+        let account = self.try_get_account(mint)?;
+        let non_transferable =
+            unpack_extension::<spl_token_2022::state::Mint, NonTransferable>(mint, &account.data)?;
+        Ok(non_transferable.is_some())
+    }
+
+    fn get_metadata_pointer(&self, mint: &Pubkey) -> ClientResult<Option<MetadataPointer>>
+    where
+        Self: GetAccount,
+    {
+        let account = self.try_get_account(mint)?;
+        unpack_extension::<spl_token_2022::state::Mint, _>(mint, &account.data)
+    }
+
+    fn get_token_metadata(&self, mint: &Pubkey) -> ClientResult<Option<TokenMetadata>>
+    where
+        Self: GetAccount,
+    {
+        let account = self.try_get_account(mint)?;
+        let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&account.data)
+            .map_err(|_| ClientError::AccountDidNotDeserialize(*mint))?;
+        unpack_token_metadata(mint, &state)
+    }
+
+    fn try_get_token_metadata(&self, mint: &Pubkey) -> ClientResult<TokenMetadata>
+    where
+        Self: GetAccount,
+    {
+        let token_metadata = self
+            .get_token_metadata(mint)?
+            .ok_or(ExtensionError::NotFound(
+                *mint,
+                ExtensionType::TokenMetadata,
+            ))?;
+        Ok(token_metadata)
+    }
 }
 
 impl<C: ?Sized + Client> TokenInterfaceGetter for C {}
 
+pub trait TokenInterfaceRpcGetter {
+    /// At most 20 holders, as returned by `getTokenLargestAccounts`.
+    fn get_rpc_largest_token_holders(
+        &self,
+        mint: &Pubkey,
+        n: usize,
+    ) -> ClientResult<Vec<TokenHolder>>;
+}
+
+impl TokenInterfaceRpcGetter for RpcClient {
+    fn get_rpc_largest_token_holders(
+        &self,
+        mint: &Pubkey,
+        n: usize,
+    ) -> ClientResult<Vec<TokenHolder>> {
+        let balances = RpcClient::get_token_largest_accounts(self, mint)?;
+        let token_account_pubkeys = balances
+            .iter()
+            .take(n)
+            .map(|balance| Pubkey::from_str(&balance.address))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| ClientError::DomainSpecific(err.into()))?;
+
+        // the balances do not include the owners
+        let token_accounts =
+            self.get_anchor_multiple_accounts::<TokenAccount>(&token_account_pubkeys)?;
+
+        let mut holders: Vec<_> = token_accounts
+            .into_iter()
+            .flatten()
+            .map(|token_account| TokenHolder {
+                owner: token_account.owner,
+                token_account: token_account.key(),
+                amount: token_account.amount,
+            })
+            .collect();
+        holders.sort_by(|a, b| b.amount.cmp(&a.amount));
+
+        Ok(holders)
+    }
+}
+
 pub trait TokenInterfaceInstruction: Client {
     fn build_initialize_mint(
         &self,
@@ -177,6 +797,42 @@ pub trait TokenInterfaceInstruction: Client {
         .unwrap()
     }
 
+    fn build_initialize_multisig(
+        &self,
+        token_program_id: Pubkey,
+        multisig: Pubkey,
+        signers: &[Pubkey],
+        m: u8,
+    ) -> Instruction {
+        spl_token_2022::instruction::initialize_multisig(
+            &token_program_id,
+            &multisig,
+            &signers.iter().collect::<Vec<_>>(),
+            m,
+        )
+        .unwrap()
+    }
+
+    fn build_mint_to_multisig(
+        &self,
+        token_program_id: Pubkey,
+        token_account: Pubkey,
+        mint: Pubkey,
+        multisig_authority: Pubkey,
+        signers: &[Pubkey],
+        amount: u64,
+    ) -> Instruction {
+        spl_token_2022::instruction::mint_to(
+            &token_program_id,
+            &mint,
+            &token_account,
+            &multisig_authority,
+            &signers.iter().collect::<Vec<_>>(),
+            amount,
+        )
+        .unwrap()
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn build_transfer_checked(
         &self,
@@ -202,24 +858,199 @@ pub trait TokenInterfaceInstruction: Client {
         .unwrap()
     }
 
-    fn build_create_and_initialize_mint(
+    #[allow(clippy::too_many_arguments)]
+    fn add_transfer_hook_account_metas(
         &self,
-        payer: Pubkey,
-        token_program_id: Pubkey,
+        instruction: &mut Instruction,
+        source: Pubkey,
         mint: Pubkey,
-        mint_authority: Pubkey,
-        freeze_authority: Option<Pubkey>,
-        decimals: u8,
+        destination: Pubkey,
+        authority: Pubkey,
+        amount: u64,
+    ) -> ClientResult<()>
+    where
+        Self: GetAccount,
+    {
+        // account data is fetched synchronously, so every future resolves on the first poll
+        let fetch_account_data = |pubkey: Pubkey| {
+            future::ready(
+                self.get_account(&pubkey)
+                    .map(|account| account.map(|account| account.data))
+                    .map_err(AccountFetchError::from),
+            )
+        };
+
+        futures::executor::block_on(spl_token_2022::offchain::add_extra_account_metas(
+            instruction,
+            &source,
+            &mint,
+            &destination,
+            &authority,
+            amount,
+            fetch_account_data,
+        ))
+        .map_err(|err| match err.downcast::<ClientError>() {
+            Ok(err) => *err,
+            Err(err) => ClientError::DomainSpecific(err),
+        })
+    }
+
+    fn build_burn(
+        &self,
+        token_program_id: Pubkey,
+        token_account: Pubkey,
+        mint: Pubkey,
+        authority: Pubkey,
+        amount: u64,
+    ) -> Instruction {
+        spl_token_2022::instruction::burn(
+            &token_program_id,
+            &token_account,
+            &mint,
+            &authority,
+            &[],
+            amount,
+        )
+        .unwrap()
+    }
+
+    fn build_approve(
+        &self,
+        token_program_id: Pubkey,
+        source: Pubkey,
+        delegate: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+    ) -> Instruction {
+        spl_token_2022::instruction::approve(
+            &token_program_id,
+            &source,
+            &delegate,
+            &owner,
+            &[],
+            amount,
+        )
+        .unwrap()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn build_approve_checked(
+        &self,
+        token_program_id: Pubkey,
+        source: Pubkey,
+        mint: Pubkey,
+        delegate: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+        decimals: u8,
+    ) -> Instruction {
+        spl_token_2022::instruction::approve_checked(
+            &token_program_id,
+            &source,
+            &mint,
+            &delegate,
+            &owner,
+            &[],
+            amount,
+            decimals,
+        )
+        .unwrap()
+    }
+
+    fn build_revoke(&self, token_program_id: Pubkey, source: Pubkey, owner: Pubkey) -> Instruction {
+        spl_token_2022::instruction::revoke(&token_program_id, &source, &owner, &[]).unwrap()
+    }
+
+    fn build_freeze_account(
+        &self,
+        token_program_id: Pubkey,
+        token_account: Pubkey,
+        mint: Pubkey,
+        freeze_authority: Pubkey,
+    ) -> Instruction {
+        spl_token_2022::instruction::freeze_account(
+            &token_program_id,
+            &token_account,
+            &mint,
+            &freeze_authority,
+            &[],
+        )
+        .unwrap()
+    }
+
+    fn build_thaw_account(
+        &self,
+        token_program_id: Pubkey,
+        token_account: Pubkey,
+        mint: Pubkey,
+        freeze_authority: Pubkey,
+    ) -> Instruction {
+        spl_token_2022::instruction::thaw_account(
+            &token_program_id,
+            &token_account,
+            &mint,
+            &freeze_authority,
+            &[],
+        )
+        .unwrap()
+    }
+
+    fn build_set_authority(
+        &self,
+        token_program_id: Pubkey,
+        owned: Pubkey,
+        new_authority: Option<Pubkey>,
+        authority_type: AuthorityType,
+        current_authority: Pubkey,
+    ) -> Instruction {
+        spl_token_2022::instruction::set_authority(
+            &token_program_id,
+            &owned,
+            new_authority.as_ref(),
+            authority_type,
+            &current_authority,
+            &[],
+        )
+        .unwrap()
+    }
+
+    fn build_close_account(
+        &self,
+        token_program_id: Pubkey,
+        token_account: Pubkey,
+        destination: Pubkey,
+        authority: Pubkey,
+    ) -> Instruction {
+        spl_token_2022::instruction::close_account(
+            &token_program_id,
+            &token_account,
+            &destination,
+            &authority,
+            &[],
+        )
+        .unwrap()
+    }
+
+    fn build_create_and_initialize_mint(
+        &self,
+        payer: Pubkey,
+        token_program_id: Pubkey,
+        mint: Pubkey,
+        mint_authority: Pubkey,
+        freeze_authority: Option<Pubkey>,
+        decimals: u8,
     ) -> ClientResult<[Instruction; 2]>
     where
         Self: GetMinimumBalanceForRentExemption,
     {
+        let mint_len = mint_len(&[]);
+
         let instructions = [
             system_instruction::create_account(
                 &payer,
                 &mint,
-                self.get_minimum_balance_for_rent_exemption(MINT_LEN)?,
-                MINT_LEN as u64,
+                self.get_minimum_balance_for_rent_exemption(mint_len)?,
+                mint_len as u64,
                 &token_program_id,
             ),
             self.build_initialize_mint(
@@ -243,157 +1074,1266 @@ pub trait TokenInterfaceInstruction: Client {
         owner: Pubkey,
     ) -> ClientResult<[Instruction; 2]>
     where
-        Self: GetMinimumBalanceForRentExemption,
+        Self: GetAccount + GetMinimumBalanceForRentExemption,
+    {
+        // the token program initializes the extensions required by the mint in place
+        let token_account_len = self.get_token_account_len(&mint)?;
+
+        let instructions = [
+            system_instruction::create_account(
+                &payer,
+                &token_account,
+                self.get_minimum_balance_for_rent_exemption(token_account_len)?,
+                token_account_len as u64,
+                &token_program_id,
+            ),
+            spl_token_2022::instruction::initialize_account(
+                &token_program_id,
+                &token_account,
+                &mint,
+                &owner,
+            )
+            .unwrap(),
+        ];
+
+        Ok(instructions)
+    }
+
+    fn build_create_and_initialize_multisig(
+        &self,
+        payer: Pubkey,
+        token_program_id: Pubkey,
+        multisig: Pubkey,
+        signers: &[Pubkey],
+        m: u8,
+    ) -> ClientResult<[Instruction; 2]>
+    where
+        Self: GetMinimumBalanceForRentExemption,
+    {
+        let multisig_len = spl_token_2022::state::Multisig::LEN;
+
+        let instructions = [
+            system_instruction::create_account(
+                &payer,
+                &multisig,
+                self.get_minimum_balance_for_rent_exemption(multisig_len)?,
+                multisig_len as u64,
+                &token_program_id,
+            ),
+            self.build_initialize_multisig(token_program_id, multisig, signers, m),
+        ];
+
+        Ok(instructions)
+    }
+
+    fn build_initialize_transfer_fee_config(
+        &self,
+        token_program_id: Pubkey,
+        mint: Pubkey,
+        transfer_fee_config_authority: Option<Pubkey>,
+        withdraw_withheld_authority: Option<Pubkey>,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    ) -> Instruction {
+        spl_token_2022::extension::transfer_fee::instruction::initialize_transfer_fee_config(
+            &token_program_id,
+            &mint,
+            transfer_fee_config_authority.as_ref(),
+            withdraw_withheld_authority.as_ref(),
+            transfer_fee_basis_points,
+            maximum_fee,
+        )
+        .unwrap()
+    }
+
+    fn build_initialize_permanent_delegate(
+        &self,
+        token_program_id: Pubkey,
+        mint: Pubkey,
+        delegate: Pubkey,
+    ) -> Instruction {
+        spl_token_2022::instruction::initialize_permanent_delegate(
+            &token_program_id,
+            &mint,
+            &delegate,
+        )
+        .unwrap()
+    }
+
+    fn build_initialize_non_transferable_mint(
+        &self,
+        token_program_id: Pubkey,
+        mint: Pubkey,
+    ) -> Instruction {
+        spl_token_2022::instruction::initialize_non_transferable_mint(&token_program_id, &mint)
+            .unwrap()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn build_create_and_initialize_mint_with_options(
+        &self,
+        payer: Pubkey,
+        token_program_id: Pubkey,
+        mint: Pubkey,
+        mint_authority: Pubkey,
+        freeze_authority: Option<Pubkey>,
+        decimals: u8,
+        options: &MintExtensionOptions,
+    ) -> ClientResult<Vec<Instruction>>
+    where
+        Self: GetMinimumBalanceForRentExemption,
+    {
+        let mint_len = mint_len(&options.extension_types());
+
+        let mut instructions = vec![system_instruction::create_account(
+            &payer,
+            &mint,
+            self.get_minimum_balance_for_rent_exemption(mint_len)?,
+            mint_len as u64,
+            &token_program_id,
+        )];
+        // extensions must be initialized before the mint itself
+        if let Some(delegate) = options.permanent_delegate {
+            instructions.push(self.build_initialize_permanent_delegate(
+                token_program_id,
+                mint,
+                delegate,
+            ));
+        }
+        if options.non_transferable {
+            instructions.push(self.build_initialize_non_transferable_mint(token_program_id, mint));
+        }
+        instructions.push(self.build_initialize_mint(
+            token_program_id,
+            mint,
+            mint_authority,
+            freeze_authority,
+            decimals,
+        ));
+
+        Ok(instructions)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn build_create_and_initialize_mint_with_transfer_fee(
+        &self,
+        payer: Pubkey,
+        token_program_id: Pubkey,
+        mint: Pubkey,
+        mint_authority: Pubkey,
+        freeze_authority: Option<Pubkey>,
+        decimals: u8,
+        transfer_fee_config_authority: Option<Pubkey>,
+        withdraw_withheld_authority: Option<Pubkey>,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    ) -> ClientResult<[Instruction; 3]>
+    where
+        Self: GetMinimumBalanceForRentExemption,
+    {
+        let mint_len = mint_len(&[ExtensionType::TransferFeeConfig]);
+
+        let instructions = [
+            system_instruction::create_account(
+                &payer,
+                &mint,
+                self.get_minimum_balance_for_rent_exemption(mint_len)?,
+                mint_len as u64,
+                &token_program_id,
+            ),
+            self.build_initialize_transfer_fee_config(
+                token_program_id,
+                mint,
+                transfer_fee_config_authority,
+                withdraw_withheld_authority,
+                transfer_fee_basis_points,
+                maximum_fee,
+            ),
+            self.build_initialize_mint(
+                token_program_id,
+                mint,
+                mint_authority,
+                freeze_authority,
+                decimals,
+            ),
+        ];
+
+        Ok(instructions)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn build_transfer_checked_with_fee(
+        &self,
+        token_program_id: Pubkey,
+        source: Pubkey,
+        mint: Pubkey,
+        destination: Pubkey,
+        authority: Pubkey,
+        signers: &[Pubkey],
+        amount: u64,
+        decimals: u8,
+        fee: u64,
+    ) -> Instruction {
+        spl_token_2022::extension::transfer_fee::instruction::transfer_checked_with_fee(
+            &token_program_id,
+            &source,
+            &mint,
+            &destination,
+            &authority,
+            &signers.iter().collect::<Vec<_>>(),
+            amount,
+            decimals,
+            fee,
+        )
+        .unwrap()
+    }
+
+    fn build_withdraw_withheld_tokens_from_mint(
+        &self,
+        token_program_id: Pubkey,
+        mint: Pubkey,
+        destination: Pubkey,
+        authority: Pubkey,
+    ) -> Instruction {
+        spl_token_2022::extension::transfer_fee::instruction::withdraw_withheld_tokens_from_mint(
+            &token_program_id,
+            &mint,
+            &destination,
+            &authority,
+            &[],
+        )
+        .unwrap()
+    }
+
+    fn build_withdraw_withheld_tokens_from_accounts(
+        &self,
+        token_program_id: Pubkey,
+        mint: Pubkey,
+        destination: Pubkey,
+        authority: Pubkey,
+        sources: &[Pubkey],
+    ) -> Instruction {
+        spl_token_2022::extension::transfer_fee::instruction::withdraw_withheld_tokens_from_accounts(
+            &token_program_id,
+            &mint,
+            &destination,
+            &authority,
+            &[],
+            &sources.iter().collect::<Vec<_>>(),
+        )
+        .unwrap()
+    }
+
+    fn build_harvest_withheld_tokens_to_mint(
+        &self,
+        token_program_id: Pubkey,
+        mint: Pubkey,
+        sources: &[Pubkey],
+    ) -> Instruction {
+        spl_token_2022::extension::transfer_fee::instruction::harvest_withheld_tokens_to_mint(
+            &token_program_id,
+            &mint,
+            &sources.iter().collect::<Vec<_>>(),
+        )
+        .unwrap()
+    }
+
+    fn build_initialize_metadata_pointer(
+        &self,
+        token_program_id: Pubkey,
+        mint: Pubkey,
+        authority: Option<Pubkey>,
+        metadata_address: Option<Pubkey>,
+    ) -> Instruction {
+        spl_token_2022::extension::metadata_pointer::instruction::initialize(
+            &token_program_id,
+            &mint,
+            authority,
+            metadata_address,
+        )
+        .unwrap()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn build_initialize_token_metadata(
+        &self,
+        token_program_id: Pubkey,
+        mint: Pubkey,
+        update_authority: Pubkey,
+        mint_authority: Pubkey,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Instruction {
+        spl_token_metadata_interface::instruction::initialize(
+            &token_program_id,
+            &mint,
+            &update_authority,
+            &mint,
+            &mint_authority,
+            name,
+            symbol,
+            uri,
+        )
+    }
+
+    fn build_update_token_metadata_field(
+        &self,
+        token_program_id: Pubkey,
+        mint: Pubkey,
+        update_authority: Pubkey,
+        field: Field,
+        value: String,
+    ) -> Instruction {
+        spl_token_metadata_interface::instruction::update_field(
+            &token_program_id,
+            &mint,
+            &update_authority,
+            field,
+            value,
+        )
+    }
+
+    fn build_remove_token_metadata_key(
+        &self,
+        token_program_id: Pubkey,
+        mint: Pubkey,
+        update_authority: Pubkey,
+        key: String,
+        idempotent: bool,
+    ) -> Instruction {
+        spl_token_metadata_interface::instruction::remove_key(
+            &token_program_id,
+            &mint,
+            &update_authority,
+            key,
+            idempotent,
+        )
+    }
+
+    /// The mint authority is also the update authority.
+    #[allow(clippy::too_many_arguments)]
+    fn build_create_and_initialize_mint_with_metadata(
+        &self,
+        payer: Pubkey,
+        token_program_id: Pubkey,
+        mint: Pubkey,
+        mint_authority: Pubkey,
+        freeze_authority: Option<Pubkey>,
+        decimals: u8,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> ClientResult<[Instruction; 4]>
+    where
+        Self: GetMinimumBalanceForRentExemption,
+    {
+        let mint_len = mint_len(&[ExtensionType::MetadataPointer]);
+
+        let token_metadata = TokenMetadata {
+            update_authority: Some(mint_authority).try_into().unwrap(),
+            mint,
+            name,
+            symbol,
+            uri,
+            additional_metadata: Vec::new(),
+        };
+        // the token program reallocates the mint for the metadata, but it must be funded upfront
+        let metadata_len = token_metadata.tlv_size_of().unwrap();
+
+        let instructions = [
+            system_instruction::create_account(
+                &payer,
+                &mint,
+                self.get_minimum_balance_for_rent_exemption(mint_len + metadata_len)?,
+                mint_len as u64,
+                &token_program_id,
+            ),
+            self.build_initialize_metadata_pointer(
+                token_program_id,
+                mint,
+                Some(mint_authority),
+                Some(mint),
+            ),
+            self.build_initialize_mint(
+                token_program_id,
+                mint,
+                mint_authority,
+                freeze_authority,
+                decimals,
+            ),
+            self.build_initialize_token_metadata(
+                token_program_id,
+                mint,
+                mint_authority,
+                mint_authority,
+                token_metadata.name,
+                token_metadata.symbol,
+                token_metadata.uri,
+            ),
+        ];
+
+        Ok(instructions)
+    }
+}
+
+impl<C: ?Sized + Client> TokenInterfaceInstruction for C {}
+
+pub trait TokenInterfaceProcessor: Client {
+    fn process_create_and_initialize_mint(
+        &self,
+        payer: &impl FeePayer,
+        token_program_id: Pubkey,
+        mint: &impl Signer,
+        mint_authority: Pubkey,
+        freeze_authority: Option<Pubkey>,
+        decimals: u8,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetMinimumBalanceForRentExemption
+            + GetLatestBlockhash
+            + ProcessTransaction<ExecutionOutput>,
+    {
+        let instructions = self.build_create_and_initialize_mint(
+            payer.payer_pubkey(),
+            token_program_id,
+            mint.pubkey(),
+            mint_authority,
+            freeze_authority,
+            decimals,
+        )?;
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), mint];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_create_and_initialize_token_account(
+        &self,
+        payer: &impl FeePayer,
+        token_program_id: Pubkey,
+        token_account: &impl Signer,
+        mint: Pubkey,
+        owner: Pubkey,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetAccount
+            + GetMinimumBalanceForRentExemption
+            + GetLatestBlockhash
+            + ProcessTransaction<ExecutionOutput>,
+    {
+        let instructions = self.build_create_and_initialize_token_account(
+            payer.payer_pubkey(),
+            token_program_id,
+            token_account.pubkey(),
+            mint,
+            owner,
+        )?;
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), token_account];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_mint_to(
+        &self,
+        payer: &impl FeePayer,
+        token_account: Pubkey,
+        mint_authority: &impl Signer,
+        amount: u64,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetAccount + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let token_account_account = self.try_get_token_account(&token_account)?;
+        let token_program_id = *ReadableAccount::owner(&token_account_account);
+        let mint = token_account_account.mint;
+
+        let instructions = [self.build_mint_to(
+            token_program_id,
+            token_account,
+            mint,
+            mint_authority.pubkey(),
+            amount,
+        )];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), mint_authority];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_transfer_checked(
+        &self,
+        payer: &impl FeePayer,
+        source: Pubkey,
+        destination: Pubkey,
+        authority: &impl Signer,
+        signers: &[Pubkey],
+        amount: u64,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetAccount + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let source_account = self.try_get_token_account(&source)?;
+        let mint_account = self.try_get_mint(&source_account.mint)?;
+        let token_program_id = *ReadableAccount::owner(&mint_account);
+        let decimals = mint_account.decimals;
+
+        let mut instruction = self.build_transfer_checked(
+            token_program_id,
+            source,
+            mint_account.key(),
+            destination,
+            authority.pubkey(),
+            signers,
+            amount,
+            decimals,
+        );
+        self.add_transfer_hook_account_metas(
+            &mut instruction,
+            source,
+            mint_account.key(),
+            destination,
+            authority.pubkey(),
+            amount,
+        )?;
+
+        let instructions = [instruction];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), authority];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_wrap_native(
+        &self,
+        payer: &impl FeePayer,
+        source: &impl Signer,
+        destination: Pubkey,
+        lamports: u64,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetAccount + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let destination_account = self.try_get_token_account(&destination)?;
+        let token_program_id = self.try_get_token_program_id(&destination_account.mint)?;
+
+        let instructions = [
+            system_instruction::transfer(&source.pubkey(), &destination, lamports),
+            spl_token_2022::instruction::sync_native(&token_program_id, &destination).unwrap(),
+        ];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), source];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_unwrap_native(
+        &self,
+        payer: &impl FeePayer,
+        token_account: Pubkey,
+        recipient: Pubkey,
+        owner: &impl Signer,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetAccount + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let token_account_account = self.try_get_token_account(&token_account)?;
+        let token_program_id = *ReadableAccount::owner(&token_account_account);
+
+        let instructions =
+            [
+                self.build_close_account(
+                    token_program_id,
+                    token_account,
+                    recipient,
+                    owner.pubkey(),
+                ),
+            ];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), owner];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    /// See [`crate::token::TokenProcessor::process_with_ephemeral_wrapped_native`].
+    fn process_with_ephemeral_wrapped_native<F>(
+        &self,
+        payer: &impl FeePayer,
+        token_program_id: Pubkey,
+        owner: &impl Signer,
+        lamports: u64,
+        build_instructions: F,
+        signers: &[&dyn Signer],
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetMinimumBalanceForRentExemption
+            + GetLatestBlockhash
+            + ProcessTransaction<ExecutionOutput>,
+        F: FnOnce(Pubkey) -> Vec<Instruction>,
+    {
+        let wrapped = Keypair::new();
+        let native_mint = if token_program_id == spl_token_2022::id() {
+            spl_token_2022::native_mint::id()
+        } else {
+            spl_token::native_mint::id()
+        };
+        // the native mint has no extensions
+        let token_account_len = token_account_len(&[]);
+
+        // initializing a native account sets its amount to the lamports above the rent exemption
+        let mut instructions = vec![
+            system_instruction::create_account(
+                &owner.pubkey(),
+                &wrapped.pubkey(),
+                self.get_minimum_balance_for_rent_exemption(token_account_len)? + lamports,
+                token_account_len as u64,
+                &token_program_id,
+            ),
+            self.build_initialize_account(
+                token_program_id,
+                wrapped.pubkey(),
+                native_mint,
+                owner.pubkey(),
+            ),
+        ];
+        instructions.extend(build_instructions(wrapped.pubkey()));
+        instructions.push(self.build_close_account(
+            token_program_id,
+            wrapped.pubkey(),
+            owner.pubkey(),
+            owner.pubkey(),
+        ));
+
+        let mut all_signers: Vec<&dyn Signer> = vec![payer.payer(), owner, &wrapped];
+        all_signers.extend_from_slice(signers);
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &all_signers, &[])
+    }
+
+    fn process_create_and_initialize_multisig(
+        &self,
+        payer: &impl FeePayer,
+        token_program_id: Pubkey,
+        multisig: &impl Signer,
+        signers: &[Pubkey],
+        m: u8,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetMinimumBalanceForRentExemption
+            + GetLatestBlockhash
+            + ProcessTransaction<ExecutionOutput>,
+    {
+        let instructions = self.build_create_and_initialize_multisig(
+            payer.payer_pubkey(),
+            token_program_id,
+            multisig.pubkey(),
+            signers,
+            m,
+        )?;
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), multisig];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_mint_to_multisig(
+        &self,
+        payer: &impl FeePayer,
+        token_account: Pubkey,
+        multisig_authority: Pubkey,
+        signers: &[&dyn Signer],
+        amount: u64,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetAccount + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let token_account_account = self.try_get_token_account(&token_account)?;
+        let token_program_id = *ReadableAccount::owner(&token_account_account);
+        let mint = token_account_account.mint;
+
+        let signer_pubkeys: Vec<_> = signers.iter().map(|signer| signer.pubkey()).collect();
+        let instructions = [self.build_mint_to_multisig(
+            token_program_id,
+            token_account,
+            mint,
+            multisig_authority,
+            &signer_pubkeys,
+            amount,
+        )];
+
+        let mut all_signers = vec![payer.payer()];
+        all_signers.extend_from_slice(signers);
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &all_signers, &[])
+    }
+
+    fn process_transfer_checked_multisig(
+        &self,
+        payer: &impl FeePayer,
+        source: Pubkey,
+        destination: Pubkey,
+        multisig_authority: Pubkey,
+        signers: &[&dyn Signer],
+        amount: u64,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetAccount + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let source_account = self.try_get_token_account(&source)?;
+        let mint_account = self.try_get_mint(&source_account.mint)?;
+        let token_program_id = *ReadableAccount::owner(&mint_account);
+        let decimals = mint_account.decimals;
+
+        let signer_pubkeys: Vec<_> = signers.iter().map(|signer| signer.pubkey()).collect();
+        let mut instruction = self.build_transfer_checked(
+            token_program_id,
+            source,
+            mint_account.key(),
+            destination,
+            multisig_authority,
+            &signer_pubkeys,
+            amount,
+            decimals,
+        );
+        self.add_transfer_hook_account_metas(
+            &mut instruction,
+            source,
+            mint_account.key(),
+            destination,
+            multisig_authority,
+            amount,
+        )?;
+
+        let instructions = [instruction];
+
+        let mut all_signers = vec![payer.payer()];
+        all_signers.extend_from_slice(signers);
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &all_signers, &[])
+    }
+
+    fn process_approve(
+        &self,
+        payer: &impl FeePayer,
+        source: Pubkey,
+        delegate: Pubkey,
+        owner: &impl Signer,
+        amount: u64,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetAccount + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let source_account = self.try_get_token_account(&source)?;
+        let token_program_id = *ReadableAccount::owner(&source_account);
+
+        let instructions =
+            [self.build_approve(token_program_id, source, delegate, owner.pubkey(), amount)];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), owner];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_approve_checked(
+        &self,
+        payer: &impl FeePayer,
+        source: Pubkey,
+        delegate: Pubkey,
+        owner: &impl Signer,
+        amount: u64,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetAccount + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let source_account = self.try_get_token_account(&source)?;
+        let mint_account = self.try_get_mint(&source_account.mint)?;
+        let token_program_id = *ReadableAccount::owner(&mint_account);
+        let decimals = mint_account.decimals;
+
+        let instructions = [self.build_approve_checked(
+            token_program_id,
+            source,
+            mint_account.key(),
+            delegate,
+            owner.pubkey(),
+            amount,
+            decimals,
+        )];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), owner];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_revoke(
+        &self,
+        payer: &impl FeePayer,
+        source: Pubkey,
+        owner: &impl Signer,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetAccount + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let source_account = self.try_get_token_account(&source)?;
+        let token_program_id = *ReadableAccount::owner(&source_account);
+
+        let instructions = [self.build_revoke(token_program_id, source, owner.pubkey())];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), owner];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_freeze_account(
+        &self,
+        payer: &impl FeePayer,
+        token_account: Pubkey,
+        freeze_authority: &impl Signer,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetAccount + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let token_account_account = self.try_get_token_account(&token_account)?;
+        let token_program_id = *ReadableAccount::owner(&token_account_account);
+        let mint = token_account_account.mint;
+
+        let instructions = [self.build_freeze_account(
+            token_program_id,
+            token_account,
+            mint,
+            freeze_authority.pubkey(),
+        )];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), freeze_authority];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_thaw_account(
+        &self,
+        payer: &impl FeePayer,
+        token_account: Pubkey,
+        freeze_authority: &impl Signer,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetAccount + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let token_account_account = self.try_get_token_account(&token_account)?;
+        let token_program_id = *ReadableAccount::owner(&token_account_account);
+        let mint = token_account_account.mint;
+
+        let instructions = [self.build_thaw_account(
+            token_program_id,
+            token_account,
+            mint,
+            freeze_authority.pubkey(),
+        )];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), freeze_authority];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_set_authority(
+        &self,
+        payer: &impl FeePayer,
+        owned: Pubkey,
+        new_authority: Option<Pubkey>,
+        authority_type: AuthorityType,
+        current_authority: &impl Signer,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetAccount + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        // the owned account is either a mint or a token account
+        let token_program_id = self.try_get_account(&owned)?.owner;
+
+        let instructions = [self.build_set_authority(
+            token_program_id,
+            owned,
+            new_authority,
+            authority_type,
+            current_authority.pubkey(),
+        )];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), current_authority];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_burn(
+        &self,
+        payer: &impl FeePayer,
+        token_account: Pubkey,
+        authority: &impl Signer,
+        amount: u64,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetAccount + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let token_account_account = self.try_get_token_account(&token_account)?;
+        let token_program_id = *ReadableAccount::owner(&token_account_account);
+        let mint = token_account_account.mint;
+
+        let instructions = [self.build_burn(
+            token_program_id,
+            token_account,
+            mint,
+            authority.pubkey(),
+            amount,
+        )];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), authority];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_close_account(
+        &self,
+        payer: &impl FeePayer,
+        token_account: Pubkey,
+        destination: Pubkey,
+        authority: &impl Signer,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetAccount + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let token_account_account = self.try_get_token_account(&token_account)?;
+        let token_program_id = *ReadableAccount::owner(&token_account_account);
+
+        let instructions = [self.build_close_account(
+            token_program_id,
+            token_account,
+            destination,
+            authority.pubkey(),
+        )];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), authority];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    /// Like `spl-token gc`, skipping accounts with withheld fees or another close authority.
+    fn process_close_empty_token_accounts(
+        &self,
+        payer: &impl FeePayer,
+        owner: &impl Signer,
+    ) -> ClientResult<Vec<ExecutionOutput>>
+    where
+        Self: GetProgramAccounts
+            + GetMultipleAccounts
+            + GetLatestBlockhash
+            + ProcessTransaction<ExecutionOutput>,
+    {
+        let owner_pubkey = owner.pubkey();
+
+        let mut instructions = Vec::new();
+        for owned in self.get_token_accounts_for_owner(&owner_pubkey)? {
+            let (token_account, account, data) = AnchorAccount::as_parts(&owned.token_account);
+            if data.amount > 0 || data.is_frozen() {
+                continue;
+            }
+            let close_authority: Option<Pubkey> = data.close_authority.into();
+            if close_authority.is_some_and(|authority| authority != owner_pubkey) {
+                continue;
+            }
+
+            let withheld_amount: u64 = unpack_extension::<
+                spl_token_2022::state::Account,
+                TransferFeeAmount,
+            >(token_account, &account.data)?
+            .map(|amount| amount.withheld_amount.into())
+            .unwrap_or_default();
+            if withheld_amount > 0 {
+                continue;
+            }
+
+            instructions.push(self.build_close_account(
+                owned.token_program_id,
+                *token_account,
+                owner_pubkey,
+                owner_pubkey,
+            ));
+        }
+
+        if instructions.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), owner];
+        self.compile_transaction_batch(&instructions, &payer.payer_pubkey(), &signers, &[])?
+            .into_iter()
+            .map(|transaction| self.process_transaction(transaction))
+            .collect()
+    }
+
+    fn process_burn_all_and_close_account(
+        &self,
+        payer: &impl FeePayer,
+        token_account: Pubkey,
+        destination: Pubkey,
+        authority: &impl Signer,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetAccount + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let token_account_account = self.try_get_token_account(&token_account)?;
+        let token_program_id = *ReadableAccount::owner(&token_account_account);
+
+        let mut instructions = Vec::with_capacity(2);
+        // closing alone reclaims the lamports of native accounts
+        if token_account_account.amount > 0 && !token_account_account.is_native() {
+            instructions.push(self.build_burn(
+                token_program_id,
+                token_account,
+                token_account_account.mint,
+                authority.pubkey(),
+                token_account_account.amount,
+            ));
+        }
+        instructions.push(self.build_close_account(
+            token_program_id,
+            token_account,
+            destination,
+            authority.pubkey(),
+        ));
+
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), authority];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn process_create_and_initialize_mint_with_options(
+        &self,
+        payer: &impl FeePayer,
+        token_program_id: Pubkey,
+        mint: &impl Signer,
+        mint_authority: Pubkey,
+        freeze_authority: Option<Pubkey>,
+        decimals: u8,
+        options: &MintExtensionOptions,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetMinimumBalanceForRentExemption
+            + GetLatestBlockhash
+            + ProcessTransaction<ExecutionOutput>,
+    {
+        let instructions = self.build_create_and_initialize_mint_with_options(
+            payer.payer_pubkey(),
+            token_program_id,
+            mint.pubkey(),
+            mint_authority,
+            freeze_authority,
+            decimals,
+            options,
+        )?;
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), mint];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn process_create_and_initialize_mint_with_transfer_fee(
+        &self,
+        payer: &impl FeePayer,
+        token_program_id: Pubkey,
+        mint: &impl Signer,
+        mint_authority: Pubkey,
+        freeze_authority: Option<Pubkey>,
+        decimals: u8,
+        transfer_fee_config_authority: Option<Pubkey>,
+        withdraw_withheld_authority: Option<Pubkey>,
+        transfer_fee_basis_points: u16,
+        maximum_fee: u64,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetMinimumBalanceForRentExemption
+            + GetLatestBlockhash
+            + ProcessTransaction<ExecutionOutput>,
+    {
+        let instructions = self.build_create_and_initialize_mint_with_transfer_fee(
+            payer.payer_pubkey(),
+            token_program_id,
+            mint.pubkey(),
+            mint_authority,
+            freeze_authority,
+            decimals,
+            transfer_fee_config_authority,
+            withdraw_withheld_authority,
+            transfer_fee_basis_points,
+            maximum_fee,
+        )?;
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), mint];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_transfer_checked_with_fee(
+        &self,
+        payer: &impl FeePayer,
+        source: Pubkey,
+        destination: Pubkey,
+        authority: &impl Signer,
+        signers: &[Pubkey],
+        amount: u64,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetAccount + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let source_account = self.try_get_token_account(&source)?;
+        let mint_account = self.try_get_mint(&source_account.mint)?;
+        let token_program_id = *ReadableAccount::owner(&mint_account);
+        let decimals = mint_account.decimals;
+        let fee = self.get_transfer_fee(&mint_account.key(), amount)?;
+
+        let mut instruction = self.build_transfer_checked_with_fee(
+            token_program_id,
+            source,
+            mint_account.key(),
+            destination,
+            authority.pubkey(),
+            signers,
+            amount,
+            decimals,
+            fee,
+        );
+        self.add_transfer_hook_account_metas(
+            &mut instruction,
+            source,
+            mint_account.key(),
+            destination,
+            authority.pubkey(),
+            amount,
+        )?;
+
+        let instructions = [instruction];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), authority];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_withdraw_withheld_tokens_from_mint(
+        &self,
+        payer: &impl FeePayer,
+        mint: Pubkey,
+        destination: Pubkey,
+        withdraw_withheld_authority: &impl Signer,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetAccount + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let token_program_id = self.try_get_token_program_id(&mint)?;
+
+        let instructions = [self.build_withdraw_withheld_tokens_from_mint(
+            token_program_id,
+            mint,
+            destination,
+            withdraw_withheld_authority.pubkey(),
+        )];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), withdraw_withheld_authority];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_withdraw_withheld_tokens_from_accounts(
+        &self,
+        payer: &impl FeePayer,
+        mint: Pubkey,
+        destination: Pubkey,
+        withdraw_withheld_authority: &impl Signer,
+        sources: &[Pubkey],
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetAccount + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let token_program_id = self.try_get_token_program_id(&mint)?;
+
+        let instructions = [self.build_withdraw_withheld_tokens_from_accounts(
+            token_program_id,
+            mint,
+            destination,
+            withdraw_withheld_authority.pubkey(),
+            sources,
+        )];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), withdraw_withheld_authority];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_harvest_withheld_tokens_to_mint(
+        &self,
+        payer: &impl FeePayer,
+        mint: Pubkey,
+        sources: &[Pubkey],
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetAccount + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
     {
-        let instructions = [
-            system_instruction::create_account(
-                &payer,
-                &token_account,
-                self.get_minimum_balance_for_rent_exemption(TOKEN_ACCOUNT_LEN)?,
-                TOKEN_ACCOUNT_LEN as u64,
-                &token_program_id,
-            ),
-            spl_token_2022::instruction::initialize_account(
-                &token_program_id,
-                &token_account,
-                &mint,
-                &owner,
-            )
-            .unwrap(),
-        ];
+        let token_program_id = self.try_get_token_program_id(&mint)?;
 
-        Ok(instructions)
+        let instructions =
+            [self.build_harvest_withheld_tokens_to_mint(token_program_id, mint, sources)];
+        let signers: Vec<&dyn Signer> = vec![payer.payer()];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
     }
-}
 
-impl<C: ?Sized + Client> TokenInterfaceInstruction for C {}
-
-pub trait TokenInterfaceProcessor: Client {
-    fn process_create_and_initialize_mint(
+    #[allow(clippy::too_many_arguments)]
+    fn process_create_and_initialize_mint_with_metadata(
         &self,
-        payer: &impl Signer,
+        payer: &impl FeePayer,
         token_program_id: Pubkey,
         mint: &impl Signer,
-        mint_authority: Pubkey,
+        mint_authority: &impl Signer,
         freeze_authority: Option<Pubkey>,
         decimals: u8,
+        name: String,
+        symbol: String,
+        uri: String,
     ) -> ClientResult<ExecutionOutput>
     where
         Self: GetMinimumBalanceForRentExemption
             + GetLatestBlockhash
             + ProcessTransaction<ExecutionOutput>,
     {
-        let instructions = self.build_create_and_initialize_mint(
-            payer.pubkey(),
+        let instructions = self.build_create_and_initialize_mint_with_metadata(
+            payer.payer_pubkey(),
             token_program_id,
             mint.pubkey(),
-            mint_authority,
+            mint_authority.pubkey(),
             freeze_authority,
             decimals,
+            name,
+            symbol,
+            uri,
         )?;
-        let signers: Vec<&dyn Signer> = vec![payer, mint];
-        self.compiling_process_transaction(&instructions, &payer.pubkey(), &signers, &[])
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), mint, mint_authority];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
     }
 
-    fn process_create_and_initialize_token_account(
+    fn process_update_token_metadata_field(
         &self,
-        payer: &impl Signer,
-        token_program_id: Pubkey,
-        token_account: &impl Signer,
+        payer: &impl FeePayer,
         mint: Pubkey,
-        owner: Pubkey,
+        update_authority: &impl Signer,
+        field: Field,
+        value: String,
     ) -> ClientResult<ExecutionOutput>
     where
-        Self: GetMinimumBalanceForRentExemption
+        Self: GetAccount
+            + GetMinimumBalanceForRentExemption
             + GetLatestBlockhash
             + ProcessTransaction<ExecutionOutput>,
     {
-        let instructions = self.build_create_and_initialize_token_account(
-            payer.pubkey(),
-            token_program_id,
-            token_account.pubkey(),
-            mint,
-            owner,
-        )?;
-        let signers: Vec<&dyn Signer> = vec![payer, token_account];
-        self.compiling_process_transaction(&instructions, &payer.pubkey(), &signers, &[])
-    }
-
-    fn process_mint_to(
-        &self,
-        payer: &impl Signer,
-        token_account: Pubkey,
-        mint_authority: &impl Signer,
-        amount: u64,
-    ) -> ClientResult<ExecutionOutput>
-    where
-        Self: GetAccount + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
-    {
-        let token_account_account = self.try_get_token_account(&token_account)?;
-        let token_program_id = *ReadableAccount::owner(&token_account_account);
-        let mint = token_account_account.mint;
+        let token_program_id = self.try_get_token_program_id(&mint)?;
+        let shortfall = token_metadata_rent_shortfall(self, &mint, |token_metadata| {
+            token_metadata.update(field.clone(), value.clone())
+        })?;
 
-        let instructions = [self.build_mint_to(
+        let mut instructions = Vec::with_capacity(2);
+        if shortfall > 0 {
+            instructions.push(system_instruction::transfer(
+                &payer.payer_pubkey(),
+                &mint,
+                shortfall,
+            ));
+        }
+        instructions.push(self.build_update_token_metadata_field(
             token_program_id,
-            token_account,
             mint,
-            mint_authority.pubkey(),
-            amount,
-        )];
-        let signers: Vec<&dyn Signer> = vec![payer, mint_authority];
-        self.compiling_process_transaction(&instructions, &payer.pubkey(), &signers, &[])
+            update_authority.pubkey(),
+            field,
+            value,
+        ));
+
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), update_authority];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
     }
 
-    fn process_transfer_checked(
+    fn process_remove_token_metadata_key(
         &self,
-        payer: &impl Signer,
-        source: Pubkey,
-        destination: Pubkey,
-        authority: &impl Signer,
-        signers: &[Pubkey],
-        amount: u64,
+        payer: &impl FeePayer,
+        mint: Pubkey,
+        update_authority: &impl Signer,
+        key: String,
+        idempotent: bool,
     ) -> ClientResult<ExecutionOutput>
     where
         Self: GetAccount + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
     {
-        let source_account = self.try_get_token_account(&source)?;
-        let mint_account = self.try_get_mint(&source_account.mint)?;
-        let token_program_id = *ReadableAccount::owner(&mint_account);
-        let decimals = mint_account.decimals;
+        let token_program_id = self.try_get_token_program_id(&mint)?;
 
-        let instructions = [self.build_transfer_checked(
+        let instructions = [self.build_remove_token_metadata_key(
             token_program_id,
-            source,
-            mint_account.key(),
-            destination,
-            authority.pubkey(),
-            signers,
-            amount,
-            decimals,
+            mint,
+            update_authority.pubkey(),
+            key,
+            idempotent,
         )];
-        let signers: Vec<&dyn Signer> = vec![payer, authority];
-        self.compiling_process_transaction(&instructions, &payer.pubkey(), &signers, &[])
-    }
-
-    fn process_wrap_native(
-        &self,
-        payer: &impl Signer,
-        source: &impl Signer,
-        destination: Pubkey,
-        lamports: u64,
-    ) -> ClientResult<ExecutionOutput>
-    where
-        Self: GetAccount + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
-    {
-        let destination_account = self.try_get_token_account(&destination)?;
-        let token_program_id = self.try_get_token_program_id(&destination_account.mint)?;
-
-        let instructions = [
-            system_instruction::transfer(&source.pubkey(), &destination, lamports),
-            spl_token_2022::instruction::sync_native(&token_program_id, &destination).unwrap(),
-        ];
-        let signers: Vec<&dyn Signer> = vec![payer, source];
-        self.compiling_process_transaction(&instructions, &payer.pubkey(), &signers, &[])
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), update_authority];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
     }
 }
 
@@ -422,7 +2362,7 @@ pub trait TokenInterfaceSetter: Client {
 
         let account = self.packing_set_account(
             mint_pk,
-            self.minimum_balance_for_rent_exemption(MINT_LEN),
+            self.minimum_balance_for_rent_exemption(mint_len(&[])),
             token_program_id,
             &mint,
         );
@@ -430,6 +2370,99 @@ pub trait TokenInterfaceSetter: Client {
         AnchorAccount::try_from_account(mint_pk, account).unwrap()
     }
 
+    #[allow(clippy::too_many_arguments)]
+    fn set_mint_with_close_authority(
+        &mut self,
+        mint_pk: Pubkey,
+        mint_authority: Option<Pubkey>,
+        supply: u64,
+        decimals: u8,
+        freeze_authority: Option<Pubkey>,
+        close_authority: Pubkey,
+    ) -> AnchorAccount<Mint>
+    where
+        Self: SetAccount + HasRent,
+    {
+        let mint_len = mint_len(&[ExtensionType::MintCloseAuthority]);
+
+        let mut account = Account::new(
+            self.minimum_balance_for_rent_exemption(mint_len),
+            mint_len,
+            &spl_token_2022::id(),
+        );
+        let mut state =
+            StateWithExtensionsMut::<spl_token_2022::state::Mint>::unpack_uninitialized(
+                &mut account.data,
+            )
+            .unwrap();
+        state
+            .init_extension::<MintCloseAuthority>(true)
+            .unwrap()
+            .close_authority = Some(close_authority).try_into().unwrap();
+        state.base = spl_token_2022::state::Mint {
+            mint_authority: mint_authority.into(),
+            supply,
+            decimals,
+            is_initialized: true,
+            freeze_authority: freeze_authority.into(),
+        };
+        state.pack_base();
+        state.init_account_type().unwrap();
+
+        self.set_account(mint_pk, account.clone());
+
+        AnchorAccount::try_from_account(mint_pk, account).unwrap()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn set_mint_with_options(
+        &mut self,
+        mint_pk: Pubkey,
+        mint_authority: Option<Pubkey>,
+        supply: u64,
+        decimals: u8,
+        freeze_authority: Option<Pubkey>,
+        options: &MintExtensionOptions,
+    ) -> AnchorAccount<Mint>
+    where
+        Self: SetAccount + HasRent,
+    {
+        let mint_len = mint_len(&options.extension_types());
+
+        let mut account = Account::new(
+            self.minimum_balance_for_rent_exemption(mint_len),
+            mint_len,
+            &spl_token_2022::id(),
+        );
+        let mut state =
+            StateWithExtensionsMut::<spl_token_2022::state::Mint>::unpack_uninitialized(
+                &mut account.data,
+            )
+            .unwrap();
+        if let Some(delegate) = options.permanent_delegate {
+            state
+                .init_extension::<PermanentDelegate>(true)
+                .unwrap()
+                .delegate = Some(delegate).try_into().unwrap();
+        }
+        if options.non_transferable {
+            state.init_extension::<NonTransferable>(true).unwrap();
+        }
+        state.base = spl_token_2022::state::Mint {
+            mint_authority: mint_authority.into(),
+            supply,
+            decimals,
+            is_initialized: true,
+            freeze_authority: freeze_authority.into(),
+        };
+        state.pack_base();
+        state.init_account_type().unwrap();
+
+        self.set_account(mint_pk, account.clone());
+
+        AnchorAccount::try_from_account(mint_pk, account).unwrap()
+    }
+
     fn set_token_account(
         &mut self,
         token_program_id: Pubkey,
@@ -441,7 +2474,162 @@ pub trait TokenInterfaceSetter: Client {
     where
         Self: SetAccount + HasRent,
     {
-        let rent_exempt = self.minimum_balance_for_rent_exemption(TOKEN_ACCOUNT_LEN);
+        self.set_token_account_with_state(
+            token_program_id,
+            token_account_pk,
+            mint,
+            owner,
+            amount,
+            |_| {},
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn set_token_account_with_delegate(
+        &mut self,
+        token_program_id: Pubkey,
+        token_account_pk: Pubkey,
+        mint: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+        delegate: Pubkey,
+        delegated_amount: u64,
+    ) -> AnchorAccount<TokenAccount>
+    where
+        Self: SetAccount + HasRent,
+    {
+        self.set_token_account_with_state(
+            token_program_id,
+            token_account_pk,
+            mint,
+            owner,
+            amount,
+            |state| {
+                state.delegate = Some(delegate).into();
+                state.delegated_amount = delegated_amount;
+            },
+        )
+    }
+
+    fn set_frozen_token_account(
+        &mut self,
+        token_program_id: Pubkey,
+        token_account_pk: Pubkey,
+        mint: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+    ) -> AnchorAccount<TokenAccount>
+    where
+        Self: SetAccount + HasRent,
+    {
+        self.set_token_account_with_state(
+            token_program_id,
+            token_account_pk,
+            mint,
+            owner,
+            amount,
+            |state| state.state = spl_token_2022::state::AccountState::Frozen,
+        )
+    }
+
+    fn set_token_account_with_options(
+        &mut self,
+        token_program_id: Pubkey,
+        token_account_pk: Pubkey,
+        mint: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+        options: TokenAccountOptions,
+    ) -> AnchorAccount<TokenAccount>
+    where
+        Self: SetAccount + HasRent,
+    {
+        self.set_token_account_with_state(
+            token_program_id,
+            token_account_pk,
+            mint,
+            owner,
+            amount,
+            |state| {
+                if let Some((delegate, delegated_amount)) = options.delegate {
+                    state.delegate = Some(delegate).into();
+                    state.delegated_amount = delegated_amount;
+                }
+                if options.frozen {
+                    state.state = spl_token_2022::state::AccountState::Frozen;
+                }
+                state.close_authority = options.close_authority.into();
+            },
+        )
+    }
+
+    fn set_token_account_with_state<F>(
+        &mut self,
+        token_program_id: Pubkey,
+        token_account_pk: Pubkey,
+        mint: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+        modify: F,
+    ) -> AnchorAccount<TokenAccount>
+    where
+        Self: SetAccount + HasRent,
+        F: FnOnce(&mut spl_token_2022::state::Account),
+    {
+        self.set_token_account_with_extensions(
+            token_program_id,
+            token_account_pk,
+            mint,
+            owner,
+            amount,
+            &[],
+            modify,
+        )
+        .unwrap()
+    }
+
+    /// The mint has to be set beforehand.
+    fn set_token_account_for_mint(
+        &mut self,
+        token_program_id: Pubkey,
+        token_account_pk: Pubkey,
+        mint: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+    ) -> ClientResult<AnchorAccount<TokenAccount>>
+    where
+        Self: GetAccount + SetAccount + HasRent,
+    {
+        let extension_types = self.get_required_token_account_extension_types(&mint)?;
+        self.set_token_account_with_extensions(
+            token_program_id,
+            token_account_pk,
+            mint,
+            owner,
+            amount,
+            &extension_types,
+            |_| {},
+        )
+    }
+
+    /// Fails on extensions not initialized along with the account.
+    #[allow(clippy::too_many_arguments)]
+    fn set_token_account_with_extensions<F>(
+        &mut self,
+        token_program_id: Pubkey,
+        token_account_pk: Pubkey,
+        mint: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+        extension_types: &[ExtensionType],
+        modify: F,
+    ) -> ClientResult<AnchorAccount<TokenAccount>>
+    where
+        Self: SetAccount + HasRent,
+        F: FnOnce(&mut spl_token_2022::state::Account),
+    {
+        let token_account_len = token_account_len(extension_types);
+        let rent_exempt = self.minimum_balance_for_rent_exemption(token_account_len);
 
         let (lamports, is_native) = if spl_token::native_mint::check_id(&mint) {
             (rent_exempt + amount, Some(rent_exempt))
@@ -449,7 +2637,7 @@ pub trait TokenInterfaceSetter: Client {
             (rent_exempt, None)
         };
 
-        let token_account = spl_token_2022::state::Account {
+        let mut token_account = spl_token_2022::state::Account {
             mint,
             owner,
             amount,
@@ -459,11 +2647,42 @@ pub trait TokenInterfaceSetter: Client {
             delegated_amount: 0,
             close_authority: None.into(),
         };
+        modify(&mut token_account);
+
+        let mut account = Account::new(lamports, token_account_len, &token_program_id);
+        let mut state =
+            StateWithExtensionsMut::<spl_token_2022::state::Account>::unpack_uninitialized(
+                &mut account.data,
+            )
+            .unwrap();
+        for extension_type in extension_types {
+            let initialized = match extension_type {
+                ExtensionType::TransferFeeAmount => {
+                    state.init_extension::<TransferFeeAmount>(true).map(|_| ())
+                }
+                ExtensionType::NonTransferableAccount => state
+                    .init_extension::<NonTransferableAccount>(true)
+                    .map(|_| ()),
+                ExtensionType::ImmutableOwner => {
+                    state.init_extension::<ImmutableOwner>(true).map(|_| ())
+                }
+                ExtensionType::TransferHookAccount => state
+                    .init_extension::<TransferHookAccount>(true)
+                    .map(|_| ()),
+                ExtensionType::ConfidentialTransferFeeAmount => state
+                    .init_extension::<ConfidentialTransferFeeAmount>(true)
+                    .map(|_| ()),
+                _ => return Err(ClientError::AccountDidNotSerialize(token_account_pk)),
+            };
+            initialized.map_err(|_| ClientError::AccountDidNotSerialize(token_account_pk))?;
+        }
+        state.base = token_account;
+        state.pack_base();
+        state.init_account_type().unwrap();
 
-        let account =
-            self.packing_set_account(token_account_pk, lamports, token_program_id, &token_account);
+        self.set_account(token_account_pk, account.clone());
 
-        AnchorAccount::try_from_account(token_account_pk, account).unwrap()
+        Ok(AnchorAccount::try_from_account(token_account_pk, account).unwrap())
     }
 }
 
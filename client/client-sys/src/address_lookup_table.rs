@@ -1,22 +1,38 @@
 use std::borrow::Cow;
+use std::collections::BTreeSet;
+use std::thread;
+use std::time::{Duration, Instant};
 
+use solana_sdk::account::Account;
 use solana_sdk::address_lookup_table;
 use solana_sdk::address_lookup_table::instruction::{
     close_lookup_table, create_lookup_table, deactivate_lookup_table, extend_lookup_table,
 };
-use solana_sdk::address_lookup_table::state::AddressLookupTable;
-use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::address_lookup_table::state::{
+    AddressLookupTable, LookupTableMeta, LookupTableStatus, LOOKUP_TABLE_MAX_ADDRESSES,
+};
+use solana_sdk::address_lookup_table_account::AddressLookupTableAccount;
 use solana_sdk::instruction::Instruction;
+use solana_sdk::message::{CompileError, VersionedMessage};
+use solana_sdk::packet::PACKET_DATA_SIZE;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signer::Signer;
+use solana_sdk::signers::Signers;
+use solana_sdk::transaction::VersionedTransaction;
 
-use dexter_client_api::base::executor::ProcessTransaction;
+use dexter_client_api::base::executor::{ProcessTransaction, SimulateTransaction};
 use dexter_client_api::base::getter::{
-    GetAccount, GetLatestBlockhash, GetProgramAccounts, Memcmp, ProgramAccountsFilter,
+    GetAccount, GetLatestBlockhash, GetMultipleAccounts, GetProgramAccounts, Memcmp,
+    ProgramAccountsFilter,
 };
-use dexter_client_api::errors::{ClientError, ClientResult};
+use dexter_client_api::base::setter::{HasRent, SetAccount, WarpSlot};
+use dexter_client_api::errors::{AddressLookupError, ClientError, ClientResult};
 use dexter_client_api::execution::ExecutionOutput;
-use dexter_client_api::exts::executor::CompilingProcessTransaction;
+use dexter_client_api::exts::executor::{
+    CompileTransaction, CompileTransactionBatch, CompilingProcessTransactionWithBudget,
+    ComputeBudgetConfig,
+};
+use dexter_client_api::exts::signer::FeePayer;
 use dexter_client_api::Client;
 
 use crate::sysvar::SysvarGetter;
@@ -24,16 +40,11 @@ use crate::sysvar::SysvarGetter;
 const LOOKUP_TABLE_META_AUTHORITY_OFFSET: usize = 22;
 const RECENT_SLOT_INDEX: usize = 1;
 
-const COMPUTE_BUDGET_UNITS: u32 = 2_000;
 const COMPUTE_BUDGET_PRICE: u64 = 1_000_000;
 
-fn with_compute_budget(instruction: Instruction) -> [Instruction; 3] {
-    [
-        ComputeBudgetInstruction::set_compute_unit_limit(COMPUTE_BUDGET_UNITS),
-        ComputeBudgetInstruction::set_compute_unit_price(COMPUTE_BUDGET_PRICE),
-        instruction,
-    ]
-}
+const MAX_EXTEND_ADDRESSES: usize = 20;
+const ACTIVATION_TIMEOUT: Duration = Duration::from_secs(15);
+const STATUS_POLL_INTERVAL: Duration = Duration::from_millis(500);
 
 pub trait AddressLookupTableGetter: Client {
     fn get_address_lookup_table(&self, pubkey: &Pubkey) -> ClientResult<Option<AddressLookupTable>>
@@ -50,6 +61,30 @@ pub trait AddressLookupTableGetter: Client {
         Ok(Some(convert_to_owned(lookup_table)))
     }
 
+    fn try_get_address_lookup_table_accounts(
+        &self,
+        pubkeys: &[Pubkey],
+    ) -> ClientResult<Vec<AddressLookupTableAccount>>
+    where
+        Self: GetMultipleAccounts,
+    {
+        let accounts = self.get_multiple_accounts(pubkeys)?;
+
+        pubkeys
+            .iter()
+            .zip(accounts)
+            .map(|(pubkey, account)| {
+                let account = account.ok_or(ClientError::AccountNotFound(*pubkey))?;
+                let lookup_table = AddressLookupTable::deserialize(&account.data)
+                    .map_err(|_| ClientError::AccountDidNotDeserialize(*pubkey))?;
+                Ok(AddressLookupTableAccount {
+                    key: *pubkey,
+                    addresses: lookup_table.addresses.into_owned(),
+                })
+            })
+            .collect()
+    }
+
     fn get_address_lookup_tables_for_authority(
         &self,
         authority: &Pubkey,
@@ -75,100 +110,472 @@ pub trait AddressLookupTableGetter: Client {
 
         Ok(lookup_tables)
     }
+
+    fn get_lookup_table_status(&self, pubkey: &Pubkey) -> ClientResult<Option<LookupTableStatus>>
+    where
+        Self: GetAccount,
+    {
+        let Some(lookup_table) = self.get_address_lookup_table(pubkey)? else {
+            return Ok(None);
+        };
+
+        let current_slot = self.try_get_sysvar_clock()?.slot;
+        let slot_hashes = self.try_get_sysvar_slothashes()?;
+
+        Ok(Some(lookup_table.meta.status(current_slot, &slot_hashes)))
+    }
+
+    /// Only on a cluster, see [`AddressLookupTableSetter::warp_until_usable`] for a bank.
+    fn wait_until_usable(
+        &self,
+        pubkey: &Pubkey,
+        timeout: Duration,
+    ) -> ClientResult<AddressLookupTable<'static>>
+    where
+        Self: GetAccount,
+    {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            if let Some(lookup_table) = self.get_address_lookup_table(pubkey)? {
+                let current_slot = self.try_get_sysvar_clock()?.slot;
+                let slot_hashes = self.try_get_sysvar_slothashes()?;
+
+                let is_usable = lookup_table.meta.is_active(current_slot, &slot_hashes)
+                    && current_slot > lookup_table.meta.last_extended_slot;
+                if is_usable {
+                    return Ok(convert_to_owned(lookup_table));
+                }
+            }
+
+            if Instant::now() >= deadline {
+                return Err(ClientError::Timeout(
+                    "waiting for the lookup table to be usable",
+                ));
+            }
+
+            thread::sleep(STATUS_POLL_INTERVAL);
+        }
+    }
+
+    fn wait_until_closable(&self, pubkey: &Pubkey, timeout: Duration) -> ClientResult<()>
+    where
+        Self: GetAccount,
+    {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            let status = self
+                .get_lookup_table_status(pubkey)?
+                .ok_or(ClientError::AccountNotFound(*pubkey))?;
+            if status == LookupTableStatus::Deactivated {
+                return Ok(());
+            }
+
+            if Instant::now() >= deadline {
+                return Err(ClientError::Timeout(
+                    "waiting for the lookup table to be closable",
+                ));
+            }
+
+            thread::sleep(STATUS_POLL_INTERVAL);
+        }
+    }
 }
 
 impl<C: ?Sized + Client> AddressLookupTableGetter for C {}
 
+pub trait AddressLookupTableSetter: Client {
+    /// The addresses are usable right away, except the last one of a full table at slot 0.
+    fn set_address_lookup_table(
+        &mut self,
+        lookup_table_address: Pubkey,
+        authority: Option<Pubkey>,
+        addresses: &[Pubkey],
+    ) -> AddressLookupTableAccount
+    where
+        Self: SetAccount + HasRent,
+    {
+        assert!(addresses.len() <= LOOKUP_TABLE_MAX_ADDRESSES);
+
+        let lookup_table = AddressLookupTable {
+            meta: LookupTableMeta {
+                authority,
+                // addresses before the start index are active even within the last extended slot
+                last_extended_slot: 0,
+                last_extended_slot_start_index: u8::try_from(addresses.len()).unwrap_or(u8::MAX),
+                ..LookupTableMeta::default()
+            },
+            addresses: Cow::Borrowed(addresses),
+        };
+        let data = lookup_table.serialize_for_tests().unwrap();
+
+        let account = Account {
+            lamports: self.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: address_lookup_table::program::id(),
+            executable: false,
+            rent_epoch: u64::MAX,
+        };
+        self.set_account(lookup_table_address, account);
+
+        AddressLookupTableAccount {
+            key: lookup_table_address,
+            addresses: addresses.to_vec(),
+        }
+    }
+
+    fn warp_until_usable(&mut self, pubkey: &Pubkey) -> ClientResult<AddressLookupTable<'static>>
+    where
+        Self: GetAccount + WarpSlot,
+    {
+        let lookup_table = self
+            .get_address_lookup_table(pubkey)?
+            .map(convert_to_owned)
+            .ok_or(ClientError::AccountNotFound(*pubkey))?;
+
+        let current_slot = self.try_get_sysvar_clock()?.slot;
+        if current_slot <= lookup_table.meta.last_extended_slot {
+            self.warp_to_slot(lookup_table.meta.last_extended_slot + 1)?;
+        }
+
+        let current_slot = self.try_get_sysvar_clock()?.slot;
+        let slot_hashes = self.try_get_sysvar_slothashes()?;
+        if !lookup_table.meta.is_active(current_slot, &slot_hashes) {
+            return Err(AddressLookupError::LookupTableAccountNotFound.into());
+        }
+
+        Ok(lookup_table)
+    }
+}
+
+impl<C: ?Sized + Client> AddressLookupTableSetter for C {}
+
 pub trait AddressLookupTableProcessor: Client {
     fn process_create_lookup_table(
         &self,
-        payer: &impl Signer,
+        payer: &impl FeePayer,
         authority: Pubkey,
     ) -> ClientResult<Pubkey>
     where
-        Self: GetAccount + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+        Self: GetAccount
+            + GetLatestBlockhash
+            + SimulateTransaction<ExecutionOutput>
+            + ProcessTransaction<ExecutionOutput>,
     {
         let (recent_slot, _) = self.try_get_sysvar_slothashes()?.slot_hashes()[RECENT_SLOT_INDEX];
 
         let (instruction, lookup_table_address) =
-            create_lookup_table(authority, payer.pubkey(), recent_slot);
-        let instructions = with_compute_budget(instruction);
+            create_lookup_table(authority, payer.payer_pubkey(), recent_slot);
+        let instructions = [instruction];
+        let budget = ComputeBudgetConfig::with_unit_price(COMPUTE_BUDGET_PRICE);
 
-        let signers = vec![payer];
-        self.compiling_process_transaction(&instructions, &payer.pubkey(), &signers, &[])?;
+        let signers = vec![payer.payer()];
+        self.compiling_process_transaction_with_budget(
+            &instructions,
+            &payer.payer_pubkey(),
+            &signers,
+            &[],
+            &budget,
+        )?;
 
         Ok(lookup_table_address)
     }
 
     fn process_extend_lookup_table(
         &self,
-        payer: &impl Signer,
-        authority: &impl Signer,
+        payer: &impl FeePayer,
+        authority: &dyn Signer,
         lookup_table_address: Pubkey,
         new_addresses: Vec<Pubkey>,
     ) -> ClientResult<()>
     where
-        Self: GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+        Self: GetLatestBlockhash
+            + SimulateTransaction<ExecutionOutput>
+            + ProcessTransaction<ExecutionOutput>,
     {
         let instruction = extend_lookup_table(
             lookup_table_address,
             authority.pubkey(),
-            Some(payer.pubkey()),
+            Some(payer.payer_pubkey()),
             new_addresses,
         );
-        let instructions = with_compute_budget(instruction);
+        let instructions = [instruction];
+        let budget = ComputeBudgetConfig::with_unit_price(COMPUTE_BUDGET_PRICE);
 
-        let signers: Vec<&dyn Signer> = if payer.pubkey() == authority.pubkey() {
-            vec![payer]
+        let signers: Vec<&dyn Signer> = if payer.payer_pubkey() == authority.pubkey() {
+            vec![payer.payer()]
         } else {
-            vec![payer, authority]
+            vec![payer.payer(), authority]
         };
-        self.compiling_process_transaction(&instructions, &payer.pubkey(), &signers, &[])?;
+        self.compiling_process_transaction_with_budget(
+            &instructions,
+            &payer.payer_pubkey(),
+            &signers,
+            &[],
+            &budget,
+        )?;
 
         Ok(())
     }
 
     fn process_deactivate_lookup_table(
         &self,
-        payer: &impl Signer,
-        authority: &impl Signer,
+        payer: &impl FeePayer,
+        authority: &dyn Signer,
         lookup_table_address: Pubkey,
     ) -> ClientResult<()>
     where
-        Self: GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+        Self: GetLatestBlockhash
+            + SimulateTransaction<ExecutionOutput>
+            + ProcessTransaction<ExecutionOutput>,
     {
         let instruction = deactivate_lookup_table(lookup_table_address, authority.pubkey());
-        let instructions = with_compute_budget(instruction);
+        let instructions = [instruction];
+        let budget = ComputeBudgetConfig::with_unit_price(COMPUTE_BUDGET_PRICE);
 
-        let signers: Vec<&dyn Signer> = vec![payer, authority];
-        self.compiling_process_transaction(&instructions, &payer.pubkey(), &signers, &[])?;
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), authority];
+        self.compiling_process_transaction_with_budget(
+            &instructions,
+            &payer.payer_pubkey(),
+            &signers,
+            &[],
+            &budget,
+        )?;
 
         Ok(())
     }
 
     fn process_close_lookup_table(
         &self,
-        payer: &impl Signer,
-        authority: &impl Signer,
+        payer: &impl FeePayer,
+        authority: &dyn Signer,
         lookup_table_address: Pubkey,
         recipient_address: Pubkey,
     ) -> ClientResult<()>
     where
-        Self: GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+        Self: GetLatestBlockhash
+            + SimulateTransaction<ExecutionOutput>
+            + ProcessTransaction<ExecutionOutput>,
     {
         let instruction =
             close_lookup_table(lookup_table_address, authority.pubkey(), recipient_address);
-        let instructions = with_compute_budget(instruction);
+        let instructions = [instruction];
+        let budget = ComputeBudgetConfig::with_unit_price(COMPUTE_BUDGET_PRICE);
 
-        let signers: Vec<&dyn Signer> = vec![payer, authority];
-        self.compiling_process_transaction(&instructions, &payer.pubkey(), &signers, &[])?;
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), authority];
+        self.compiling_process_transaction_with_budget(
+            &instructions,
+            &payer.payer_pubkey(),
+            &signers,
+            &[],
+            &budget,
+        )?;
 
         Ok(())
     }
+
+    fn process_close_deactivated_lookup_tables(
+        &self,
+        payer: &impl FeePayer,
+        authority: &dyn Signer,
+        recipient_address: Pubkey,
+    ) -> ClientResult<Vec<Pubkey>>
+    where
+        Self: GetAccount
+            + GetProgramAccounts
+            + GetLatestBlockhash
+            + ProcessTransaction<ExecutionOutput>,
+    {
+        let lookup_tables = self.get_address_lookup_tables_for_authority(&authority.pubkey())?;
+
+        let current_slot = self.try_get_sysvar_clock()?.slot;
+        let slot_hashes = self.try_get_sysvar_slothashes()?;
+
+        let closable: Vec<Pubkey> = lookup_tables
+            .into_iter()
+            .filter(|(_, lookup_table)| {
+                lookup_table.meta.status(current_slot, &slot_hashes)
+                    == LookupTableStatus::Deactivated
+            })
+            .map(|(pubkey, _)| pubkey)
+            .collect();
+        if closable.is_empty() {
+            return Ok(closable);
+        }
+
+        let instructions: Vec<_> = closable
+            .iter()
+            .map(|pubkey| close_lookup_table(*pubkey, authority.pubkey(), recipient_address))
+            .collect();
+
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), authority];
+        let transactions =
+            self.compile_transaction_batch(&instructions, &payer.payer_pubkey(), &signers, &[])?;
+        for transaction in transactions {
+            self.process_transaction(transaction)?.result?;
+        }
+
+        Ok(closable)
+    }
 }
 
 impl<C: ?Sized + Client> AddressLookupTableProcessor for C {}
 
+pub trait CompileTransactionWithLookupTables: Client + GetLatestBlockhash {
+    fn compile_transaction_with_lookup_tables<S>(
+        &self,
+        instructions: &[Instruction],
+        payer: &Pubkey,
+        signers: &S,
+        lookup_table_addresses: &[Pubkey],
+    ) -> ClientResult<VersionedTransaction>
+    where
+        Self: GetMultipleAccounts,
+        S: Signers + ?Sized,
+    {
+        let address_lookup_table_accounts =
+            self.try_get_address_lookup_table_accounts(lookup_table_addresses)?;
+        self.compile_transaction(instructions, payer, signers, &address_lookup_table_accounts)
+    }
+
+    fn compile_message_with_lookup_tables(
+        &self,
+        instructions: &[Instruction],
+        payer: &Pubkey,
+        lookup_table_addresses: &[Pubkey],
+    ) -> ClientResult<VersionedMessage>
+    where
+        Self: GetMultipleAccounts,
+    {
+        let address_lookup_table_accounts =
+            self.try_get_address_lookup_table_accounts(lookup_table_addresses)?;
+        self.compile_message(instructions, payer, &address_lookup_table_accounts)
+    }
+}
+
+impl<C: ?Sized + Client + GetLatestBlockhash> CompileTransactionWithLookupTables for C {}
+
+pub trait AutoLookupTableProcessor: Client {
+    fn compiling_process_transaction_with_auto_lookup_table<T, S>(
+        &self,
+        instructions: &[Instruction],
+        payer: &impl FeePayer,
+        signers: &S,
+        address_lookup_table_accounts: &[AddressLookupTableAccount],
+    ) -> ClientResult<(T, Option<Pubkey>)>
+    where
+        Self: GetAccount
+            + GetLatestBlockhash
+            + SimulateTransaction<ExecutionOutput>
+            + ProcessTransaction<ExecutionOutput>
+            + ProcessTransaction<T>,
+        S: Signers + ?Sized,
+    {
+        if let Some(transaction) = try_compile_fitting_transaction(
+            self,
+            instructions,
+            &payer.payer_pubkey(),
+            signers,
+            address_lookup_table_accounts,
+        )? {
+            let output = ProcessTransaction::<T>::process_transaction(self, transaction)?;
+            return Ok((output, None));
+        }
+
+        let lookup_table_address = self.process_create_lookup_table(payer, payer.payer_pubkey())?;
+
+        let addresses = lookup_table_candidates(instructions, address_lookup_table_accounts);
+        for chunk in addresses.chunks(MAX_EXTEND_ADDRESSES) {
+            self.process_extend_lookup_table(
+                payer,
+                payer.payer(),
+                lookup_table_address,
+                chunk.to_vec(),
+            )?;
+        }
+
+        let lookup_table = self.wait_lookup_table_activation(&lookup_table_address)?;
+
+        let mut address_lookup_table_accounts = address_lookup_table_accounts.to_vec();
+        address_lookup_table_accounts.push(AddressLookupTableAccount {
+            key: lookup_table_address,
+            addresses: lookup_table.addresses.into_owned(),
+        });
+
+        let transaction = self.compile_transaction(
+            instructions,
+            &payer.payer_pubkey(),
+            signers,
+            &address_lookup_table_accounts,
+        )?;
+        let output = ProcessTransaction::<T>::process_transaction(self, transaction)?;
+
+        Ok((output, Some(lookup_table_address)))
+    }
+
+    fn wait_lookup_table_activation(
+        &self,
+        lookup_table_address: &Pubkey,
+    ) -> ClientResult<AddressLookupTable<'static>>
+    where
+        Self: GetAccount,
+    {
+        self.wait_until_usable(lookup_table_address, ACTIVATION_TIMEOUT)
+    }
+}
+
+impl<C: ?Sized + Client> AutoLookupTableProcessor for C {}
+
+fn try_compile_fitting_transaction<C, S>(
+    client: &C,
+    instructions: &[Instruction],
+    payer: &Pubkey,
+    signers: &S,
+    address_lookup_table_accounts: &[AddressLookupTableAccount],
+) -> ClientResult<Option<VersionedTransaction>>
+where
+    C: ?Sized + CompileTransaction,
+    S: Signers + ?Sized,
+{
+    let result =
+        client.compile_transaction(instructions, payer, signers, address_lookup_table_accounts);
+
+    let transaction = match result {
+        Ok(transaction) => transaction,
+        Err(ClientError::CompileError(CompileError::AccountIndexOverflow)) => return Ok(None),
+        Err(err) => return Err(err),
+    };
+
+    let size = bincode::serialized_size(&transaction).unwrap_or(u64::MAX);
+    if size > PACKET_DATA_SIZE as u64 {
+        return Ok(None);
+    }
+
+    Ok(Some(transaction))
+}
+
+fn lookup_table_candidates(
+    instructions: &[Instruction],
+    address_lookup_table_accounts: &[AddressLookupTableAccount],
+) -> Vec<Pubkey> {
+    let program_ids: BTreeSet<_> = instructions.iter().map(|ix| ix.program_id).collect();
+    let already_loaded: BTreeSet<_> = address_lookup_table_accounts
+        .iter()
+        .flat_map(|table| table.addresses.iter().copied())
+        .collect();
+
+    let mut seen = BTreeSet::new();
+    instructions
+        .iter()
+        .flat_map(|ix| ix.accounts.iter())
+        .filter(|meta| !meta.is_signer)
+        .map(|meta| meta.pubkey)
+        .filter(|key| !program_ids.contains(key) && !already_loaded.contains(key))
+        .filter(|key| seen.insert(*key))
+        .collect()
+}
+
 fn convert_to_owned(lookup_table: AddressLookupTable<'_>) -> AddressLookupTable<'static> {
     let AddressLookupTable { meta, addresses } = lookup_table;
     AddressLookupTable {
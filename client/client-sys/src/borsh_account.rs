@@ -0,0 +1,162 @@
+use std::io;
+use std::ops::Deref;
+
+use borsh::BorshDeserialize;
+
+use solana_sdk::account::{Account, AccountSharedData, ReadableAccount};
+use solana_sdk::borsh1::try_from_slice_unchecked;
+use solana_sdk::clock::Epoch;
+use solana_sdk::pubkey::Pubkey;
+
+use dexter_client_api::base::getter::{GetAccount, GetProgramAccounts, ProgramAccountsFilter};
+use dexter_client_api::errors::{ClientError, ClientResult};
+use dexter_client_api::Client;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BorshAccount<T> {
+    key: Pubkey,
+    account: Account,
+    data: T,
+}
+
+impl<T> BorshAccount<T> {
+    // native programs commonly allocate spare space, so trailing bytes are allowed
+    pub fn try_from_account(key: Pubkey, account: Account) -> io::Result<Self>
+    where
+        T: BorshDeserialize,
+    {
+        let data = try_from_slice_unchecked(&account.data)?;
+        Ok(Self { key, account, data })
+    }
+
+    pub fn as_parts(this: &Self) -> (&Pubkey, &Account, &T) {
+        (&this.key, &this.account, &this.data)
+    }
+
+    pub fn into_account(this: Self) -> Account {
+        this.account
+    }
+
+    pub fn into_data(this: Self) -> T {
+        this.data
+    }
+
+    pub fn into_parts(this: Self) -> (Pubkey, Account, T) {
+        (this.key, this.account, this.data)
+    }
+}
+
+impl<T: BorshDeserialize> TryFrom<(Pubkey, Account)> for BorshAccount<T> {
+    type Error = io::Error;
+
+    fn try_from((key, account): (Pubkey, Account)) -> io::Result<Self> {
+        Self::try_from_account(key, account)
+    }
+}
+
+impl<T> From<BorshAccount<T>> for Account {
+    fn from(account: BorshAccount<T>) -> Self {
+        account.account
+    }
+}
+
+impl<T> From<BorshAccount<T>> for AccountSharedData {
+    fn from(account: BorshAccount<T>) -> Self {
+        account.account.into()
+    }
+}
+
+impl<T> AsRef<Pubkey> for BorshAccount<T> {
+    fn as_ref(&self) -> &Pubkey {
+        &self.key
+    }
+}
+
+impl<T> AsRef<Account> for BorshAccount<T> {
+    fn as_ref(&self) -> &Account {
+        &self.account
+    }
+}
+
+impl<T> Deref for BorshAccount<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<T> ReadableAccount for BorshAccount<T> {
+    fn lamports(&self) -> u64 {
+        self.account.lamports()
+    }
+
+    fn data(&self) -> &[u8] {
+        self.account.data()
+    }
+
+    fn owner(&self) -> &Pubkey {
+        self.account.owner()
+    }
+
+    fn executable(&self) -> bool {
+        self.account.executable()
+    }
+
+    fn rent_epoch(&self) -> Epoch {
+        self.account.rent_epoch()
+    }
+
+    fn to_account_shared_data(&self) -> AccountSharedData {
+        self.account.to_account_shared_data()
+    }
+}
+
+pub trait BorshGetter: Client {
+    fn get_borsh_account<T>(&self, pubkey: &Pubkey) -> ClientResult<Option<BorshAccount<T>>>
+    where
+        Self: GetAccount,
+        T: BorshDeserialize,
+    {
+        let Some(account) = self.get_account(pubkey)? else {
+            return Ok(None);
+        };
+
+        let account = BorshAccount::try_from_account(*pubkey, account)
+            .map_err(|_| ClientError::AccountDidNotDeserialize(*pubkey))?;
+
+        Ok(Some(account))
+    }
+
+    fn try_get_borsh_account<T>(&self, pubkey: &Pubkey) -> ClientResult<BorshAccount<T>>
+    where
+        Self: GetAccount,
+        T: BorshDeserialize,
+    {
+        match self.get_borsh_account(pubkey)? {
+            Some(account) => Ok(account),
+            None => Err(ClientError::AccountNotFound(*pubkey)),
+        }
+    }
+
+    /// `filters` need to single out the accounts of `T`, e.g. by data size.
+    fn get_borsh_program_accounts<T>(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+    ) -> ClientResult<Vec<BorshAccount<T>>>
+    where
+        Self: GetProgramAccounts,
+        T: BorshDeserialize,
+    {
+        self.get_program_accounts(program_id, filters)?
+            .into_iter()
+            .map(|(key, account)| {
+                BorshAccount::try_from_account(key, account)
+                    .map_err(|_| ClientError::AccountDidNotDeserialize(key))
+            })
+            .collect()
+    }
+}
+
+impl<C: ?Sized + Client> BorshGetter for C {}
@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+
+use solana_sdk::config::program as config_program;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::short_vec::decode_shortu16_len;
+
+use dexter_client_api::base::getter::{
+    GetAccount, GetProgramAccounts, Memcmp, ProgramAccountsFilter,
+};
+use dexter_client_api::errors::{ClientError, ClientResult};
+use dexter_client_api::Client;
+
+// the first key of every validator info account, followed by the identity
+pub const VALIDATOR_INFO_KEY: Pubkey =
+    solana_sdk::pubkey!("Va1idator1nfo111111111111111111111111111111");
+
+// the short_vec length of the two keys, each followed by its signer flag
+const VALIDATOR_INFO_KEYS_PREFIX: u8 = 2;
+const IDENTITY_OFFSET: usize = 1 + 33;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidatorInfo {
+    pub info_pubkey: Pubkey,
+    pub identity: Pubkey,
+    pub name: Option<String>,
+    pub website: Option<String>,
+    pub details: Option<String>,
+    pub icon_url: Option<String>,
+    pub keybase_username: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ValidatorInfoPayload {
+    name: Option<String>,
+    website: Option<String>,
+    details: Option<String>,
+    icon_url: Option<String>,
+    keybase_username: Option<String>,
+}
+
+pub trait ValidatorInfoGetter: Client {
+    fn get_validator_info(&self, info_pubkey: &Pubkey) -> ClientResult<Option<ValidatorInfo>>
+    where
+        Self: GetAccount,
+    {
+        let Some(account) = self.get_account(info_pubkey)? else {
+            return Ok(None);
+        };
+
+        let validator_info = parse_validator_info(info_pubkey, &account.data)
+            .ok_or(ClientError::AccountDidNotDeserialize(*info_pubkey))?;
+
+        Ok(Some(validator_info))
+    }
+
+    /// Skips malformed infos.
+    fn get_validator_infos(&self) -> ClientResult<Vec<ValidatorInfo>>
+    where
+        Self: GetProgramAccounts,
+    {
+        scan_validator_infos(self, None)
+    }
+
+    /// Returns the first one found if the validator republished under a new account.
+    fn get_validator_info_for_identity(
+        &self,
+        identity: &Pubkey,
+    ) -> ClientResult<Option<ValidatorInfo>>
+    where
+        Self: GetProgramAccounts,
+    {
+        let filter = ProgramAccountsFilter::Memcmp(Memcmp::new_raw_bytes(
+            IDENTITY_OFFSET,
+            identity.to_bytes().to_vec(),
+        ));
+        Ok(scan_validator_infos(self, Some(filter))?.into_iter().next())
+    }
+}
+
+impl<C: ?Sized + Client> ValidatorInfoGetter for C {}
+
+fn scan_validator_infos<C: ?Sized + GetProgramAccounts>(
+    client: &C,
+    extra_filter: Option<ProgramAccountsFilter>,
+) -> ClientResult<Vec<ValidatorInfo>> {
+    let mut prefix = vec![VALIDATOR_INFO_KEYS_PREFIX];
+    prefix.extend_from_slice(VALIDATOR_INFO_KEY.as_ref());
+
+    let mut filters = vec![ProgramAccountsFilter::Memcmp(Memcmp::new_raw_bytes(
+        0, prefix,
+    ))];
+    filters.extend(extra_filter);
+
+    let validator_infos = client
+        .get_program_accounts(&config_program::id(), Some(filters))?
+        .into_iter()
+        .filter_map(|(pubkey, account)| parse_validator_info(&pubkey, &account.data))
+        .collect();
+
+    Ok(validator_infos)
+}
+
+// a short_vec of `(Pubkey, bool)` keys followed by the config, a json string for infos
+fn parse_validator_info(info_pubkey: &Pubkey, data: &[u8]) -> Option<ValidatorInfo> {
+    let (num_keys, mut offset) = decode_shortu16_len(data).ok()?;
+
+    let mut keys = Vec::with_capacity(num_keys);
+    for _ in 0..num_keys {
+        let key = Pubkey::try_from(data.get(offset..offset + 32)?).ok()?;
+        keys.push(key);
+        offset += 33;
+    }
+
+    let [info_key, identity] = keys.as_slice() else {
+        return None;
+    };
+    if *info_key != VALIDATOR_INFO_KEY {
+        return None;
+    }
+
+    let json: String = bincode::deserialize(data.get(offset..)?).ok()?;
+    let payload: ValidatorInfoPayload = serde_json::from_str(&json).ok()?;
+
+    Some(ValidatorInfo {
+        info_pubkey: *info_pubkey,
+        identity: *identity,
+        name: payload.name,
+        website: payload.website,
+        details: payload.details,
+        icon_url: payload.icon_url,
+        keybase_username: payload.keybase_username,
+    })
+}
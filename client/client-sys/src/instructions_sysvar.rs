@@ -0,0 +1,185 @@
+use std::str::FromStr;
+
+use solana_sdk::inner_instruction::InnerInstructionsList;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::message::VersionedMessage;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::VersionedTransaction;
+
+use dexter_client_api::execution::{ExecutionEffect, ExecutionOutput};
+
+const TOP_LEVEL_INVOKE_LOG_SUFFIX: &str = " invoke [1]";
+
+/// Indexes hold only if the transaction is compiled from these instructions alone.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InstructionSequence {
+    instructions: Vec<Instruction>,
+}
+
+impl InstructionSequence {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, instruction: Instruction) -> usize {
+        self.instructions.push(instruction);
+        self.instructions.len() - 1
+    }
+
+    pub fn extend(&mut self, instructions: impl IntoIterator<Item = Instruction>) {
+        self.instructions.extend(instructions);
+    }
+
+    pub fn position(&self, program_id: &Pubkey) -> Option<usize> {
+        self.instructions
+            .iter()
+            .position(|instruction| instruction.program_id == *program_id)
+    }
+
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    pub fn into_instructions(self) -> Vec<Instruction> {
+        self.instructions
+    }
+}
+
+impl From<Vec<Instruction>> for InstructionSequence {
+    fn from(instructions: Vec<Instruction>) -> Self {
+        Self { instructions }
+    }
+}
+
+pub trait ExecutionInstructions {
+    /// `None` if the message loads addresses from lookup tables.
+    fn sysvar_instructions(&self) -> Option<Vec<Instruction>>;
+
+    fn has_sibling_instruction(&self, index: usize, program_id: &Pubkey) -> bool;
+
+    /// Ends at the failing instruction.
+    fn invoked_programs(&self) -> Vec<Pubkey>;
+
+    /// `None` without inner instructions or with lookup tables.
+    fn inner_programs(&self, index: usize) -> Option<Vec<Pubkey>>;
+}
+
+impl ExecutionInstructions for ExecutionOutput {
+    fn sysvar_instructions(&self) -> Option<Vec<Instruction>> {
+        decompile_instructions(&self.transaction)
+    }
+
+    fn has_sibling_instruction(&self, index: usize, program_id: &Pubkey) -> bool {
+        has_sibling_instruction(&self.transaction, index, program_id)
+    }
+
+    fn invoked_programs(&self) -> Vec<Pubkey> {
+        parse_invoked_programs(&self.logs)
+    }
+
+    fn inner_programs(&self, index: usize) -> Option<Vec<Pubkey>> {
+        inner_programs(&self.transaction, self.inner_instructions.as_ref()?, index)
+    }
+}
+
+impl ExecutionInstructions for ExecutionEffect {
+    fn sysvar_instructions(&self) -> Option<Vec<Instruction>> {
+        decompile_instructions(&self.transaction)
+    }
+
+    fn has_sibling_instruction(&self, index: usize, program_id: &Pubkey) -> bool {
+        has_sibling_instruction(&self.transaction, index, program_id)
+    }
+
+    fn invoked_programs(&self) -> Vec<Pubkey> {
+        parse_invoked_programs(&self.logs)
+    }
+
+    fn inner_programs(&self, index: usize) -> Option<Vec<Pubkey>> {
+        inner_programs(&self.transaction, self.inner_instructions.as_ref()?, index)
+    }
+}
+
+fn static_keys_only(message: &VersionedMessage) -> Option<&[Pubkey]> {
+    let uses_lookup_tables = message
+        .address_table_lookups()
+        .is_some_and(|lookups| !lookups.is_empty());
+    (!uses_lookup_tables).then(|| message.static_account_keys())
+}
+
+fn decompile_instructions(transaction: &VersionedTransaction) -> Option<Vec<Instruction>> {
+    let message = &transaction.message;
+    let keys = static_keys_only(message)?;
+
+    let instructions = message
+        .instructions()
+        .iter()
+        .map(|compiled| {
+            let accounts = compiled
+                .accounts
+                .iter()
+                .map(|&index| {
+                    let index = usize::from(index);
+                    AccountMeta {
+                        pubkey: keys[index],
+                        is_signer: message.is_signer(index),
+                        is_writable: message.is_maybe_writable(index),
+                    }
+                })
+                .collect();
+
+            Instruction {
+                program_id: keys[usize::from(compiled.program_id_index)],
+                accounts,
+                data: compiled.data.clone(),
+            }
+        })
+        .collect();
+
+    Some(instructions)
+}
+
+fn has_sibling_instruction(
+    transaction: &VersionedTransaction,
+    index: usize,
+    program_id: &Pubkey,
+) -> bool {
+    // program ids are never loaded from lookup tables
+    let message = &transaction.message;
+    message
+        .instructions()
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i != index)
+        .any(|(_, compiled)| {
+            message.static_account_keys()[usize::from(compiled.program_id_index)] == *program_id
+        })
+}
+
+fn parse_invoked_programs(logs: &[String]) -> Vec<Pubkey> {
+    logs.iter()
+        .filter_map(|log| log.strip_prefix("Program "))
+        .filter_map(|log| log.strip_suffix(TOP_LEVEL_INVOKE_LOG_SUFFIX))
+        .filter_map(|program_id| Pubkey::from_str(program_id).ok())
+        .collect()
+}
+
+fn inner_programs(
+    transaction: &VersionedTransaction,
+    inner_instructions: &InnerInstructionsList,
+    index: usize,
+) -> Option<Vec<Pubkey>> {
+    let keys = static_keys_only(&transaction.message)?;
+
+    let programs = inner_instructions
+        .get(index)
+        .map(|inner| {
+            inner
+                .iter()
+                .map(|inner| keys[usize::from(inner.instruction.program_id_index)])
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(programs)
+}
@@ -1,7 +1,15 @@
 pub mod address_lookup_table;
+pub mod borsh_account;
+pub mod config;
 pub mod feature;
+pub mod instructions_sysvar;
+pub mod memo;
 pub mod pack;
 pub mod program;
 pub mod remote;
+pub mod replay;
+pub mod snapshot;
+pub mod system;
 pub mod sysvar;
+pub mod vote;
 pub mod wallet;
@@ -0,0 +1 @@
+pub use dexter_client_api::exts::memo::MemoInstruction;
@@ -1,14 +1,43 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Instant;
+
+use solana_sdk::account::Account;
 use solana_sdk::bpf_loader;
 use solana_sdk::bpf_loader_deprecated;
 use solana_sdk::bpf_loader_upgradeable::{self, UpgradeableLoaderState};
-use solana_sdk::loader_v4::{self, LoaderV4State};
+use solana_sdk::clock::Slot;
+use solana_sdk::hash::{hash, Hash};
+use solana_sdk::loader_v4::{self, LoaderV4State, LoaderV4Status};
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::TransactionError;
 
-use dexter_client_api::base::getter::GetAccount;
+use dexter_client_api::base::executor::{ProcessTransaction, SendTransaction};
+use dexter_client_api::base::getter::{
+    GetAccount, GetBlockHeight, GetLatestBlockhash, GetLatestBlockhashWithExpiry,
+    GetMinimumBalanceForRentExemption, GetSignatureStatuses,
+};
+use dexter_client_api::base::setter::{HasRent, SetAccount};
 use dexter_client_api::errors::{ClientError, ClientResult};
+use dexter_client_api::execution::ExecutionOutput;
+use dexter_client_api::exts::executor::{
+    CompileTransactionBatch, CompilingProcessTransaction, ResendConfig,
+};
 use dexter_client_api::exts::getter::GetAccountExt;
+use dexter_client_api::exts::signer::FeePayer;
 use dexter_client_api::Client;
 
+// leaves room for a `write` signed by both the payer and the authority
+const WRITE_CHUNK_SIZE: usize = 900;
+
+// sent under the same blockhash, which has to stay valid until all of them land
+const WRITES_PER_BLOCKHASH: usize = 100;
+
 pub trait ProgramGetter: Client {
     fn get_program(&self, program_id: &Pubkey) -> ClientResult<Option<Vec<u8>>>
     where
@@ -32,13 +61,21 @@ pub trait ProgramGetter: Client {
 
             let programdata_account = self.try_get_account(&programdata_address)?;
 
-            programdata_account.data[UpgradeableLoaderState::size_of_programdata_metadata()..]
+            programdata_account
+                .data
+                .get(UpgradeableLoaderState::size_of_programdata_metadata()..)
+                .ok_or(ClientError::AccountDidNotDeserialize(programdata_address))?
                 .to_vec()
         } else if loader_id == loader_v4::id() {
-            program_account.data[LoaderV4State::program_data_offset()..].to_vec()
-        } else {
-            assert!(loader_id == bpf_loader::id() || loader_id == bpf_loader_deprecated::id());
+            program_account
+                .data
+                .get(LoaderV4State::program_data_offset()..)
+                .ok_or(ClientError::AccountDidNotDeserialize(*program_id))?
+                .to_vec()
+        } else if loader_id == bpf_loader::id() || loader_id == bpf_loader_deprecated::id() {
             program_account.data
+        } else {
+            return Err(ClientError::AccountDidNotDeserialize(*program_id));
         };
 
         Ok(Some(program))
@@ -53,6 +90,553 @@ pub trait ProgramGetter: Client {
             None => Err(ClientError::AccountNotFound(*program_id)),
         }
     }
+
+    fn get_program_metadata(&self, program_id: &Pubkey) -> ClientResult<Option<ProgramMetadata>>
+    where
+        Self: GetAccount,
+    {
+        let Some(program_account) = self.get_account(program_id)? else {
+            return Ok(None);
+        };
+
+        let loader_id = program_account.owner;
+        let metadata = if loader_id == bpf_loader_upgradeable::id() {
+            let program_state: UpgradeableLoaderState = bincode::deserialize(&program_account.data)
+                .map_err(|_| ClientError::AccountDidNotDeserialize(*program_id))?;
+
+            let UpgradeableLoaderState::Program {
+                programdata_address,
+            } = program_state
+            else {
+                return Err(ClientError::AccountDidNotDeserialize(*program_id));
+            };
+
+            let programdata_account = self.try_get_account(&programdata_address)?;
+            let programdata_state: UpgradeableLoaderState =
+                bincode::deserialize(&programdata_account.data)
+                    .map_err(|_| ClientError::AccountDidNotDeserialize(programdata_address))?;
+
+            let UpgradeableLoaderState::ProgramData {
+                slot,
+                upgrade_authority_address,
+            } = programdata_state
+            else {
+                return Err(ClientError::AccountDidNotDeserialize(programdata_address));
+            };
+
+            ProgramMetadata {
+                loader: ProgramLoader::Upgradeable,
+                upgrade_authority: upgrade_authority_address,
+                slot: Some(slot),
+                data_len: programdata_account
+                    .data
+                    .len()
+                    .saturating_sub(UpgradeableLoaderState::size_of_programdata_metadata()),
+            }
+        } else if loader_id == loader_v4::id() {
+            let data = &program_account.data;
+            let offset = LoaderV4State::program_data_offset();
+            if data.len() < offset {
+                return Err(ClientError::AccountDidNotDeserialize(*program_id));
+            }
+
+            // the state is laid out as repr(C) rather than serialized
+            let slot = u64::from_le_bytes(data[0..8].try_into().unwrap());
+            let authority = Pubkey::try_from(&data[8..40]).unwrap();
+            let status = u64::from_le_bytes(data[40..48].try_into().unwrap());
+
+            ProgramMetadata {
+                loader: ProgramLoader::V4,
+                upgrade_authority: (status != LoaderV4Status::Finalized as u64)
+                    .then_some(authority),
+                slot: Some(slot),
+                data_len: data.len() - offset,
+            }
+        } else {
+            let loader = if loader_id == bpf_loader::id() {
+                ProgramLoader::Bpf
+            } else if loader_id == bpf_loader_deprecated::id() {
+                ProgramLoader::Deprecated
+            } else {
+                return Err(ClientError::AccountDidNotDeserialize(*program_id));
+            };
+
+            ProgramMetadata {
+                loader,
+                upgrade_authority: None,
+                slot: None,
+                data_len: program_account.data.len(),
+            }
+        };
+
+        Ok(Some(metadata))
+    }
+
+    fn try_get_program_metadata(&self, program_id: &Pubkey) -> ClientResult<ProgramMetadata>
+    where
+        Self: GetAccount,
+    {
+        match self.get_program_metadata(program_id)? {
+            Some(metadata) => Ok(metadata),
+            None => Err(ClientError::AccountNotFound(*program_id)),
+        }
+    }
+
+    /// Ignores trailing zeros, which pad programdata accounts.
+    fn verify_program(
+        &self,
+        program_id: &Pubkey,
+        path: impl AsRef<Path>,
+    ) -> ClientResult<ProgramVerification>
+    where
+        Self: GetAccount,
+    {
+        let local = fs::read(path).map_err(|err| ClientError::DomainSpecific(Box::new(err)))?;
+        let onchain = self.try_get_program(program_id)?;
+
+        let local = trim_trailing_zeros(&local);
+        let onchain = trim_trailing_zeros(&onchain);
+
+        let first_mismatch = onchain
+            .iter()
+            .zip(local)
+            .position(|(onchain_byte, local_byte)| onchain_byte != local_byte)
+            .or_else(|| (onchain.len() != local.len()).then(|| onchain.len().min(local.len())));
+
+        Ok(ProgramVerification {
+            program_id: *program_id,
+            onchain_hash: hash(onchain),
+            local_hash: hash(local),
+            onchain_len: onchain.len(),
+            local_len: local.len(),
+            first_mismatch,
+        })
+    }
 }
 
 impl<C: ?Sized + Client> ProgramGetter for C {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProgramLoader {
+    Deprecated,
+    Bpf,
+    Upgradeable,
+    V4,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProgramMetadata {
+    pub loader: ProgramLoader,
+    // `None` if the program is immutable
+    pub upgrade_authority: Option<Pubkey>,
+    // recorded only by the upgradeable loader and loader v4
+    pub slot: Option<Slot>,
+    // excluding the metadata of the loader
+    pub data_len: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProgramVerification {
+    pub program_id: Pubkey,
+    pub onchain_hash: Hash,
+    pub local_hash: Hash,
+    pub onchain_len: usize,
+    pub local_len: usize,
+    pub first_mismatch: Option<usize>,
+}
+
+impl ProgramVerification {
+    pub fn is_match(&self) -> bool {
+        self.first_mismatch.is_none()
+    }
+}
+
+pub trait ProgramSetter: Client {
+    /// Returns the address of the programdata account.
+    fn set_upgradeable_program(
+        &mut self,
+        program_id: Pubkey,
+        program: &[u8],
+        upgrade_authority: Option<Pubkey>,
+        slot: Slot,
+    ) -> Pubkey
+    where
+        Self: SetAccount + HasRent,
+    {
+        let (programdata_address, _) =
+            Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id());
+
+        let programdata_account = {
+            let metadata_len = UpgradeableLoaderState::size_of_programdata_metadata();
+
+            let mut data = bincode::serialize(&UpgradeableLoaderState::ProgramData {
+                slot,
+                upgrade_authority_address: upgrade_authority,
+            })
+            .unwrap();
+            data.resize(metadata_len, 0);
+            data.extend_from_slice(program);
+
+            Account {
+                lamports: self.minimum_balance_for_rent_exemption(data.len()),
+                data,
+                owner: bpf_loader_upgradeable::id(),
+                executable: false,
+                rent_epoch: u64::MAX,
+            }
+        };
+
+        let program_account = {
+            let data = bincode::serialize(&UpgradeableLoaderState::Program {
+                programdata_address,
+            })
+            .unwrap();
+
+            Account {
+                lamports: self.minimum_balance_for_rent_exemption(data.len()),
+                data,
+                owner: bpf_loader_upgradeable::id(),
+                executable: true,
+                rent_epoch: u64::MAX,
+            }
+        };
+
+        self.set_account(programdata_address, programdata_account);
+        self.set_account(program_id, program_account);
+
+        programdata_address
+    }
+
+    fn set_program(&mut self, program_id: Pubkey, program: &[u8])
+    where
+        Self: SetAccount + HasRent,
+    {
+        let program_account = Account {
+            lamports: self.minimum_balance_for_rent_exemption(program.len()),
+            data: program.to_vec(),
+            owner: bpf_loader::id(),
+            executable: true,
+            rent_epoch: u64::MAX,
+        };
+
+        self.set_account(program_id, program_account);
+    }
+
+    fn set_program_from_file(
+        &mut self,
+        program_id: Pubkey,
+        path: impl AsRef<Path>,
+    ) -> io::Result<()>
+    where
+        Self: SetAccount + HasRent,
+    {
+        let program = fs::read(path)?;
+        self.set_program(program_id, &program);
+        Ok(())
+    }
+}
+
+impl<C: ?Sized + Client> ProgramSetter for C {}
+
+pub trait ProgramProcessor: Client {
+    fn process_write_buffer(
+        &self,
+        payer: &impl FeePayer,
+        buffer: &impl Signer,
+        authority: &impl Signer,
+        program: &[u8],
+    ) -> ClientResult<()>
+    where
+        Self: GetMinimumBalanceForRentExemption
+            + GetLatestBlockhashWithExpiry
+            + ProcessTransaction<ExecutionOutput>
+            + SendTransaction
+            + GetSignatureStatuses
+            + GetBlockHeight,
+    {
+        let lamports = self.get_minimum_balance_for_rent_exemption(
+            UpgradeableLoaderState::size_of_buffer(program.len()),
+        )?;
+        let instructions = bpf_loader_upgradeable::create_buffer(
+            &payer.payer_pubkey(),
+            &buffer.pubkey(),
+            &authority.pubkey(),
+            lamports,
+            program.len(),
+        )
+        .map_err(|err| ClientError::DomainSpecific(Box::new(err)))?;
+
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), buffer];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])?
+            .result?;
+
+        let writes: Vec<_> = program
+            .chunks(WRITE_CHUNK_SIZE)
+            .enumerate()
+            .map(|(i, chunk)| {
+                bpf_loader_upgradeable::write(
+                    &buffer.pubkey(),
+                    &authority.pubkey(),
+                    (i * WRITE_CHUNK_SIZE) as u32,
+                    chunk.to_vec(),
+                )
+            })
+            .collect();
+
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), authority];
+        let config = ResendConfig::default();
+
+        for group in writes.chunks(WRITES_PER_BLOCKHASH) {
+            // the batch compiles against a blockhash fetched no earlier than this one
+            let (_, last_valid_block_height) =
+                self.get_latest_blockhash_with_last_valid_block_height()?;
+            let mut pending =
+                self.compile_transaction_batch(group, &payer.payer_pubkey(), &signers, &[])?;
+
+            for transaction in &pending {
+                self.send_transaction(transaction.clone())?;
+            }
+            let mut resend_at = Instant::now() + config.resend_interval;
+
+            loop {
+                thread::sleep(config.poll_interval);
+
+                let signatures: Vec<_> = pending
+                    .iter()
+                    .map(|transaction| transaction.signatures[0])
+                    .collect();
+                let statuses = self.get_signature_statuses(&signatures)?;
+
+                let mut unconfirmed = Vec::with_capacity(pending.len());
+                for (transaction, status) in pending.into_iter().zip(statuses) {
+                    match status {
+                        Some(status) if status.satisfies_commitment(config.commitment) => {
+                            // landed writes may still have failed
+                            if let Some(err) = status.err {
+                                return Err(err.into());
+                            }
+                        }
+                        _ => unconfirmed.push(transaction),
+                    }
+                }
+                pending = unconfirmed;
+
+                if pending.is_empty() {
+                    break;
+                }
+
+                if self.get_block_height()? > last_valid_block_height {
+                    return Err(TransactionError::BlockhashNotFound.into());
+                }
+
+                if Instant::now() >= resend_at {
+                    for transaction in &pending {
+                        self.send_transaction(transaction.clone())?;
+                    }
+                    resend_at = Instant::now() + config.resend_interval;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// `max_data_len` defaults to the length of the program, leaving no room to grow.
+    fn process_deploy_program(
+        &self,
+        payer: &impl FeePayer,
+        program_keypair: &impl Signer,
+        program: &[u8],
+        max_data_len: Option<usize>,
+        upgrade_authority: &impl Signer,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetMinimumBalanceForRentExemption
+            + GetLatestBlockhashWithExpiry
+            + ProcessTransaction<ExecutionOutput>
+            + SendTransaction
+            + GetSignatureStatuses
+            + GetBlockHeight,
+    {
+        let buffer = Keypair::new();
+        let deployed = self
+            .process_write_buffer(payer, &buffer, upgrade_authority, program)
+            .and_then(|()| {
+                let program_lamports = self.get_minimum_balance_for_rent_exemption(
+                    UpgradeableLoaderState::size_of_program(),
+                )?;
+                let instructions = bpf_loader_upgradeable::deploy_with_max_program_len(
+                    &payer.payer_pubkey(),
+                    &program_keypair.pubkey(),
+                    &buffer.pubkey(),
+                    &upgrade_authority.pubkey(),
+                    program_lamports,
+                    max_data_len.unwrap_or(program.len()),
+                )
+                .map_err(|err| ClientError::DomainSpecific(Box::new(err)))?;
+
+                let signers: Vec<&dyn Signer> =
+                    vec![payer.payer(), program_keypair, upgrade_authority];
+                self.compiling_process_transaction(
+                    &instructions,
+                    &payer.payer_pubkey(),
+                    &signers,
+                    &[],
+                )
+            });
+
+        close_buffer_on_failure(self, payer, &buffer, upgrade_authority, &deployed);
+        deployed
+    }
+
+    fn process_upgrade_program(
+        &self,
+        payer: &impl FeePayer,
+        program_id: &Pubkey,
+        program: &[u8],
+        upgrade_authority: &impl Signer,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetMinimumBalanceForRentExemption
+            + GetLatestBlockhashWithExpiry
+            + ProcessTransaction<ExecutionOutput>
+            + SendTransaction
+            + GetSignatureStatuses
+            + GetBlockHeight,
+    {
+        let buffer = Keypair::new();
+        let upgraded = self
+            .process_write_buffer(payer, &buffer, upgrade_authority, program)
+            .and_then(|()| {
+                let instruction = bpf_loader_upgradeable::upgrade(
+                    program_id,
+                    &buffer.pubkey(),
+                    &upgrade_authority.pubkey(),
+                    &payer.payer_pubkey(),
+                );
+
+                let signers: Vec<&dyn Signer> = vec![payer.payer(), upgrade_authority];
+                self.compiling_process_transaction(
+                    &[instruction],
+                    &payer.payer_pubkey(),
+                    &signers,
+                    &[],
+                )
+            });
+
+        close_buffer_on_failure(self, payer, &buffer, upgrade_authority, &upgraded);
+        upgraded
+    }
+
+    fn process_set_upgrade_authority(
+        &self,
+        payer: &impl FeePayer,
+        program_id: &Pubkey,
+        current_authority: &impl Signer,
+        new_authority: Option<&Pubkey>,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let instruction = bpf_loader_upgradeable::set_upgrade_authority(
+            program_id,
+            &current_authority.pubkey(),
+            new_authority,
+        );
+
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), current_authority];
+        self.compiling_process_transaction(&[instruction], &payer.payer_pubkey(), &signers, &[])
+    }
+
+    /// The program can no longer be invoked nor redeployed at the same address.
+    fn process_close_program(
+        &self,
+        payer: &impl FeePayer,
+        program_id: &Pubkey,
+        upgrade_authority: &impl Signer,
+        recipient: &Pubkey,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let (programdata_address, _) =
+            Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id());
+        let instruction = bpf_loader_upgradeable::close_any(
+            &programdata_address,
+            recipient,
+            Some(&upgrade_authority.pubkey()),
+            Some(program_id),
+        );
+
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), upgrade_authority];
+        self.compiling_process_transaction(&[instruction], &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_close_buffer(
+        &self,
+        payer: &impl FeePayer,
+        buffer: &Pubkey,
+        authority: &impl Signer,
+        recipient: &Pubkey,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let instruction = bpf_loader_upgradeable::close(buffer, recipient, &authority.pubkey());
+
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), authority];
+        self.compiling_process_transaction(&[instruction], &payer.payer_pubkey(), &signers, &[])
+    }
+}
+
+impl<C: ?Sized + Client> ProgramProcessor for C {}
+
+/// Looks in `SBF_OUT_DIR` if set, or else in `target/deploy` of the current workspace.
+pub fn find_deploy_artifact(name: &str) -> Option<PathBuf> {
+    let file_name = format!("{name}.so");
+
+    if let Some(out_dir) = env::var_os("SBF_OUT_DIR") {
+        let path = PathBuf::from(out_dir).join(&file_name);
+        return path.is_file().then_some(path);
+    }
+
+    // the outermost manifest is the workspace root, while tests run in their package directory
+    let current_dir = env::current_dir().ok()?;
+    let workspace_root = current_dir
+        .ancestors()
+        .filter(|dir| dir.join("Cargo.toml").is_file())
+        .last()?;
+
+    let target_dir = match env::var_os("CARGO_TARGET_DIR") {
+        Some(target_dir) => workspace_root.join(target_dir),
+        None => workspace_root.join("target"),
+    };
+
+    let path = target_dir.join("deploy").join(file_name);
+    path.is_file().then_some(path)
+}
+
+// the buffer may not exist if creating it failed, so failing to close it is ignored
+fn close_buffer_on_failure<C>(
+    client: &C,
+    payer: &impl FeePayer,
+    buffer: &Keypair,
+    authority: &impl Signer,
+    processed: &ClientResult<ExecutionOutput>,
+) where
+    C: ?Sized + GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+{
+    if processed.as_ref().is_ok_and(ExecutionOutput::is_success) {
+        return;
+    }
+
+    let _ = client.process_close_buffer(payer, &buffer.pubkey(), authority, &payer.payer_pubkey());
+}
+
+fn trim_trailing_zeros(program: &[u8]) -> &[u8] {
+    let len = program
+        .iter()
+        .rposition(|byte| *byte != 0)
+        .map_or(0, |i| i + 1);
+    &program[..len]
+}
@@ -1,12 +1,28 @@
+use std::borrow::Cow;
+
 use solana_rpc_client::rpc_client::RpcClient;
 use solana_sdk::account::Account;
+use solana_sdk::address_lookup_table;
+use solana_sdk::address_lookup_table::state::{AddressLookupTable, LookupTableMeta};
+use solana_sdk::bpf_loader_upgradeable::{self, UpgradeableLoaderState};
+use solana_sdk::clock::Slot;
+use solana_sdk::message::VersionedMessage;
+use solana_sdk::native_loader;
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::sysvar;
 
+use dexter_client_api::base::getter::{
+    GetMultipleAccounts, GetProgramAccounts, ProgramAccountsFilter,
+};
 use dexter_client_api::base::setter::SetAccount;
-use dexter_client_api::errors::ClientResult;
-use dexter_client_api::exts::getter::GetAccountExt;
+use dexter_client_api::errors::{AddressLookupError, ClientError, ClientResult};
+use dexter_client_api::exts::getter::{GetAccountExt, GetMultipleAccountsExt, GetTransactionExt};
 use dexter_client_api::Client;
 
+// the maximum number of accounts per getMultipleAccounts call
+const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
 pub trait RemoteSetter: Client {
     fn set_account_from_remote<U: ToString>(
         &mut self,
@@ -22,6 +38,257 @@ pub trait RemoteSetter: Client {
 
         Ok(account)
     }
+
+    /// Returns the account as installed.
+    fn clone_with<U, F>(
+        &mut self,
+        pubkey: Pubkey,
+        rpcurl: U,
+        mut rewrite: F,
+    ) -> ClientResult<Account>
+    where
+        Self: SetAccount,
+        U: ToString,
+        F: FnMut(&mut Account),
+    {
+        let mut account = RpcClient::new(rpcurl).try_get_account(&pubkey)?;
+        rewrite(&mut account);
+
+        self.set_account(pubkey, account.clone());
+
+        Ok(account)
+    }
+
+    fn clone_accounts_from_remote<U: ToString>(
+        &mut self,
+        pubkeys: &[Pubkey],
+        rpcurl: U,
+    ) -> ClientResult<Vec<Account>>
+    where
+        Self: SetAccount,
+    {
+        let client = RpcClient::new(rpcurl);
+
+        let mut accounts = Vec::with_capacity(pubkeys.len());
+        for chunk in pubkeys.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            accounts.extend(client.try_get_multiple_accounts(chunk)?);
+        }
+
+        for (pubkey, account) in pubkeys.iter().zip(&accounts) {
+            self.set_account(*pubkey, account.clone());
+        }
+
+        Ok(accounts)
+    }
+
+    fn clone_program_accounts_from_remote<U: ToString>(
+        &mut self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+        rpcurl: U,
+    ) -> ClientResult<Vec<(Pubkey, Account)>>
+    where
+        Self: SetAccount,
+    {
+        let client = RpcClient::new(rpcurl);
+        let program_accounts =
+            GetProgramAccounts::get_program_accounts(&client, program_id, filters)?;
+
+        for (pubkey, account) in &program_accounts {
+            self.set_account(*pubkey, account.clone());
+        }
+
+        Ok(program_accounts)
+    }
+
+    /// Includes the programdata account, rebased to slot 0 to be visible right away.
+    fn clone_program_from_remote<U: ToString>(
+        &mut self,
+        program_id: Pubkey,
+        rpcurl: U,
+    ) -> ClientResult<()>
+    where
+        Self: SetAccount,
+    {
+        let client = RpcClient::new(rpcurl);
+
+        let program_account = client.try_get_account(&program_id)?;
+        if !program_account.executable {
+            return Err(ClientError::AccountDidNotDeserialize(program_id));
+        }
+
+        if program_account.owner == bpf_loader_upgradeable::id() {
+            let program_state: UpgradeableLoaderState = bincode::deserialize(&program_account.data)
+                .map_err(|_| ClientError::AccountDidNotDeserialize(program_id))?;
+
+            let UpgradeableLoaderState::Program {
+                programdata_address,
+            } = program_state
+            else {
+                return Err(ClientError::AccountDidNotDeserialize(program_id));
+            };
+
+            let mut programdata_account = client.try_get_account(&programdata_address)?;
+            rebase_slots(&mut programdata_account);
+            self.set_account(programdata_address, programdata_account);
+        }
+
+        self.set_account(program_id, program_account);
+
+        Ok(())
+    }
+
+    /// Includes lookup tables and programdata accounts, but leaves sysvars and builtins as they are.
+    fn clone_transaction_accounts_from_remote<U: ToString>(
+        &mut self,
+        transaction: impl Into<RemoteTransaction>,
+        rpcurl: U,
+    ) -> ClientResult<Vec<(Pubkey, Account)>>
+    where
+        Self: SetAccount,
+    {
+        let client = RpcClient::new(rpcurl);
+
+        let message = match transaction.into() {
+            RemoteTransaction::Signature(signature) => {
+                client
+                    .try_fetch_execution_output(&signature)?
+                    .transaction
+                    .message
+            }
+            RemoteTransaction::Message(message) => message,
+        };
+
+        let mut pubkeys = message.static_account_keys().to_vec();
+        if let Some(lookups) = message.address_table_lookups() {
+            let lookup_table_addresses: Vec<Pubkey> =
+                lookups.iter().map(|lookup| lookup.account_key).collect();
+            let lookup_table_accounts =
+                client.try_get_multiple_accounts(&lookup_table_addresses)?;
+
+            for (lookup, account) in lookups.iter().zip(&lookup_table_accounts) {
+                let lookup_table = AddressLookupTable::deserialize(&account.data)
+                    .map_err(|_| ClientError::AccountDidNotDeserialize(lookup.account_key))?;
+
+                for index in lookup
+                    .writable_indexes
+                    .iter()
+                    .chain(&lookup.readonly_indexes)
+                {
+                    let address = lookup_table
+                        .addresses
+                        .get(usize::from(*index))
+                        .ok_or(AddressLookupError::InvalidLookupIndex)?;
+                    pubkeys.push(*address);
+                }
+            }
+
+            pubkeys.extend(lookup_table_addresses);
+        }
+        pubkeys.retain(|pubkey| !sysvar::is_sysvar_id(pubkey));
+        pubkeys.sort();
+        pubkeys.dedup();
+
+        let mut accounts = get_existing_accounts(&client, &pubkeys)?;
+
+        // upgradeable programs execute the programdata, which the message does not reference
+        let programdata_addresses: Vec<Pubkey> = accounts
+            .iter()
+            .filter(|(_, account)| {
+                account.executable && account.owner == bpf_loader_upgradeable::id()
+            })
+            .filter_map(|(_, account)| match bincode::deserialize(&account.data) {
+                Ok(UpgradeableLoaderState::Program {
+                    programdata_address,
+                }) => Some(programdata_address),
+                _ => None,
+            })
+            .filter(|programdata_address| pubkeys.binary_search(programdata_address).is_err())
+            .collect();
+        accounts.extend(get_existing_accounts(&client, &programdata_addresses)?);
+
+        for (_, account) in &mut accounts {
+            rebase_slots(account);
+        }
+
+        for (pubkey, account) in &accounts {
+            self.set_account(*pubkey, account.clone());
+        }
+
+        Ok(accounts)
+    }
 }
 
 impl<C: ?Sized + Client> RemoteSetter for C {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoteTransaction {
+    Signature(Signature),
+    Message(VersionedMessage),
+}
+
+impl From<Signature> for RemoteTransaction {
+    fn from(signature: Signature) -> Self {
+        Self::Signature(signature)
+    }
+}
+
+impl From<VersionedMessage> for RemoteTransaction {
+    fn from(message: VersionedMessage) -> Self {
+        Self::Message(message)
+    }
+}
+
+fn get_existing_accounts(
+    client: &RpcClient,
+    pubkeys: &[Pubkey],
+) -> ClientResult<Vec<(Pubkey, Account)>> {
+    let mut accounts = Vec::with_capacity(pubkeys.len());
+    for chunk in pubkeys.chunks(MAX_MULTIPLE_ACCOUNTS) {
+        let chunk_accounts = GetMultipleAccounts::get_multiple_accounts(client, chunk)?;
+        accounts.extend(
+            chunk
+                .iter()
+                .zip(chunk_accounts)
+                .filter_map(|(pubkey, account)| Some((*pubkey, account?)))
+                // builtin programs are part of every bank
+                .filter(|(_, account)| account.owner != native_loader::id()),
+        );
+    }
+    Ok(accounts)
+}
+
+// cluster slots are far ahead of a local bank, which would hide later deployments and extensions
+fn rebase_slots(account: &mut Account) {
+    if account.owner == bpf_loader_upgradeable::id() {
+        let Ok(UpgradeableLoaderState::ProgramData {
+            upgrade_authority_address,
+            ..
+        }) = bincode::deserialize(&account.data)
+        else {
+            return;
+        };
+
+        let programdata = UpgradeableLoaderState::ProgramData {
+            slot: 0,
+            upgrade_authority_address,
+        };
+        bincode::serialize_into(&mut account.data[..], &programdata).unwrap();
+    } else if account.owner == address_lookup_table::program::id() {
+        let Ok(lookup_table) = AddressLookupTable::deserialize(&account.data) else {
+            return;
+        };
+
+        let addresses = lookup_table.addresses.to_vec();
+        let lookup_table = AddressLookupTable {
+            meta: LookupTableMeta {
+                deactivation_slot: Slot::MAX,
+                last_extended_slot: 0,
+                last_extended_slot_start_index: u8::try_from(addresses.len()).unwrap_or(u8::MAX),
+                ..lookup_table.meta
+            },
+            addresses: Cow::Owned(addresses),
+        };
+        account.data = lookup_table.serialize_for_tests().unwrap();
+    }
+}
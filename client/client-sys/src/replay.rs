@@ -0,0 +1,60 @@
+use solana_rpc_client::rpc_client::RpcClient;
+use solana_sdk::signature::Signature;
+
+use dexter_client_api::base::executor::SimulateTransaction;
+use dexter_client_api::base::getter::{GetAccount, GetLatestBlockhash, GetTransaction};
+use dexter_client_api::base::setter::{SetAccount, SetSysvar};
+use dexter_client_api::errors::{ClientError, ClientResult};
+use dexter_client_api::execution::ExecutionEffect;
+use dexter_client_api::Client;
+
+use crate::remote::RemoteSetter;
+use crate::sysvar::{SysvarGetter, SysvarSetter};
+
+pub trait TransactionReplay: Client {
+    /// The accounts are cloned in their current state, not the one at the slot of the transaction.
+    fn replay_transaction_from_remote<U: ToString>(
+        &mut self,
+        signature: &Signature,
+        rpcurl: U,
+    ) -> ClientResult<ExecutionEffect>
+    where
+        Self: SetAccount
+            + SetSysvar
+            + GetAccount
+            + GetLatestBlockhash
+            + SimulateTransaction<ExecutionEffect>,
+    {
+        let rpcurl = rpcurl.to_string();
+        let client = RpcClient::new(&rpcurl);
+
+        let confirmed = GetTransaction::get_transaction(&client, signature)?
+            .ok_or(ClientError::TransactionNotFound(*signature))?;
+        let mut transaction = confirmed
+            .transaction
+            .transaction
+            .decode()
+            .ok_or(ClientError::TransactionDidNotDecode(*signature))?;
+
+        self.clone_transaction_accounts_from_remote(transaction.message.clone(), &rpcurl)?;
+
+        // the epochs follow the slot, under the local epoch schedule
+        let epoch_schedule = self.try_get_sysvar_epoch_schedule()?;
+        let mut clock = self.try_get_sysvar_clock()?;
+        clock.slot = confirmed.slot;
+        clock.epoch = epoch_schedule.get_epoch(confirmed.slot);
+        clock.leader_schedule_epoch = epoch_schedule.get_leader_schedule_epoch(confirmed.slot);
+        if let Some(block_time) = confirmed.block_time {
+            clock.unix_timestamp = block_time;
+            clock.epoch_start_timestamp = block_time;
+        }
+        self.set_clock(&clock);
+
+        let recent_blockhash = self.get_latest_blockhash()?;
+        transaction.message.set_recent_blockhash(recent_blockhash);
+
+        self.simulate_transaction_unverified(transaction)
+    }
+}
+
+impl<C: ?Sized + Client> TransactionReplay for C {}
@@ -0,0 +1,188 @@
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+use solana_account_decoder::{UiAccount, UiAccountEncoding};
+use solana_rpc_client_api::response::RpcKeyedAccount;
+use solana_sdk::account::Account;
+use solana_sdk::pubkey::Pubkey;
+
+use dexter_client_api::base::getter::{GetAccount, GetProgramAccounts, ProgramAccountsFilter};
+use dexter_client_api::base::setter::SetAccount;
+use dexter_client_api::errors::ClientResult;
+use dexter_client_api::exts::getter::GetAccountExt;
+use dexter_client_api::Client;
+
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+const BINARY_MAGIC: &[u8; 8] = b"DXSNAPSH";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotEncoding {
+    Binary,
+    /// A directory of `solana account --output json` files, e.g. for `--account-dir`.
+    Json,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccountSnapshot {
+    pub accounts: Vec<(Pubkey, Account)>,
+}
+
+impl AccountSnapshot {
+    pub fn new(accounts: Vec<(Pubkey, Account)>) -> Self {
+        Self { accounts }
+    }
+
+    pub fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(BINARY_MAGIC)?;
+        writer.write_all(&SNAPSHOT_VERSION.to_le_bytes())?;
+        bincode::serialize_into(writer, &self.accounts).map_err(invalid_data)
+    }
+
+    pub fn read<R: Read>(mut reader: R) -> io::Result<Self> {
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic != BINARY_MAGIC {
+            return Err(invalid_data("not an account snapshot"));
+        }
+
+        let mut version = [0; 4];
+        reader.read_exact(&mut version)?;
+        check_version(u32::from_le_bytes(version))?;
+
+        let accounts = bincode::deserialize_from(reader).map_err(invalid_data)?;
+        Ok(Self { accounts })
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>, encoding: SnapshotEncoding) -> io::Result<()> {
+        let path = path.as_ref();
+        match encoding {
+            SnapshotEncoding::Binary => {
+                let mut writer = BufWriter::new(File::create(path)?);
+                self.write(&mut writer)?;
+                writer.flush()
+            }
+            SnapshotEncoding::Json => {
+                fs::create_dir_all(path)?;
+                for (pubkey, account) in &self.accounts {
+                    let file = File::create(path.join(format!("{pubkey}.json")))?;
+                    let mut writer = BufWriter::new(file);
+                    write_json_account(&mut writer, pubkey, account)?;
+                    writer.flush()?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Accepts a json directory, a single `.json` account, or a binary file.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        if path.is_dir() {
+            let mut paths = fs::read_dir(path)?
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect::<io::Result<Vec<_>>>()?;
+            paths.retain(|path| is_json(path));
+            paths.sort();
+
+            let accounts = paths
+                .iter()
+                .map(|path| read_json_account(BufReader::new(File::open(path)?)))
+                .collect::<io::Result<_>>()?;
+            return Ok(Self { accounts });
+        }
+
+        let reader = BufReader::new(File::open(path)?);
+        if is_json(path) {
+            Ok(Self::new(vec![read_json_account(reader)?]))
+        } else {
+            Self::read(reader)
+        }
+    }
+}
+
+pub fn write_json_account<W: Write>(
+    writer: W,
+    pubkey: &Pubkey,
+    account: &Account,
+) -> io::Result<()> {
+    let keyed = RpcKeyedAccount {
+        pubkey: pubkey.to_string(),
+        account: UiAccount::encode(pubkey, account, UiAccountEncoding::Base64, None, None),
+    };
+    serde_json::to_writer_pretty(writer, &keyed).map_err(invalid_data)
+}
+
+pub fn read_json_account<R: Read>(reader: R) -> io::Result<(Pubkey, Account)> {
+    let keyed: RpcKeyedAccount = serde_json::from_reader(reader).map_err(invalid_data)?;
+    let pubkey = Pubkey::from_str(&keyed.pubkey).map_err(invalid_data)?;
+    let account = keyed
+        .account
+        .decode()
+        .ok_or_else(|| invalid_data(format!("undecodable account {pubkey}")))?;
+    Ok((pubkey, account))
+}
+
+fn is_json(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == "json")
+}
+
+fn check_version(version: u32) -> io::Result<()> {
+    if version > SNAPSHOT_VERSION {
+        return Err(invalid_data(format!(
+            "unsupported snapshot version {version}"
+        )));
+    }
+    Ok(())
+}
+
+fn invalid_data<E>(error: E) -> io::Error
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+pub trait SnapshotGetter: Client {
+    /// Fails if any of the accounts does not exist.
+    fn snapshot_accounts(&self, pubkeys: &[Pubkey]) -> ClientResult<AccountSnapshot>
+    where
+        Self: GetAccount,
+    {
+        let accounts = pubkeys
+            .iter()
+            .map(|pubkey| Ok((*pubkey, self.try_get_account(pubkey)?)))
+            .collect::<ClientResult<_>>()?;
+        Ok(AccountSnapshot::new(accounts))
+    }
+
+    fn snapshot_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        filters: Option<Vec<ProgramAccountsFilter>>,
+    ) -> ClientResult<AccountSnapshot>
+    where
+        Self: GetProgramAccounts,
+    {
+        let accounts = self.get_program_accounts(program_id, filters)?;
+        Ok(AccountSnapshot::new(accounts))
+    }
+}
+
+impl<C: ?Sized + Client> SnapshotGetter for C {}
+
+pub trait SnapshotSetter: Client {
+    fn restore_snapshot(&mut self, snapshot: &AccountSnapshot)
+    where
+        Self: SetAccount,
+    {
+        for (pubkey, account) in &snapshot.accounts {
+            self.set_account(*pubkey, account.clone());
+        }
+    }
+}
+
+impl<C: ?Sized + Client> SnapshotSetter for C {}
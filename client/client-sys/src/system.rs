@@ -0,0 +1,144 @@
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_instruction;
+
+use dexter_client_api::base::executor::ProcessTransaction;
+use dexter_client_api::base::getter::{GetLatestBlockhash, GetMinimumBalanceForRentExemption};
+use dexter_client_api::errors::{ClientError, ClientResult};
+use dexter_client_api::execution::ExecutionOutput;
+use dexter_client_api::exts::executor::{CompileTransactionBatch, CompilingProcessTransaction};
+use dexter_client_api::exts::signer::FeePayer;
+use dexter_client_api::Client;
+
+pub trait SystemInstruction: Client {
+    fn build_transfer(&self, from: &Pubkey, to: &Pubkey, lamports: u64) -> Instruction {
+        system_instruction::transfer(from, to, lamports)
+    }
+
+    fn build_transfer_many(&self, from: &Pubkey, transfers: &[(Pubkey, u64)]) -> Vec<Instruction> {
+        system_instruction::transfer_many(from, transfers)
+    }
+
+    fn build_create_account_with_seed(
+        &self,
+        from: &Pubkey,
+        base: &Pubkey,
+        seed: &str,
+        lamports: u64,
+        space: u64,
+        owner: &Pubkey,
+    ) -> ClientResult<Instruction> {
+        let to = Pubkey::create_with_seed(base, seed, owner)
+            .map_err(|err| ClientError::DomainSpecific(Box::new(err)))?;
+
+        Ok(system_instruction::create_account_with_seed(
+            from, &to, base, seed, lamports, space, owner,
+        ))
+    }
+
+    fn build_allocate(&self, pubkey: &Pubkey, space: u64) -> Instruction {
+        system_instruction::allocate(pubkey, space)
+    }
+
+    fn build_assign(&self, pubkey: &Pubkey, owner: &Pubkey) -> Instruction {
+        system_instruction::assign(pubkey, owner)
+    }
+}
+
+impl<C: ?Sized + Client> SystemInstruction for C {}
+
+pub trait SystemProcessor: Client {
+    fn process_transfer(
+        &self,
+        payer: &impl FeePayer,
+        from: &impl Signer,
+        to: &Pubkey,
+        lamports: u64,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let instructions = [self.build_transfer(&from.pubkey(), to, lamports)];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), from];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    /// Packs the transfers into as few transactions as fit.
+    fn process_transfer_many(
+        &self,
+        payer: &impl FeePayer,
+        from: &impl Signer,
+        transfers: &[(Pubkey, u64)],
+    ) -> ClientResult<Vec<ExecutionOutput>>
+    where
+        Self: GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let instructions = self.build_transfer_many(&from.pubkey(), transfers);
+        if instructions.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), from];
+        self.compile_transaction_batch(&instructions, &payer.payer_pubkey(), &signers, &[])?
+            .into_iter()
+            .map(|transaction| self.process_transaction(transaction))
+            .collect()
+    }
+
+    fn process_create_account_with_seed(
+        &self,
+        payer: &impl FeePayer,
+        base: &impl Signer,
+        seed: &str,
+        space: u64,
+        owner: &Pubkey,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetMinimumBalanceForRentExemption
+            + GetLatestBlockhash
+            + ProcessTransaction<ExecutionOutput>,
+    {
+        let lamports = self.get_minimum_balance_for_rent_exemption(space as usize)?;
+        let instructions = [self.build_create_account_with_seed(
+            &payer.payer_pubkey(),
+            &base.pubkey(),
+            seed,
+            lamports,
+            space,
+            owner,
+        )?];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), base];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_allocate(
+        &self,
+        payer: &impl FeePayer,
+        account: &impl Signer,
+        space: u64,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let instructions = [self.build_allocate(&account.pubkey(), space)];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), account];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+
+    fn process_assign(
+        &self,
+        payer: &impl FeePayer,
+        account: &impl Signer,
+        owner: &Pubkey,
+    ) -> ClientResult<ExecutionOutput>
+    where
+        Self: GetLatestBlockhash + ProcessTransaction<ExecutionOutput>,
+    {
+        let instructions = [self.build_assign(&account.pubkey(), owner)];
+        let signers: Vec<&dyn Signer> = vec![payer.payer(), account];
+        self.compiling_process_transaction(&instructions, &payer.payer_pubkey(), &signers, &[])
+    }
+}
+
+impl<C: ?Sized + Client> SystemProcessor for C {}
@@ -1,12 +1,17 @@
 use solana_sdk::sysvar::clock::Clock;
+use solana_sdk::sysvar::epoch_rewards::EpochRewards;
+use solana_sdk::sysvar::epoch_schedule::EpochSchedule;
+use solana_sdk::sysvar::last_restart_slot::LastRestartSlot;
 #[allow(deprecated)]
 use solana_sdk::sysvar::recent_blockhashes::RecentBlockhashes;
 use solana_sdk::sysvar::rent::Rent;
 use solana_sdk::sysvar::slot_hashes::SlotHashes;
 use solana_sdk::sysvar::slot_history::SlotHistory;
+use solana_sdk::sysvar::stake_history::StakeHistory;
 use solana_sdk::sysvar::Sysvar;
 
 use dexter_client_api::base::getter::GetAccount;
+use dexter_client_api::base::setter::SetSysvar;
 use dexter_client_api::errors::{ClientError, ClientResult};
 use dexter_client_api::exts::getter::GetAccountExt;
 use dexter_client_api::Client;
@@ -44,6 +49,24 @@ pub trait SysvarGetter: Client + GetAccount {
         self.get_sysvar()
     }
 
+    fn get_sysvar_epoch_schedule(&self) -> ClientResult<Option<EpochSchedule>> {
+        self.get_sysvar()
+    }
+
+    /// `None` unless the partitioned epoch rewards feature is active.
+    fn get_sysvar_epoch_rewards(&self) -> ClientResult<Option<EpochRewards>> {
+        self.get_sysvar()
+    }
+
+    fn get_sysvar_stake_history(&self) -> ClientResult<Option<StakeHistory>> {
+        self.get_sysvar()
+    }
+
+    /// `None` unless the last restart slot feature is active.
+    fn get_sysvar_last_restart_slot(&self) -> ClientResult<Option<LastRestartSlot>> {
+        self.get_sysvar()
+    }
+
     fn try_get_sysvar<T: Sysvar>(&self) -> ClientResult<T> {
         let account = self.try_get_account(&T::id())?;
 
@@ -73,6 +96,60 @@ pub trait SysvarGetter: Client + GetAccount {
     fn try_get_sysvar_recent_blockhashes(&self) -> ClientResult<RecentBlockhashes> {
         self.try_get_sysvar()
     }
+
+    fn try_get_sysvar_epoch_schedule(&self) -> ClientResult<EpochSchedule> {
+        self.try_get_sysvar()
+    }
+
+    fn try_get_sysvar_epoch_rewards(&self) -> ClientResult<EpochRewards> {
+        self.try_get_sysvar()
+    }
+
+    fn try_get_sysvar_stake_history(&self) -> ClientResult<StakeHistory> {
+        self.try_get_sysvar()
+    }
+
+    fn try_get_sysvar_last_restart_slot(&self) -> ClientResult<LastRestartSlot> {
+        self.try_get_sysvar()
+    }
 }
 
 impl<C: ?Sized + Client + GetAccount> SysvarGetter for C {}
+
+pub trait SysvarSetter: Client {
+    fn set_clock(&mut self, clock: &Clock)
+    where
+        Self: SetSysvar,
+    {
+        self.set_sysvar(clock);
+    }
+
+    /// Does not advance the slot.
+    fn advance_unix_timestamp(&mut self, secs: i64) -> ClientResult<Clock>
+    where
+        Self: SetSysvar + GetAccount,
+    {
+        let mut clock = self.try_get_sysvar_clock()?;
+        clock.unix_timestamp += secs;
+
+        self.set_clock(&clock);
+
+        Ok(clock)
+    }
+
+    fn set_epoch_schedule(&mut self, epoch_schedule: &EpochSchedule)
+    where
+        Self: SetSysvar,
+    {
+        self.set_sysvar(epoch_schedule);
+    }
+
+    fn set_slot_hashes(&mut self, slot_hashes: &SlotHashes)
+    where
+        Self: SetSysvar,
+    {
+        self.set_sysvar(slot_hashes);
+    }
+}
+
+impl<C: ?Sized + Client> SysvarSetter for C {}
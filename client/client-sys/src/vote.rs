@@ -0,0 +1,80 @@
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::vote::program as vote_program;
+use solana_sdk::vote::state::VoteState;
+
+use dexter_client_api::base::getter::{
+    GetAccount, GetProgramAccounts, Memcmp, ProgramAccountsFilter,
+};
+use dexter_client_api::errors::{ClientError, ClientResult};
+use dexter_client_api::Client;
+
+// every version of the vote state starts with its node pubkey right after the version tag
+const NODE_PUBKEY_OFFSET: usize = 4;
+
+pub trait VoteGetter: Client {
+    fn get_vote_account(&self, pubkey: &Pubkey) -> ClientResult<Option<VoteState>>
+    where
+        Self: GetAccount,
+    {
+        let Some(account) = self.get_account(pubkey)? else {
+            return Ok(None);
+        };
+
+        let vote_state = VoteState::deserialize(&account.data)
+            .map_err(|_| ClientError::AccountDidNotDeserialize(*pubkey))?;
+
+        Ok(Some(vote_state))
+    }
+
+    fn try_get_vote_account(&self, pubkey: &Pubkey) -> ClientResult<VoteState>
+    where
+        Self: GetAccount,
+    {
+        match self.get_vote_account(pubkey)? {
+            Some(vote_state) => Ok(vote_state),
+            None => Err(ClientError::AccountNotFound(*pubkey)),
+        }
+    }
+
+    /// Skips uninitialized accounts.
+    fn get_vote_accounts(&self) -> ClientResult<Vec<(Pubkey, VoteState)>>
+    where
+        Self: GetProgramAccounts,
+    {
+        scan_vote_accounts(self, None)
+    }
+
+    fn get_vote_accounts_for_node(
+        &self,
+        node_pubkey: &Pubkey,
+    ) -> ClientResult<Vec<(Pubkey, VoteState)>>
+    where
+        Self: GetProgramAccounts,
+    {
+        let filters = vec![ProgramAccountsFilter::Memcmp(Memcmp::new_raw_bytes(
+            NODE_PUBKEY_OFFSET,
+            node_pubkey.to_bytes().to_vec(),
+        ))];
+        scan_vote_accounts(self, Some(filters))
+    }
+}
+
+impl<C: ?Sized + Client> VoteGetter for C {}
+
+fn scan_vote_accounts<C: ?Sized + GetProgramAccounts>(
+    client: &C,
+    filters: Option<Vec<ProgramAccountsFilter>>,
+) -> ClientResult<Vec<(Pubkey, VoteState)>> {
+    client
+        .get_program_accounts(&vote_program::id(), filters)?
+        .into_iter()
+        .map(|(key, account)| {
+            VoteState::deserialize(&account.data)
+                .map(|vote_state| (key, vote_state))
+                .map_err(|_| ClientError::AccountDidNotDeserialize(key))
+        })
+        .filter(|result| {
+            !matches!(result, Ok((_, vote_state)) if vote_state.node_pubkey == Pubkey::default())
+        })
+        .collect()
+}
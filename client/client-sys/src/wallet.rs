@@ -1,9 +1,21 @@
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
 use solana_sdk::account::Account;
+use solana_sdk::derivation_path::{DerivationPath, DerivationPathError};
+use solana_sdk::hash::hashv;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::signer::keypair::{
+    generate_seed_from_seed_phrase_and_passphrase, keypair_from_seed,
+    keypair_from_seed_and_derivation_path, read_keypair_file,
+};
 use solana_sdk::system_program;
 
-use dexter_client_api::base::setter::SetAccount;
+use dexter_client_api::base::getter::GetAccount;
+use dexter_client_api::base::setter::{FundAccount, SetAccount};
+use dexter_client_api::errors::ClientResult;
 use dexter_client_api::Client;
 
 pub trait WalletSetter: Client {
@@ -30,6 +42,69 @@ pub trait WalletSetter: Client {
         self.set_wallet(keypair.pubkey(), lamports);
         keypair
     }
+
+    /// Tops the balance up to `lamports`.
+    fn fund_wallet(&mut self, pubkey: &Pubkey, lamports: u64) -> ClientResult<()>
+    where
+        Self: GetAccount + FundAccount,
+    {
+        let balance = self
+            .get_account(pubkey)?
+            .map_or(0, |account| account.lamports);
+        if balance < lamports {
+            self.fund_account(pubkey, lamports - balance)?;
+        }
+        Ok(())
+    }
+
+    fn new_funded_wallet(&mut self, lamports: u64) -> ClientResult<Keypair>
+    where
+        Self: GetAccount + FundAccount,
+    {
+        let keypair = Keypair::new();
+        self.fund_wallet(&keypair.pubkey(), lamports)?;
+        Ok(keypair)
+    }
 }
 
 impl<C: ?Sized + Client> WalletSetter for C {}
+
+#[derive(Debug, Error)]
+pub enum KeypairError {
+    #[error("Failed to read the keypair file {0}: {1}")]
+    FileDidNotRead(PathBuf, String),
+    #[error(transparent)]
+    InvalidDerivationPath(#[from] DerivationPathError),
+    #[error("Failed to derive the keypair: {0}")]
+    DerivationFailed(String),
+}
+
+pub fn load_keypair(path: impl AsRef<Path>) -> Result<Keypair, KeypairError> {
+    let path = path.as_ref();
+    read_keypair_file(path)
+        .map_err(|err| KeypairError::FileDidNotRead(path.into(), err.to_string()))
+}
+
+/// The checksum is not validated. `None` matches `solana-keygen recover` without a path.
+pub fn keypair_from_seed_phrase(
+    mnemonic: &str,
+    derivation_path: Option<&str>,
+) -> Result<Keypair, KeypairError> {
+    let seed = generate_seed_from_seed_phrase_and_passphrase(mnemonic, "");
+
+    let keypair = match derivation_path {
+        Some(derivation_path) => {
+            let derivation_path = DerivationPath::from_absolute_path_str(derivation_path)?;
+            keypair_from_seed_and_derivation_path(&seed, Some(derivation_path))
+        }
+        None => keypair_from_seed(&seed),
+    };
+
+    keypair.map_err(|err| KeypairError::DerivationFailed(err.to_string()))
+}
+
+/// Never use one to hold real funds.
+pub fn named_keypair(name: &str) -> Keypair {
+    let seed = hashv(&[b"dexter-named-keypair", name.as_bytes()]);
+    keypair_from_seed(seed.as_ref()).unwrap()
+}
@@ -3,10 +3,11 @@ pub use dexter_client_api::execution;
 
 pub mod anchor {
     pub use dexter_client_anchor::*;
+    pub use dexter_client_macros::{dexter_program_client, FieldOffsets};
 }
 
 pub mod api {
-    pub use dexter_client_api::{base, exts, Client};
+    pub use dexter_client_api::{base, exts, planning, Client};
 }
 
 pub mod spl {
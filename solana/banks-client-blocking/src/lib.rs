@@ -1,31 +1,79 @@
 #![allow(clippy::result_large_err)]
 
 use borsh::BorshDeserialize;
-use std::sync::Arc;
-use tarpc::context::Context;
+use once_cell::sync::Lazy;
+use std::collections::BTreeSet;
+use std::future::Future;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant, SystemTime};
+use tarpc::context::{self, Context};
+use tokio::runtime::{Handle, Runtime, RuntimeFlavor};
 
 use solana_banks_client::BanksClient as AsyncBanksClient;
 pub use solana_banks_client::BanksClientError;
-use solana_banks_interface::TransactionStatus;
 use solana_banks_interface::{
     BanksTransactionResultWithMetadata, BanksTransactionResultWithSimulation,
 };
-use solana_sdk::account::Account;
+use solana_banks_interface::{TransactionConfirmationStatus, TransactionStatus};
+use solana_sdk::account::{from_account, Account};
 use solana_sdk::clock::Slot;
-use solana_sdk::commitment_config::CommitmentLevel;
+use solana_sdk::commitment_config::{CommitmentConfig, CommitmentLevel};
 use solana_sdk::hash::Hash;
 use solana_sdk::message::Message;
 use solana_sdk::program_pack::Pack;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::rent::Rent;
-use solana_sdk::signature::Signature;
+use solana_sdk::signature::{Keypair, Signature};
+use solana_sdk::system_transaction;
 use solana_sdk::sysvar::Sysvar;
 use solana_sdk::transaction::{self, VersionedTransaction};
 
+#[cfg(feature = "program-test")]
+pub mod program_test;
+pub mod watch;
+
+use watch::AccountWatcher;
+
+pub static RUNTIME: Lazy<Arc<Runtime>> = Lazy::new(|| {
+    tokio::runtime::Builder::new_multi_thread()
+        .thread_name("dexter-clients")
+        .enable_all()
+        .build()
+        .map(Arc::new)
+        .unwrap()
+});
+
+/// Advances the bank behind the server, which the banks interface cannot do.
+pub trait WarpBank: Send + Sync {
+    fn warp_to_slot(&self, slot: Slot) -> Result<(), BanksClientError>;
+}
+
+impl<F> WarpBank for F
+where
+    F: Fn(Slot) -> Result<(), BanksClientError> + Send + Sync,
+{
+    fn warp_to_slot(&self, slot: Slot) -> Result<(), BanksClientError> {
+        self(slot)
+    }
+}
+
+// bounds the concurrent status queries of a single poll
+const CONFIRM_BATCH_SIZE: usize = 256;
+const CONFIRM_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
 #[derive(Clone)]
 pub struct BanksClient {
     client: AsyncBanksClient,
-    rt: Arc<tokio::runtime::Runtime>,
+    rt: Handle,
+    // keeps an owned runtime alive, which the handle alone does not
+    _runtime: Option<Arc<Runtime>>,
+    timeout: Option<Duration>,
+    blockhash_ttl: Option<Duration>,
+    cached_blockhash: Arc<Mutex<Option<CachedBlockhash>>>,
+    faucet: Option<Arc<Keypair>>,
+    warp: Option<Arc<dyn WarpBank>>,
+    // the banks interface cannot scan accounts, so scans go over the keys registered here
+    registered: Arc<RwLock<BTreeSet<Pubkey>>>,
 }
 
 impl From<AsyncBanksClient> for BanksClient {
@@ -41,74 +89,180 @@ impl From<BanksClient> for AsyncBanksClient {
 }
 
 impl BanksClient {
+    /// Not supported within a current-thread runtime, e.g. a default `#[tokio::test]`.
     pub fn new(client: AsyncBanksClient) -> Self {
-        Self::with_runtime(client, Arc::new(tokio::runtime::Runtime::new().unwrap()))
+        match Handle::try_current() {
+            Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+                Self::with_handle(client, handle)
+            }
+            _ => Self::with_runtime(client, RUNTIME.clone()),
+        }
     }
 
-    pub fn with_runtime(client: AsyncBanksClient, rt: Arc<tokio::runtime::Runtime>) -> Self {
-        Self { client, rt }
+    pub fn with_runtime(client: AsyncBanksClient, rt: Arc<Runtime>) -> Self {
+        let mut this = Self::with_handle(client, rt.handle().clone());
+        this._runtime = Some(rt);
+        this
+    }
+
+    /// The runtime behind `handle` has to outlive the client.
+    pub fn with_handle(client: AsyncBanksClient, handle: Handle) -> Self {
+        Self {
+            client,
+            rt: handle,
+            _runtime: None,
+            timeout: None,
+            blockhash_ttl: None,
+            cached_blockhash: Arc::default(),
+            faucet: None,
+            warp: None,
+            registered: Arc::default(),
+        }
+    }
+
+    /// Overrides the default deadline of tarpc for calls without an explicit context.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn with_blockhash_cache(mut self, ttl: Duration) -> Self {
+        self.blockhash_ttl = Some(ttl);
+        self
+    }
+
+    pub fn invalidate_blockhash_cache(&self) {
+        *self.cached_blockhash.lock().unwrap() = None;
+    }
+
+    pub fn with_faucet(mut self, faucet: Keypair) -> Self {
+        self.faucet = Some(Arc::new(faucet));
+        self
+    }
+
+    pub fn with_warp(mut self, warp: impl WarpBank + 'static) -> Self {
+        self.warp = Some(Arc::new(warp));
+        self
+    }
+
+    // the banks server simulates without cpi recording, so neither path carries them
+    pub fn records_inner_instructions(&self) -> bool {
+        false
+    }
+
+    /// Shared among the clones of the client.
+    pub fn register_accounts(&self, pubkeys: impl IntoIterator<Item = Pubkey>) {
+        self.registered.write().unwrap().extend(pubkeys);
+    }
+
+    pub fn unregister_accounts<'a>(&self, pubkeys: impl IntoIterator<Item = &'a Pubkey>) {
+        let mut registered = self.registered.write().unwrap();
+        for pubkey in pubkeys {
+            registered.remove(pubkey);
+        }
+    }
+
+    pub fn registered_accounts(&self) -> Vec<Pubkey> {
+        self.registered.read().unwrap().iter().copied().collect()
+    }
+
+    pub fn get_registered_accounts(&self) -> Result<Vec<(Pubkey, Account)>, BanksClientError> {
+        let ctx = self.context();
+        self.get_registered_accounts_with_commitment_and_context(ctx, CommitmentLevel::default())
+    }
+
+    pub fn get_registered_accounts_with_commitment_and_context(
+        &self,
+        ctx: Context,
+        commitment: CommitmentLevel,
+    ) -> Result<Vec<(Pubkey, Account)>, BanksClientError> {
+        let mut accounts = Vec::new();
+        for pubkey in self.registered_accounts() {
+            if let Some(account) =
+                self.get_account_with_commitment_and_context(ctx, pubkey, commitment)?
+            {
+                accounts.push((pubkey, account));
+            }
+        }
+        Ok(accounts)
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        block_on(&self.rt, future)
+    }
+
+    // the async client takes `&mut self` only to drive its channel, so a cheap clone per call
+    // lets the blocking one be shared
+    fn client(&self) -> AsyncBanksClient {
+        self.client.clone()
+    }
+
+    pub fn context(&self) -> Context {
+        let mut ctx = context::current();
+        if let Some(timeout) = self.timeout {
+            ctx.deadline = SystemTime::now() + timeout;
+        }
+        ctx
     }
 
     pub fn send_transaction_with_context(
-        &mut self,
+        &self,
         ctx: Context,
         transaction: impl Into<VersionedTransaction>,
     ) -> Result<(), BanksClientError> {
-        self.rt
-            .block_on(self.client.send_transaction_with_context(ctx, transaction))
+        self.block_on(
+            self.client()
+                .send_transaction_with_context(ctx, transaction),
+        )
     }
 
     pub fn get_transaction_status_with_context(
-        &mut self,
+        &self,
         ctx: Context,
         signature: Signature,
     ) -> Result<Option<TransactionStatus>, BanksClientError> {
-        self.rt.block_on(
-            self.client
+        self.block_on(
+            self.client()
                 .get_transaction_status_with_context(ctx, signature),
         )
     }
 
     pub fn get_slot_with_context(
-        &mut self,
+        &self,
         ctx: Context,
         commitment: CommitmentLevel,
     ) -> Result<Slot, BanksClientError> {
-        self.rt
-            .block_on(self.client.get_slot_with_context(ctx, commitment))
+        self.block_on(self.client().get_slot_with_context(ctx, commitment))
     }
 
     pub fn get_block_height_with_context(
-        &mut self,
+        &self,
         ctx: Context,
         commitment: CommitmentLevel,
     ) -> Result<Slot, BanksClientError> {
-        self.rt
-            .block_on(self.client.get_block_height_with_context(ctx, commitment))
+        self.block_on(self.client().get_block_height_with_context(ctx, commitment))
     }
 
     pub fn process_transaction_with_commitment_and_context(
-        &mut self,
+        &self,
         ctx: Context,
         transaction: impl Into<VersionedTransaction>,
         commitment: CommitmentLevel,
     ) -> Result<Option<transaction::Result<()>>, BanksClientError> {
-        self.rt
-            .block_on(self.client.process_transaction_with_commitment_and_context(
-                ctx,
-                transaction,
-                commitment,
-            ))
+        self.block_on(
+            self.client()
+                .process_transaction_with_commitment_and_context(ctx, transaction, commitment),
+        )
     }
 
     pub fn process_transaction_with_preflight_and_commitment_and_context(
-        &mut self,
+        &self,
         ctx: Context,
         transaction: impl Into<VersionedTransaction>,
         commitment: CommitmentLevel,
     ) -> Result<BanksTransactionResultWithSimulation, BanksClientError> {
-        self.rt.block_on(
-            self.client
+        self.block_on(
+            self.client()
                 .process_transaction_with_preflight_and_commitment_and_context(
                     ctx,
                     transaction,
@@ -118,255 +272,461 @@ impl BanksClient {
     }
 
     pub fn process_transaction_with_metadata_and_context(
-        &mut self,
+        &self,
         ctx: Context,
         transaction: impl Into<VersionedTransaction>,
     ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
-        self.rt.block_on(
-            self.client
+        self.block_on(
+            self.client()
                 .process_transaction_with_metadata_and_context(ctx, transaction),
         )
     }
 
     pub fn simulate_transaction_with_commitment_and_context(
-        &mut self,
+        &self,
         ctx: Context,
         transaction: impl Into<VersionedTransaction>,
         commitment: CommitmentLevel,
     ) -> Result<BanksTransactionResultWithSimulation, BanksClientError> {
-        self.rt.block_on(
-            self.client
+        self.block_on(
+            self.client()
                 .simulate_transaction_with_commitment_and_context(ctx, transaction, commitment),
         )
     }
 
     pub fn get_account_with_commitment_and_context(
-        &mut self,
+        &self,
         ctx: Context,
         address: Pubkey,
         commitment: CommitmentLevel,
     ) -> Result<Option<Account>, BanksClientError> {
-        self.rt.block_on(
-            self.client
+        self.block_on(
+            self.client()
                 .get_account_with_commitment_and_context(ctx, address, commitment),
         )
     }
 
     pub fn send_transaction(
-        &mut self,
+        &self,
         transaction: impl Into<VersionedTransaction>,
     ) -> Result<(), BanksClientError> {
-        self.rt.block_on(self.client.send_transaction(transaction))
+        let ctx = self.context();
+        self.send_transaction_with_context(ctx, transaction)
     }
 
-    pub fn get_sysvar<T: Sysvar>(&mut self) -> Result<T, BanksClientError> {
-        self.rt.block_on(self.client.get_sysvar())
+    pub fn get_sysvar<T: Sysvar>(&self) -> Result<T, BanksClientError> {
+        let sysvar = self
+            .get_account(T::id())?
+            .ok_or(BanksClientError::ClientError("Sysvar not present"))?;
+        from_account::<T, _>(&sysvar).ok_or(BanksClientError::ClientError(
+            "Failed to deserialize sysvar",
+        ))
     }
 
-    pub fn get_rent(&mut self) -> Result<Rent, BanksClientError> {
-        self.rt.block_on(self.client.get_rent())
+    pub fn get_rent(&self) -> Result<Rent, BanksClientError> {
+        self.get_sysvar::<Rent>()
     }
 
     pub fn process_transaction_with_commitment(
-        &mut self,
+        &self,
         transaction: impl Into<VersionedTransaction>,
         commitment: CommitmentLevel,
     ) -> Result<(), BanksClientError> {
-        self.rt.block_on(
-            self.client
-                .process_transaction_with_commitment(transaction, commitment),
-        )
+        let ctx = self.context();
+        match self.process_transaction_with_commitment_and_context(ctx, transaction, commitment)? {
+            None => Err(BanksClientError::ClientError(
+                "invalid blockhash or fee-payer",
+            )),
+            Some(transaction_result) => Ok(transaction_result?),
+        }
     }
 
     pub fn process_transaction_with_metadata(
-        &mut self,
+        &self,
         transaction: impl Into<VersionedTransaction>,
     ) -> Result<BanksTransactionResultWithMetadata, BanksClientError> {
-        self.rt
-            .block_on(self.client.process_transaction_with_metadata(transaction))
+        let ctx = self.context();
+        self.process_transaction_with_metadata_and_context(ctx, transaction)
     }
 
     pub fn process_transaction_with_preflight_and_commitment(
-        &mut self,
+        &self,
         transaction: impl Into<VersionedTransaction>,
         commitment: CommitmentLevel,
     ) -> Result<(), BanksClientError> {
-        self.rt.block_on(
-            self.client
-                .process_transaction_with_preflight_and_commitment(transaction, commitment),
-        )
+        let ctx = self.context();
+        let result = self.process_transaction_with_preflight_and_commitment_and_context(
+            ctx,
+            transaction,
+            commitment,
+        )?;
+
+        match result {
+            BanksTransactionResultWithSimulation {
+                result: None,
+                simulation_details: _,
+            } => Err(BanksClientError::ClientError(
+                "invalid blockhash or fee-payer",
+            )),
+            BanksTransactionResultWithSimulation {
+                result: Some(Err(err)),
+                simulation_details: Some(simulation_details),
+            } => Err(BanksClientError::SimulationError {
+                err,
+                logs: simulation_details.logs,
+                units_consumed: simulation_details.units_consumed,
+                return_data: simulation_details.return_data,
+            }),
+            BanksTransactionResultWithSimulation {
+                result: Some(result),
+                simulation_details: _,
+            } => result.map_err(Into::into),
+        }
     }
 
     pub fn process_transaction_with_preflight(
-        &mut self,
+        &self,
         transaction: impl Into<VersionedTransaction>,
     ) -> Result<(), BanksClientError> {
-        self.rt
-            .block_on(self.client.process_transaction_with_preflight(transaction))
+        self.process_transaction_with_preflight_and_commitment(
+            transaction,
+            CommitmentLevel::default(),
+        )
     }
 
     pub fn process_transaction(
-        &mut self,
+        &self,
         transaction: impl Into<VersionedTransaction>,
     ) -> Result<(), BanksClientError> {
-        self.rt
-            .block_on(self.client.process_transaction(transaction))
+        self.process_transaction_with_commitment(transaction, CommitmentLevel::default())
     }
 
     pub fn process_transactions_with_commitment<T: Into<VersionedTransaction>>(
-        &mut self,
+        &self,
         transactions: Vec<T>,
         commitment: CommitmentLevel,
     ) -> Result<(), BanksClientError> {
-        self.rt.block_on(
-            self.client
-                .process_transactions_with_commitment(transactions, commitment),
-        )
+        let handles: Vec<_> = transactions
+            .into_iter()
+            .map(|transaction| {
+                let mut client = self.client();
+                let ctx = self.context();
+                let transaction = transaction.into();
+                self.rt.spawn(async move {
+                    client
+                        .process_transaction_with_commitment_and_context(
+                            ctx,
+                            transaction,
+                            commitment,
+                        )
+                        .await
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            let result = self.block_on(handle).expect("task panicked")?;
+            match result {
+                None => {
+                    return Err(BanksClientError::ClientError(
+                        "invalid blockhash or fee-payer",
+                    ))
+                }
+                Some(transaction_result) => transaction_result?,
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn process_transactions<T: Into<VersionedTransaction>>(
+        &self,
+        transactions: Vec<T>,
+    ) -> Result<(), BanksClientError> {
+        self.process_transactions_with_commitment(transactions, CommitmentLevel::default())
     }
 
-    pub fn process_transactions<'a, T: Into<VersionedTransaction> + 'a>(
-        &'a mut self,
+    /// Processes them one after the other and does not stop at the first failure.
+    pub fn process_transactions_with_metadata<T: Into<VersionedTransaction>>(
+        &self,
         transactions: Vec<T>,
-    ) -> Result<(), BanksClientError> {
-        self.rt
-            .block_on(self.client.process_transactions(transactions))
+    ) -> Result<Vec<BanksTransactionResultWithMetadata>, BanksClientError> {
+        transactions
+            .into_iter()
+            .map(|transaction| {
+                self.process_transaction_with_metadata_and_context(self.context(), transaction)
+            })
+            .collect()
     }
 
     pub fn simulate_transaction_with_commitment(
-        &mut self,
+        &self,
         transaction: impl Into<VersionedTransaction>,
         commitment: CommitmentLevel,
     ) -> Result<BanksTransactionResultWithSimulation, BanksClientError> {
-        self.rt.block_on(
-            self.client
-                .simulate_transaction_with_commitment(transaction, commitment),
-        )
+        let ctx = self.context();
+        self.simulate_transaction_with_commitment_and_context(ctx, transaction, commitment)
     }
 
     pub fn simulate_transaction(
-        &mut self,
+        &self,
         transaction: impl Into<VersionedTransaction>,
     ) -> Result<BanksTransactionResultWithSimulation, BanksClientError> {
-        self.rt
-            .block_on(self.client.simulate_transaction(transaction))
+        self.simulate_transaction_with_commitment(transaction, CommitmentLevel::default())
     }
 
-    pub fn get_root_slot(&mut self) -> Result<Slot, BanksClientError> {
-        self.rt.block_on(self.client.get_root_slot())
+    pub fn get_root_slot(&self) -> Result<Slot, BanksClientError> {
+        let ctx = self.context();
+        self.get_slot_with_context(ctx, CommitmentLevel::default())
     }
 
-    pub fn get_root_block_height(&mut self) -> Result<Slot, BanksClientError> {
-        self.rt.block_on(self.client.get_root_block_height())
+    pub fn get_root_block_height(&self) -> Result<Slot, BanksClientError> {
+        let ctx = self.context();
+        self.get_block_height_with_context(ctx, CommitmentLevel::default())
     }
 
     pub fn get_account_with_commitment(
-        &mut self,
+        &self,
         address: Pubkey,
         commitment: CommitmentLevel,
     ) -> Result<Option<Account>, BanksClientError> {
-        self.rt
-            .block_on(self.client.get_account_with_commitment(address, commitment))
+        let ctx = self.context();
+        self.get_account_with_commitment_and_context(ctx, address, commitment)
     }
 
-    pub fn get_account(&mut self, address: Pubkey) -> Result<Option<Account>, BanksClientError> {
-        self.rt.block_on(self.client.get_account(address))
+    pub fn get_account(&self, address: Pubkey) -> Result<Option<Account>, BanksClientError> {
+        self.get_account_with_commitment(address, CommitmentLevel::default())
     }
 
-    pub fn get_packed_account_data<T: Pack>(
-        &mut self,
-        address: Pubkey,
-    ) -> Result<T, BanksClientError> {
-        self.rt
-            .block_on(self.client.get_packed_account_data(address))
+    pub fn get_packed_account_data<T: Pack>(&self, address: Pubkey) -> Result<T, BanksClientError> {
+        let account = self
+            .get_account(address)?
+            .ok_or(BanksClientError::ClientError("Account not found"))?;
+        T::unpack_from_slice(&account.data)
+            .map_err(|_| BanksClientError::ClientError("Failed to deserialize account"))
     }
 
     pub fn get_account_data_with_borsh<T: BorshDeserialize>(
-        &mut self,
+        &self,
         address: Pubkey,
     ) -> Result<T, BanksClientError> {
-        self.rt
-            .block_on(self.client.get_account_data_with_borsh(address))
+        let account = self
+            .get_account(address)?
+            .ok_or(BanksClientError::ClientError("Account not found"))?;
+        T::try_from_slice(&account.data).map_err(Into::into)
     }
 
     pub fn get_balance_with_commitment(
-        &mut self,
+        &self,
         address: Pubkey,
         commitment: CommitmentLevel,
     ) -> Result<u64, BanksClientError> {
-        self.rt
-            .block_on(self.client.get_balance_with_commitment(address, commitment))
+        let account = self.get_account_with_commitment(address, commitment)?;
+        Ok(account.map(|account| account.lamports).unwrap_or(0))
     }
 
-    pub fn get_balance(&mut self, address: Pubkey) -> Result<u64, BanksClientError> {
-        self.rt.block_on(self.client.get_balance(address))
+    pub fn get_balance(&self, address: Pubkey) -> Result<u64, BanksClientError> {
+        self.get_balance_with_commitment(address, CommitmentLevel::default())
     }
 
     pub fn get_transaction_status(
-        &mut self,
+        &self,
         signature: Signature,
     ) -> Result<Option<TransactionStatus>, BanksClientError> {
-        self.rt
-            .block_on(self.client.get_transaction_status(signature))
+        let ctx = self.context();
+        self.get_transaction_status_with_context(ctx, signature)
     }
 
     pub fn get_transaction_statuses(
-        &mut self,
+        &self,
         signatures: Vec<Signature>,
     ) -> Result<Vec<Option<TransactionStatus>>, BanksClientError> {
-        self.rt
-            .block_on(self.client.get_transaction_statuses(signatures))
-    }
-
-    pub fn get_latest_blockhash(&mut self) -> Result<Hash, BanksClientError> {
-        self.rt.block_on(self.client.get_latest_blockhash())
+        let ctx = self.context();
+        let handles: Vec<_> = signatures
+            .into_iter()
+            .map(|signature| {
+                let mut client = self.client();
+                self.rt.spawn(async move {
+                    client
+                        .get_transaction_status_with_context(ctx, signature)
+                        .await
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| self.block_on(handle).expect("task panicked"))
+            .collect()
+    }
+
+    pub fn warp_to_slot(&self, slot: Slot) -> Result<(), BanksClientError> {
+        let warp = self
+            .warp
+            .as_ref()
+            .ok_or(BanksClientError::ClientError("slot warping not supported"))?;
+        warp.warp_to_slot(slot)?;
+        self.invalidate_blockhash_cache();
+        Ok(())
+    }
+
+    pub fn advance_slots(&self, slots: u64) -> Result<(), BanksClientError> {
+        let ctx = self.context();
+        let slot = self.get_slot_with_context(ctx, CommitmentLevel::Processed)?;
+        self.warp_to_slot(slot + slots)
+    }
+
+    pub fn request_airdrop(
+        &self,
+        pubkey: &Pubkey,
+        lamports: u64,
+    ) -> Result<Signature, BanksClientError> {
+        let faucet = self
+            .faucet
+            .as_ref()
+            .ok_or(BanksClientError::ClientError("faucet not configured"))?;
+        let blockhash = self.get_latest_blockhash()?;
+        let transaction = system_transaction::transfer(faucet, pubkey, lamports, blockhash);
+        let signature = transaction.signatures[0];
+        self.process_transaction(transaction)?;
+        Ok(signature)
+    }
+
+    pub fn watch_account(&self, pubkey: Pubkey, interval: Duration) -> AccountWatcher {
+        AccountWatcher::new(self.clone(), pubkey, interval)
+    }
+
+    /// `None` for the transactions not confirmed in time.
+    pub fn confirm_transactions(
+        &self,
+        signatures: Vec<Signature>,
+        commitment: CommitmentLevel,
+        timeout: Duration,
+    ) -> Result<Vec<Option<transaction::Result<()>>>, BanksClientError> {
+        let deadline = Instant::now() + timeout;
+        let mut results = vec![None; signatures.len()];
+        let mut pending: Vec<usize> = (0..signatures.len()).collect();
+
+        loop {
+            for batch in pending.chunks(CONFIRM_BATCH_SIZE) {
+                let statuses =
+                    self.get_transaction_statuses(batch.iter().map(|&i| signatures[i]).collect())?;
+                for (&index, status) in batch.iter().zip(statuses) {
+                    if let Some(status) = status.filter(|status| reaches(status, commitment)) {
+                        results[index] = Some(status.err.map_or(Ok(()), Err));
+                    }
+                }
+            }
+
+            pending.retain(|&index| results[index].is_none());
+            if pending.is_empty() || Instant::now() >= deadline {
+                return Ok(results);
+            }
+
+            std::thread::sleep(CONFIRM_POLL_INTERVAL);
+        }
+    }
+
+    pub fn get_latest_blockhash(&self) -> Result<Hash, BanksClientError> {
+        self.get_latest_blockhash_with_commitment(CommitmentLevel::default())?
+            .map(|(blockhash, _)| blockhash)
+            .ok_or(BanksClientError::ClientError("valid blockhash not found"))
     }
 
     pub fn get_latest_blockhash_with_commitment(
-        &mut self,
+        &self,
         commitment: CommitmentLevel,
     ) -> Result<Option<(Hash, u64)>, BanksClientError> {
-        self.rt
-            .block_on(self.client.get_latest_blockhash_with_commitment(commitment))
+        let ctx = self.context();
+        self.get_latest_blockhash_with_commitment_and_context(ctx, commitment)
     }
 
     pub fn get_latest_blockhash_with_commitment_and_context(
-        &mut self,
+        &self,
         ctx: Context,
         commitment: CommitmentLevel,
     ) -> Result<Option<(Hash, u64)>, BanksClientError> {
-        self.rt.block_on(
-            self.client
+        let Some(ttl) = self.blockhash_ttl else {
+            return self.block_on(
+                self.client()
+                    .get_latest_blockhash_with_commitment_and_context(ctx, commitment),
+            );
+        };
+
+        let mut cached_blockhash = self.cached_blockhash.lock().unwrap();
+        if let Some(cached) = *cached_blockhash {
+            if cached.commitment == commitment && cached.fetched_at.elapsed() < ttl {
+                return Ok(Some((cached.blockhash, cached.last_valid_block_height)));
+            }
+        }
+
+        let latest = self.block_on(
+            self.client()
                 .get_latest_blockhash_with_commitment_and_context(ctx, commitment),
-        )
+        )?;
+        *cached_blockhash = latest.map(|(blockhash, last_valid_block_height)| CachedBlockhash {
+            commitment,
+            blockhash,
+            last_valid_block_height,
+            fetched_at: Instant::now(),
+        });
+        Ok(latest)
     }
 
-    pub fn get_fee_for_message(
-        &mut self,
-        message: Message,
-    ) -> Result<Option<u64>, BanksClientError> {
-        self.rt.block_on(self.client.get_fee_for_message(message))
+    pub fn get_fee_for_message(&self, message: Message) -> Result<Option<u64>, BanksClientError> {
+        self.get_fee_for_message_with_commitment(message, CommitmentLevel::default())
     }
 
     pub fn get_fee_for_message_with_commitment(
-        &mut self,
+        &self,
         message: Message,
         commitment: CommitmentLevel,
     ) -> Result<Option<u64>, BanksClientError> {
-        self.rt.block_on(
-            self.client
-                .get_fee_for_message_with_commitment(message, commitment),
-        )
+        let ctx = self.context();
+        self.get_fee_for_message_with_commitment_and_context(ctx, message, commitment)
     }
 
     pub fn get_fee_for_message_with_commitment_and_context(
-        &mut self,
+        &self,
         ctx: Context,
         message: Message,
         commitment: CommitmentLevel,
     ) -> Result<Option<u64>, BanksClientError> {
-        self.rt.block_on(
-            self.client
+        self.block_on(
+            self.client()
                 .get_fee_for_message_with_commitment_and_context(ctx, message, commitment),
         )
     }
 }
+
+// blocking a worker of a runtime panics, so a call from within one hands the worker over first,
+// which only the multi-threaded runtimes support
+pub(crate) fn block_on<F: Future>(handle: &Handle, future: F) -> F::Output {
+    match Handle::try_current() {
+        Ok(current) if current.runtime_flavor() == RuntimeFlavor::MultiThread => {
+            tokio::task::block_in_place(|| handle.block_on(future))
+        }
+        Ok(_) => panic!(
+            "the blocking banks client cannot be used from within a current-thread runtime, \
+             use a multi-threaded one, e.g. `#[tokio::test(flavor = \"multi_thread\")]`"
+        ),
+        Err(_) => handle.block_on(future),
+    }
+}
+
+#[derive(Clone, Copy)]
+struct CachedBlockhash {
+    commitment: CommitmentLevel,
+    blockhash: Hash,
+    last_valid_block_height: u64,
+    fetched_at: Instant,
+}
+
+fn reaches(status: &TransactionStatus, commitment: CommitmentLevel) -> bool {
+    let commitment = CommitmentConfig { commitment };
+    match status.confirmation_status {
+        Some(TransactionConfirmationStatus::Finalized) => true,
+        Some(TransactionConfirmationStatus::Confirmed) => !commitment.is_finalized(),
+        Some(TransactionConfirmationStatus::Processed) | None => commitment.is_processed(),
+    }
+}
@@ -0,0 +1,94 @@
+use std::io;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+use solana_program_test::{
+    ProgramTest, ProgramTestContext as AsyncProgramTestContext, ProgramTestError,
+};
+use solana_sdk::account::AccountSharedData;
+use solana_sdk::clock::{Epoch, Slot};
+use solana_sdk::genesis_config::GenesisConfig;
+use solana_sdk::hash::Hash;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::sysvar::Sysvar;
+
+use crate::{block_on, BanksClient, RUNTIME};
+
+pub struct ProgramTestContext {
+    context: AsyncProgramTestContext,
+    client: BanksClient,
+}
+
+impl ProgramTestContext {
+    pub fn start(program_test: ProgramTest) -> Self {
+        let rt = RUNTIME.clone();
+        let context = block_on(rt.handle(), program_test.start_with_context());
+        Self::with_runtime(context, rt)
+    }
+
+    /// `rt` must be the runtime the context was started on.
+    pub fn with_runtime(context: AsyncProgramTestContext, rt: Arc<Runtime>) -> Self {
+        let client = BanksClient::with_runtime(context.banks_client.clone(), rt)
+            .with_faucet(context.payer.insecure_clone());
+        Self { context, client }
+    }
+
+    pub fn with_blockhash_cache(mut self, ttl: Duration) -> Self {
+        self.client = self.client.with_blockhash_cache(ttl);
+        self
+    }
+
+    pub fn banks_client(&self) -> &BanksClient {
+        &self.client
+    }
+
+    pub fn payer(&self) -> &Keypair {
+        &self.context.payer
+    }
+
+    pub fn last_blockhash(&self) -> Hash {
+        self.context.last_blockhash
+    }
+
+    pub fn genesis_config(&self) -> &GenesisConfig {
+        self.context.genesis_config()
+    }
+
+    /// Also registers the account for scans.
+    pub fn set_account(&mut self, address: &Pubkey, account: &AccountSharedData) {
+        self.context.set_account(address, account);
+        self.client.register_accounts([*address]);
+    }
+
+    pub fn set_sysvar<T: Sysvar>(&self, sysvar: &T) {
+        self.context.set_sysvar(sysvar);
+    }
+
+    pub fn warp_to_slot(&mut self, slot: Slot) -> Result<(), ProgramTestError> {
+        self.context.warp_to_slot(slot)?;
+        self.client.invalidate_blockhash_cache();
+        Ok(())
+    }
+
+    pub fn warp_to_epoch(&mut self, epoch: Epoch) -> Result<(), ProgramTestError> {
+        self.context.warp_to_epoch(epoch)?;
+        self.client.invalidate_blockhash_cache();
+        Ok(())
+    }
+
+    pub fn get_new_latest_blockhash(&mut self) -> io::Result<Hash> {
+        let blockhash = self
+            .client
+            .block_on(self.context.get_new_latest_blockhash())?;
+        self.client.invalidate_blockhash_cache();
+        Ok(blockhash)
+    }
+}
+
+impl From<ProgramTestContext> for AsyncProgramTestContext {
+    fn from(context: ProgramTestContext) -> Self {
+        context.context
+    }
+}
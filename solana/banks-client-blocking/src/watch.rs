@@ -0,0 +1,51 @@
+use std::time::Duration;
+
+use solana_sdk::account::Account;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{BanksClient, BanksClientError};
+
+/// Yields the current state and then each change, `None` while the account does not exist.
+pub struct AccountWatcher {
+    client: BanksClient,
+    pubkey: Pubkey,
+    interval: Duration,
+    last: Option<Option<Account>>,
+}
+
+impl AccountWatcher {
+    pub(crate) fn new(client: BanksClient, pubkey: Pubkey, interval: Duration) -> Self {
+        Self {
+            client,
+            pubkey,
+            interval,
+            last: None,
+        }
+    }
+
+    pub fn pubkey(&self) -> &Pubkey {
+        &self.pubkey
+    }
+}
+
+impl Iterator for AccountWatcher {
+    type Item = Result<Option<Account>, BanksClientError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.last.is_some() {
+                std::thread::sleep(self.interval);
+            }
+
+            let account = match self.client.get_account(self.pubkey) {
+                Ok(account) => account,
+                Err(err) => return Some(Err(err)),
+            };
+
+            if self.last.as_ref() != Some(&account) {
+                self.last = Some(account.clone());
+                return Some(Ok(account));
+            }
+        }
+    }
+}